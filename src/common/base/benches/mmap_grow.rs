@@ -0,0 +1,51 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![feature(allocator_api)]
+
+#[macro_use]
+extern crate criterion;
+
+use std::alloc::Allocator;
+use std::alloc::Layout;
+
+use criterion::Criterion;
+use databend_common_base::mem_allocator::JEAllocator;
+use databend_common_base::mem_allocator::MmapAllocator;
+
+/// Benchmark the grow path of the mmap allocator, in the pattern of building a vector
+/// by growing it page by page once it's served by mmap.
+fn bench_mmap_grow(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mmap_grow");
+
+    let threshold = 1 << 20;
+    let allocator = MmapAllocator::with_threshold(JEAllocator, threshold);
+    for steps in [16, 256] {
+        group.bench_function(format!("page_by_page/{steps}"), |b| {
+            b.iter(|| unsafe {
+                let mut layout = Layout::from_size_align(threshold, 8).unwrap();
+                let mut ptr = allocator.allocate(layout).unwrap().cast::<u8>();
+                for _ in 0..steps {
+                    let new_layout = Layout::from_size_align(layout.size() + 4096, 8).unwrap();
+                    ptr = allocator.grow(ptr, layout, new_layout).unwrap().cast::<u8>();
+                    layout = new_layout;
+                }
+                allocator.deallocate(ptr, layout);
+            })
+        });
+    }
+}
+
+criterion_group!(benches, bench_mmap_grow);
+criterion_main!(benches);
@@ -16,23 +16,275 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicIsize;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
 use crate::mem_allocator::JEAllocator;
 
+/// The allocations of at least this size are served by mmap by default.
+const DEFAULT_THRESHOLD: usize = 64 << 20;
+
+// Whether the live bytes and allocations are recorded, see `MmapAllocator::enable_stats`.
+// It's off by default, the allocation path only reads the flag then.
+static STATS_ENABLED: AtomicBool = AtomicBool::new(false);
+
+// The live bytes and allocations of all the mmap allocators in the process.
+// They are signed, the memory allocated before the stats are enabled may be freed after.
+static MMAP_BYTES: AtomicIsize = AtomicIsize::new(0);
+static MMAP_COUNT: AtomicIsize = AtomicIsize::new(0);
+static FALLBACK_BYTES: AtomicIsize = AtomicIsize::new(0);
+static DEALLOC_FAILURES: AtomicUsize = AtomicUsize::new(0);
+
+/// A snapshot of the memory held by the mmap allocators, split by the path serving it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocatorStats {
+    /// The bytes served by mmap.
+    pub mmap_bytes: usize,
+    /// The number of allocations served by mmap.
+    pub mmap_count: usize,
+    /// The bytes served by the inner allocator.
+    pub fallback_bytes: usize,
+    /// The number of mmap allocations failed to be released to the system.
+    pub dealloc_failures: usize,
+}
+
+/// The options of `MmapAllocator`. The default ones are constants, so the default allocator
+/// used by the hash tables stays zero-sized, the configured ones are carried by `MmapOptions`.
+pub trait MmapConfig: Copy {
+    /// The allocations smaller than this fall through to the inner allocator.
+    fn threshold(&self) -> usize;
+
+    /// Advise the kernel to back the mmap allocations by transparent huge pages.
+    fn hugepage(&self) -> bool;
+
+    /// Advise the kernel to reclaim the mmap memory as soon as it's freed.
+    fn dontneed(&self) -> bool;
+
+    /// The NUMA node the mmap allocations prefer to be placed on.
+    fn numa_node(&self) -> Option<u32>;
+}
+
+/// The default options, serving the allocations of at least 64 MiB by mmap.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultMmapConfig;
+
+impl MmapConfig for DefaultMmapConfig {
+    #[inline(always)]
+    fn threshold(&self) -> usize {
+        DEFAULT_THRESHOLD
+    }
+
+    #[inline(always)]
+    fn hugepage(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn dontneed(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn numa_node(&self) -> Option<u32> {
+        None
+    }
+}
+
+/// The options configured at runtime, see `MmapAllocator::with_threshold`.
+#[derive(Debug, Clone, Copy)]
+pub struct MmapOptions {
+    threshold: usize,
+    hugepage: bool,
+    dontneed: bool,
+    numa_node: Option<u32>,
+}
+
+impl MmapConfig for MmapOptions {
+    #[inline(always)]
+    fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    #[inline(always)]
+    fn hugepage(&self) -> bool {
+        self.hugepage
+    }
+
+    #[inline(always)]
+    fn dontneed(&self) -> bool {
+        self.dontneed
+    }
+
+    #[inline(always)]
+    fn numa_node(&self) -> Option<u32> {
+        self.numa_node
+    }
+}
+
 /// mmap allocator.
 /// For better performance, we use jemalloc as the inner allocator.
-#[derive(Debug, Clone, Copy, Default)]
-pub struct MmapAllocator {
+#[derive(Debug, Clone, Copy)]
+pub struct MmapAllocator<C: MmapConfig = DefaultMmapConfig> {
     allocator: JEAllocator,
+    config: C,
+}
+
+impl<C: MmapConfig> MmapAllocator<C> {
+    pub fn threshold(&self) -> usize {
+        self.config.threshold()
+    }
+
+    pub fn hugepage(&self) -> bool {
+        self.config.hugepage()
+    }
+
+    pub fn dontneed(&self) -> bool {
+        self.config.dontneed()
+    }
+
+    pub fn numa_node(&self) -> Option<u32> {
+        self.config.numa_node()
+    }
+}
+
+impl MmapAllocator<MmapOptions> {
+    /// Create the allocator serving the allocations of at least `threshold` bytes by mmap,
+    /// it only takes effect on Linux, the other platforms always use the inner allocator.
+    pub fn with_threshold(allocator: JEAllocator, threshold: usize) -> Self {
+        Self {
+            allocator,
+            config: MmapOptions {
+                threshold,
+                hugepage: false,
+                dontneed: false,
+                numa_node: None,
+            },
+        }
+    }
+
+    /// Back the mmap allocations whose size is a multiple of 2 MiB by transparent huge pages,
+    /// it's a hint, the allocations never fail because the kernel doesn't support it.
+    pub fn with_hugepage(mut self, hugepage: bool) -> Self {
+        self.config.hugepage = hugepage;
+        self
+    }
+
+    /// Advise the kernel with MADV_DONTNEED on the mmap memory being freed, including the tail
+    /// dropped by shrinking, so the RSS drops promptly. It only takes effect on Linux.
+    pub fn with_dontneed(mut self, dontneed: bool) -> Self {
+        self.config.dontneed = dontneed;
+        self
+    }
+
+    /// Bind the mmap allocations to the preferred NUMA `node`, the pages are placed on the node
+    /// as long as it has free memory. It only takes effect on Linux, and the allocations are left
+    /// with the default policy if the binding fails, e.g. the node doesn't exist.
+    pub fn with_numa_node(mut self, node: Option<u32>) -> Self {
+        self.config.numa_node = node;
+        self
+    }
 }
 
 impl MmapAllocator {
     pub fn new() -> Self {
         Self {
             allocator: JEAllocator,
+            config: DefaultMmapConfig,
+        }
+    }
+
+    /// Start recording the memory held by all the mmap allocators of the process, it can't be
+    /// turned off. The memory allocated before isn't counted, so it's meant to be enabled at
+    /// startup, every allocation and deallocation updates the shared counters afterwards.
+    pub fn enable_stats() {
+        STATS_ENABLED.store(true, Ordering::Relaxed);
+    }
+
+    /// The memory currently held by all the mmap allocators of the process, only the failures
+    /// of deallocation are counted if the stats are not enabled.
+    pub fn stats() -> AllocatorStats {
+        let load = |counter: &AtomicIsize| counter.load(Ordering::Relaxed).max(0) as usize;
+        AllocatorStats {
+            mmap_bytes: load(&MMAP_BYTES),
+            mmap_count: load(&MMAP_COUNT),
+            fallback_bytes: load(&FALLBACK_BYTES),
+            dealloc_failures: DEALLOC_FAILURES.load(Ordering::Relaxed),
         }
     }
 }
 
+#[inline(always)]
+fn stats_enabled() -> bool {
+    STATS_ENABLED.load(Ordering::Relaxed)
+}
+
+#[cfg(any(target_os = "linux", windows))]
+#[inline(always)]
+fn record_mmap_alloc(size: usize) {
+    if stats_enabled() {
+        MMAP_BYTES.fetch_add(size as isize, Ordering::Relaxed);
+        MMAP_COUNT.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[cfg(any(target_os = "linux", windows))]
+#[inline(always)]
+fn record_mmap_dealloc(size: usize) {
+    if stats_enabled() {
+        MMAP_BYTES.fetch_sub(size as isize, Ordering::Relaxed);
+        MMAP_COUNT.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+#[cfg(any(target_os = "linux", windows))]
+#[inline(always)]
+fn record_mmap_realloc(old_size: usize, new_size: usize) {
+    if stats_enabled() {
+        MMAP_BYTES.fetch_add(new_size as isize - old_size as isize, Ordering::Relaxed);
+    }
+}
+
+/// Releasing the mapping fails only if the pointer or the layout is corrupted, e.g. by a double
+/// free. The deallocation can't return the error, so it's logged and counted instead of taking
+/// down the whole process, the mapping is leaked. It still panics in the debug builds.
+#[cfg(any(target_os = "linux", windows))]
+#[cold]
+fn record_dealloc_failure(addr: *mut u8, size: usize) {
+    DEALLOC_FAILURES.fetch_add(1, Ordering::Relaxed);
+    let err = std::io::Error::last_os_error();
+    log::error!("Failed to deallocate {} bytes at {:p}: {}", size, addr, err);
+    debug_assert!(false, "Failed to deallocate.");
+}
+
+#[inline(always)]
+fn record_fallback_alloc(size: usize) {
+    if stats_enabled() {
+        FALLBACK_BYTES.fetch_add(size as isize, Ordering::Relaxed);
+    }
+}
+
+#[inline(always)]
+fn record_fallback_dealloc(size: usize) {
+    if stats_enabled() {
+        FALLBACK_BYTES.fetch_sub(size as isize, Ordering::Relaxed);
+    }
+}
+
+#[inline(always)]
+fn record_fallback_realloc(old_size: usize, new_size: usize) {
+    if stats_enabled() {
+        FALLBACK_BYTES.fetch_add(new_size as isize - old_size as isize, Ordering::Relaxed);
+    }
+}
+
+impl Default for MmapAllocator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(target_os = "linux")]
 pub mod linux {
     use std::alloc::AllocError;
@@ -40,36 +292,137 @@ pub mod linux {
     use std::alloc::Layout;
     use std::ptr::null_mut;
     use std::ptr::NonNull;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::atomic::Ordering;
+    use std::sync::Once;
 
+    use super::record_dealloc_failure;
+    use super::record_fallback_alloc;
+    use super::record_fallback_dealloc;
+    use super::record_fallback_realloc;
+    use super::record_mmap_alloc;
+    use super::record_mmap_dealloc;
+    use super::record_mmap_realloc;
     use super::MmapAllocator;
+    use super::MmapConfig;
     use crate::runtime::ThreadTracker;
 
     // MADV_POPULATE_WRITE is supported since Linux 5.14.
     const MADV_POPULATE_WRITE: i32 = 23;
 
-    const THRESHOLD: usize = 64 << 20;
+    const HUGE_PAGE_SIZE: usize = 2 << 20;
+
+    const FALLBACK_PAGE_SIZE: usize = 4096;
+
+    // The memory policy preferring the given node, see `mbind(2)`.
+    const MPOL_PREFERRED: libc::c_int = 1;
+
+    // The nodes can be bound to, the same as the default `CONFIG_NODES_SHIFT` of x86_64.
+    const MAX_NUMA_NODES: usize = 1024;
+    const NODE_MASK_WORDS: usize = MAX_NUMA_NODES / libc::c_ulong::BITS as usize;
+
+    // Set once `madvise` rejects MADV_HUGEPAGE, e.g. the kernel is built without THP.
+    static HUGEPAGE_UNSUPPORTED: AtomicBool = AtomicBool::new(false);
+
+    // Set once `mbind` is not implemented, e.g. the kernel is built without NUMA.
+    static NUMA_UNSUPPORTED: AtomicBool = AtomicBool::new(false);
+
+    impl<C: MmapConfig> MmapAllocator<C> {
+        #[inline(always)]
+        fn hugepage_advised(&self, size: usize) -> bool {
+            self.config.hugepage()
+                && size % HUGE_PAGE_SIZE == 0
+                && !HUGEPAGE_UNSUPPORTED.load(Ordering::Relaxed)
+        }
+
+        #[inline(always)]
+        unsafe fn advise_hugepage(&self, addr: NonNull<()>, size: usize) {
+            if !self.hugepage_advised(size) {
+                return;
+            }
+            if libc::madvise(addr.as_ptr().cast(), size, libc::MADV_HUGEPAGE) != 0
+                && std::io::Error::last_os_error().raw_os_error() == Some(libc::EINVAL)
+            {
+                // The mapping is still usable, only stop advising the unsupported kernel.
+                HUGEPAGE_UNSUPPORTED.store(true, Ordering::Relaxed);
+            }
+        }
+
+        /// Bind the mapping to the preferred NUMA node, it only applies to the pages faulted
+        /// in afterwards. The mapping keeps the default policy if `mbind` fails.
+        #[inline(always)]
+        unsafe fn bind_numa_node(&self, addr: NonNull<()>, size: usize) {
+            let Some(node) = self.config.numa_node() else {
+                return;
+            };
+            let node = node as usize;
+            if node >= MAX_NUMA_NODES || NUMA_UNSUPPORTED.load(Ordering::Relaxed) {
+                return;
+            }
+            let word_bits = libc::c_ulong::BITS as usize;
+            let mut node_mask = [0 as libc::c_ulong; NODE_MASK_WORDS];
+            node_mask[node / word_bits] |= 1 << (node % word_bits);
+            // The kernel reads `maxnode - 1` bits of the mask.
+            let result = libc::syscall(
+                libc::SYS_mbind,
+                addr.as_ptr(),
+                size,
+                MPOL_PREFERRED,
+                node_mask.as_ptr(),
+                MAX_NUMA_NODES + 1,
+                0,
+            );
+            if result != 0
+                && std::io::Error::last_os_error().raw_os_error() == Some(libc::ENOSYS)
+            {
+                NUMA_UNSUPPORTED.store(true, Ordering::Relaxed);
+            }
+        }
 
-    impl MmapAllocator {
         #[inline(always)]
         fn mmap_alloc(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
             debug_assert!(layout.align() <= page_size());
             ThreadTracker::alloc(layout.size() as i64)?;
             const PROT: i32 = libc::PROT_READ | libc::PROT_WRITE;
             const FLAGS: i32 = libc::MAP_PRIVATE | libc::MAP_ANONYMOUS | libc::MAP_POPULATE;
-            let addr = unsafe { libc::mmap(null_mut(), layout.size(), PROT, FLAGS, -1, 0) };
+            // The pages populated by mmap are faulted in as small pages and placed before the
+            // mapping is advised or bound to the node, they are populated afterwards instead.
+            let populate_later =
+                self.hugepage_advised(layout.size()) || self.config.numa_node().is_some();
+            let flags = if populate_later {
+                FLAGS & !libc::MAP_POPULATE
+            } else {
+                FLAGS
+            };
+            let addr = unsafe { libc::mmap(null_mut(), layout.size(), PROT, flags, -1, 0) };
             if addr == libc::MAP_FAILED {
                 return Err(AllocError);
             }
             let addr = NonNull::new(addr as *mut ()).ok_or(AllocError)?;
+            if populate_later {
+                unsafe {
+                    self.advise_hugepage(addr, layout.size());
+                    self.bind_numa_node(addr, layout.size());
+                    populate_write(addr, layout.size());
+                }
+            }
+            record_mmap_alloc(layout.size());
             Ok(NonNull::<[u8]>::from_raw_parts(addr, layout.size()))
         }
 
         #[inline(always)]
         unsafe fn mmap_dealloc(&self, ptr: NonNull<u8>, layout: Layout) {
             debug_assert!(layout.align() <= page_size());
-            ThreadTracker::dealloc(layout.size() as i64);
+            if self.config.dontneed() {
+                libc::madvise(ptr.cast().as_ptr(), layout.size(), libc::MADV_DONTNEED);
+            }
             let result = libc::munmap(ptr.cast().as_ptr(), layout.size());
-            assert_eq!(result, 0, "Failed to deallocate.");
+            if result != 0 {
+                // The leaked mapping is still held by the tracker.
+                return record_dealloc_failure(ptr.as_ptr(), layout.size());
+            }
+            ThreadTracker::dealloc(layout.size() as i64);
+            record_mmap_dealloc(layout.size());
         }
 
         #[inline(always)]
@@ -96,9 +449,11 @@ pub mod linux {
                 return Err(AllocError);
             }
             let addr = NonNull::new(addr as *mut ()).ok_or(AllocError)?;
-            if linux_kernel_version() >= (5, 14, 0) {
-                libc::madvise(addr.cast().as_ptr(), new_layout.size(), MADV_POPULATE_WRITE);
-            }
+            // The mapping may be moved by `mremap`, advise and bind it again.
+            self.advise_hugepage(addr, new_layout.size());
+            self.bind_numa_node(addr, new_layout.size());
+            populate_write(addr, new_layout.size());
+            record_mmap_realloc(old_layout.size(), new_layout.size());
             Ok(NonNull::<[u8]>::from_raw_parts(addr, new_layout.size()))
         }
 
@@ -115,6 +470,18 @@ pub mod linux {
             ThreadTracker::dealloc(old_layout.size() as i64);
             ThreadTracker::alloc(new_layout.size() as i64)?;
 
+            if self.config.dontneed() {
+                // Only the whole pages of the dropped tail can be advised.
+                let tail = new_layout.size().next_multiple_of(page_size());
+                if tail < old_layout.size() {
+                    libc::madvise(
+                        ptr.as_ptr().add(tail).cast(),
+                        old_layout.size() - tail,
+                        libc::MADV_DONTNEED,
+                    );
+                }
+            }
+
             const REMAP_FLAGS: i32 = libc::MREMAP_MAYMOVE;
             let addr = libc::mremap(
                 ptr.cast().as_ptr(),
@@ -126,45 +493,67 @@ pub mod linux {
                 return Err(AllocError);
             }
             let addr = NonNull::new(addr as *mut ()).ok_or(AllocError)?;
-
+            record_mmap_realloc(old_layout.size(), new_layout.size());
             Ok(NonNull::<[u8]>::from_raw_parts(addr, new_layout.size()))
         }
     }
 
-    unsafe impl Allocator for MmapAllocator {
+    impl<C: MmapConfig> MmapAllocator<C> {
+        #[inline(always)]
+        fn fallback_alloc(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            let addr = self.allocator.allocate(layout)?;
+            record_fallback_alloc(layout.size());
+            Ok(addr)
+        }
+
+        #[inline(always)]
+        fn fallback_alloc_zeroed(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            let addr = self.allocator.allocate_zeroed(layout)?;
+            record_fallback_alloc(layout.size());
+            Ok(addr)
+        }
+
+        #[inline(always)]
+        unsafe fn fallback_dealloc(&self, ptr: NonNull<u8>, layout: Layout) {
+            self.allocator.deallocate(ptr, layout);
+            record_fallback_dealloc(layout.size());
+        }
+    }
+
+    unsafe impl<C: MmapConfig> Allocator for MmapAllocator<C> {
         #[inline(always)]
         fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
             if layout.align() > page_size() {
-                return self.allocator.allocate(layout);
+                return self.fallback_alloc(layout);
             }
-            if layout.size() >= THRESHOLD {
+            if layout.size() >= self.config.threshold() {
                 self.mmap_alloc(layout)
             } else {
-                self.allocator.allocate(layout)
+                self.fallback_alloc(layout)
             }
         }
 
         #[inline(always)]
         unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
             if layout.align() > page_size() {
-                return self.allocator.deallocate(ptr, layout);
+                return self.fallback_dealloc(ptr, layout);
             }
-            if layout.size() >= THRESHOLD {
+            if layout.size() >= self.config.threshold() {
                 self.mmap_dealloc(ptr, layout);
             } else {
-                self.allocator.deallocate(ptr, layout);
+                self.fallback_dealloc(ptr, layout);
             }
         }
 
         #[inline(always)]
         fn allocate_zeroed(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
             if layout.align() > page_size() {
-                return self.allocator.allocate_zeroed(layout);
+                return self.fallback_alloc_zeroed(layout);
             }
-            if layout.size() >= THRESHOLD {
+            if layout.size() >= self.config.threshold() {
                 self.mmap_alloc(layout)
             } else {
-                self.allocator.allocate_zeroed(layout)
+                self.fallback_alloc_zeroed(layout)
             }
         }
 
@@ -175,21 +564,25 @@ pub mod linux {
             new_layout: Layout,
         ) -> Result<NonNull<[u8]>, AllocError> {
             if old_layout.align() > page_size() {
-                return self.allocator.grow(ptr, old_layout, new_layout);
+                let addr = self.allocator.grow(ptr, old_layout, new_layout)?;
+                record_fallback_realloc(old_layout.size(), new_layout.size());
+                return Ok(addr);
             }
-            if old_layout.size() >= THRESHOLD {
+            if old_layout.size() >= self.config.threshold() {
                 self.mmap_grow(ptr, old_layout, new_layout)
-            } else if new_layout.size() >= THRESHOLD {
+            } else if new_layout.size() >= self.config.threshold() {
                 let addr = self.mmap_alloc(new_layout)?;
                 std::ptr::copy_nonoverlapping(
                     ptr.as_ptr(),
                     addr.cast().as_ptr(),
                     old_layout.size(),
                 );
-                self.allocator.deallocate(ptr, old_layout);
+                self.fallback_dealloc(ptr, old_layout);
                 Ok(addr)
             } else {
-                self.allocator.grow(ptr, old_layout, new_layout)
+                let addr = self.allocator.grow(ptr, old_layout, new_layout)?;
+                record_fallback_realloc(old_layout.size(), new_layout.size());
+                Ok(addr)
             }
         }
 
@@ -200,21 +593,25 @@ pub mod linux {
             new_layout: Layout,
         ) -> Result<NonNull<[u8]>, AllocError> {
             if old_layout.align() > page_size() {
-                return self.allocator.grow_zeroed(ptr, old_layout, new_layout);
+                let addr = self.allocator.grow_zeroed(ptr, old_layout, new_layout)?;
+                record_fallback_realloc(old_layout.size(), new_layout.size());
+                return Ok(addr);
             }
-            if old_layout.size() >= THRESHOLD {
+            if old_layout.size() >= self.config.threshold() {
                 self.mmap_grow(ptr, old_layout, new_layout)
-            } else if new_layout.size() >= THRESHOLD {
+            } else if new_layout.size() >= self.config.threshold() {
                 let addr = self.mmap_alloc(new_layout)?;
                 std::ptr::copy_nonoverlapping(
                     ptr.as_ptr(),
                     addr.cast().as_ptr(),
                     old_layout.size(),
                 );
-                self.allocator.deallocate(ptr, old_layout);
+                self.fallback_dealloc(ptr, old_layout);
                 Ok(addr)
             } else {
-                self.allocator.grow_zeroed(ptr, old_layout, new_layout)
+                let addr = self.allocator.grow_zeroed(ptr, old_layout, new_layout)?;
+                record_fallback_realloc(old_layout.size(), new_layout.size());
+                Ok(addr)
             }
         }
 
@@ -225,21 +622,29 @@ pub mod linux {
             new_layout: Layout,
         ) -> Result<NonNull<[u8]>, AllocError> {
             if old_layout.align() > page_size() {
-                return self.allocator.shrink(ptr, old_layout, new_layout);
+                let addr = self.allocator.shrink(ptr, old_layout, new_layout)?;
+                record_fallback_realloc(old_layout.size(), new_layout.size());
+                return Ok(addr);
             }
-            if new_layout.size() >= THRESHOLD {
+            if new_layout.size() >= self.config.threshold() {
                 self.mmap_shrink(ptr, old_layout, new_layout)
-            } else if old_layout.size() >= THRESHOLD {
-                let addr = self.allocator.allocate(new_layout)?;
+            } else if old_layout.size() >= self.config.threshold() {
+                // The buffer must be copied to the inner allocator, unmapping only the tail
+                // would keep serving it by mmap. The allocations are routed by their size, so
+                // the later `deallocate`, `grow` or `shrink` with the new layout would hand the
+                // mapping to the inner allocator.
+                let addr = self.fallback_alloc(new_layout)?;
                 std::ptr::copy_nonoverlapping(
                     ptr.as_ptr(),
                     addr.cast().as_ptr(),
-                    old_layout.size(),
+                    new_layout.size(),
                 );
                 self.mmap_dealloc(ptr, old_layout);
                 Ok(addr)
             } else {
-                self.allocator.shrink(ptr, old_layout, new_layout)
+                let addr = self.allocator.shrink(ptr, old_layout, new_layout)?;
+                record_fallback_realloc(old_layout.size(), new_layout.size());
+                Ok(addr)
             }
         }
     }
@@ -252,8 +657,7 @@ pub mod linux {
         static CACHE: AtomicUsize = AtomicUsize::new(INVALID);
         let fetch = CACHE.load(Ordering::Relaxed);
         if fetch == INVALID {
-            let result = unsafe { libc::sysconf(libc::_SC_PAGE_SIZE) as usize };
-            debug_assert_eq!(result.count_ones(), 1);
+            let result = checked_page_size(unsafe { libc::sysconf(libc::_SC_PAGE_SIZE) });
             CACHE.store(result, Ordering::Relaxed);
             result
         } else {
@@ -261,8 +665,60 @@ pub mod linux {
         }
     }
 
+    /// Validate the page size returned by `sysconf`, which is -1 on failure, e.g. in some
+    /// constrained containers. The invalid ones fall back to `FALLBACK_PAGE_SIZE`, the smallest
+    /// page size of the supported platforms, so the alignment checks stay conservative.
+    pub(super) fn checked_page_size(result: libc::c_long) -> usize {
+        match usize::try_from(result) {
+            Ok(size) if size.is_power_of_two() => size,
+            _ => FALLBACK_PAGE_SIZE,
+        }
+    }
+
+    /// Fault in the pages of the mapping for writing. The kernels without MADV_POPULATE_WRITE
+    /// reject it with EINVAL, the pages are touched one by one instead, keeping their contents
+    /// as the grown mappings are populated too.
+    #[inline(always)]
+    pub(super) unsafe fn populate_write(addr: NonNull<()>, size: usize) {
+        if supports_populate_write() {
+            if libc::madvise(addr.as_ptr().cast(), size, MADV_POPULATE_WRITE) == 0
+                || std::io::Error::last_os_error().raw_os_error() != Some(libc::EINVAL)
+            {
+                return;
+            }
+            // The version is not reliable, e.g. the backported or the patched kernels.
+            SUPPORTS_POPULATE_WRITE.store(false, Ordering::Relaxed);
+        }
+        touch_pages(addr, size);
+    }
+
+    /// Write back the first byte of each page, which faults in the page without changing it.
     #[inline(always)]
-    fn linux_kernel_version() -> (u16, u8, u8) {
+    pub(super) unsafe fn touch_pages(addr: NonNull<()>, size: usize) {
+        let addr = addr.as_ptr().cast::<u8>();
+        for offset in (0..size).step_by(page_size()) {
+            let page = addr.add(offset);
+            page.write_volatile(page.read_volatile());
+        }
+    }
+
+    // Whether the kernel supports MADV_POPULATE_WRITE, resolved once by `supports_populate_write`.
+    static SUPPORTS_POPULATE_WRITE: AtomicBool = AtomicBool::new(false);
+    static SUPPORTS_POPULATE_WRITE_INIT: Once = Once::new();
+
+    /// The same as `linux_kernel_version() >= (5, 14, 0)`, but the comparison is done only once,
+    /// the grow path reads the cached result instead. It is cleared once the kernel rejects
+    /// MADV_POPULATE_WRITE anyway.
+    #[inline(always)]
+    pub(super) fn supports_populate_write() -> bool {
+        SUPPORTS_POPULATE_WRITE_INIT.call_once(|| {
+            SUPPORTS_POPULATE_WRITE.store(linux_kernel_version() >= (5, 14, 0), Ordering::Relaxed);
+        });
+        SUPPORTS_POPULATE_WRITE.load(Ordering::Relaxed)
+    }
+
+    #[inline(always)]
+    pub(super) fn linux_kernel_version() -> (u16, u8, u8) {
         use std::sync::atomic::AtomicU32;
         use std::sync::atomic::Ordering;
         const INVALID: u32 = 0;
@@ -304,29 +760,228 @@ pub mod linux {
     }
 }
 
-#[cfg(not(target_os = "linux"))]
+#[cfg(windows)]
+pub mod windows {
+    use std::alloc::AllocError;
+    use std::alloc::Allocator;
+    use std::alloc::Layout;
+    use std::ptr::null;
+    use std::ptr::NonNull;
+
+    use windows_sys::Win32::System::Memory::VirtualAlloc;
+    use windows_sys::Win32::System::Memory::VirtualFree;
+    use windows_sys::Win32::System::Memory::MEM_COMMIT;
+    use windows_sys::Win32::System::Memory::MEM_RELEASE;
+    use windows_sys::Win32::System::Memory::MEM_RESERVE;
+    use windows_sys::Win32::System::Memory::PAGE_READWRITE;
+
+    use super::record_dealloc_failure;
+    use super::record_fallback_alloc;
+    use super::record_fallback_dealloc;
+    use super::record_fallback_realloc;
+    use super::record_mmap_alloc;
+    use super::record_mmap_dealloc;
+    use super::MmapAllocator;
+    use super::MmapConfig;
+    use crate::runtime::ThreadTracker;
+
+    // The addresses returned by VirtualAlloc are aligned to the allocation granularity (64 KiB),
+    // the allocations requiring a larger alignment are served by the inner allocator.
+    const ALLOCATION_GRANULARITY: usize = 64 << 10;
+
+    impl<C: MmapConfig> MmapAllocator<C> {
+        #[inline(always)]
+        fn virtual_alloc(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            debug_assert!(layout.align() <= ALLOCATION_GRANULARITY);
+            ThreadTracker::alloc(layout.size() as i64)?;
+            // The committed pages are zeroed.
+            let addr = unsafe {
+                VirtualAlloc(
+                    null(),
+                    layout.size(),
+                    MEM_COMMIT | MEM_RESERVE,
+                    PAGE_READWRITE,
+                )
+            };
+            let addr = NonNull::new(addr as *mut ()).ok_or(AllocError)?;
+            record_mmap_alloc(layout.size());
+            Ok(NonNull::<[u8]>::from_raw_parts(addr, layout.size()))
+        }
+
+        #[inline(always)]
+        unsafe fn virtual_free(&self, ptr: NonNull<u8>, layout: Layout) {
+            debug_assert!(layout.align() <= ALLOCATION_GRANULARITY);
+            // The whole region must be released at once, with the size of 0.
+            let result = VirtualFree(ptr.cast().as_ptr(), 0, MEM_RELEASE);
+            if result == 0 {
+                // The leaked region is still held by the tracker.
+                return record_dealloc_failure(ptr.as_ptr(), layout.size());
+            }
+            ThreadTracker::dealloc(layout.size() as i64);
+            record_mmap_dealloc(layout.size());
+        }
+
+        #[inline(always)]
+        fn fallback_alloc(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            let addr = self.allocator.allocate(layout)?;
+            record_fallback_alloc(layout.size());
+            Ok(addr)
+        }
+
+        #[inline(always)]
+        fn fallback_alloc_zeroed(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            let addr = self.allocator.allocate_zeroed(layout)?;
+            record_fallback_alloc(layout.size());
+            Ok(addr)
+        }
+
+        #[inline(always)]
+        unsafe fn fallback_dealloc(&self, ptr: NonNull<u8>, layout: Layout) {
+            self.allocator.deallocate(ptr, layout);
+            record_fallback_dealloc(layout.size());
+        }
+
+        #[inline(always)]
+        fn is_virtual(&self, layout: Layout) -> bool {
+            layout.align() <= ALLOCATION_GRANULARITY && layout.size() >= self.config.threshold()
+        }
+
+        // Windows has no `mremap`, the resizing crossing or staying above the threshold
+        // allocates the new memory, copies the data and frees the old memory.
+        unsafe fn realloc(
+            &self,
+            ptr: NonNull<u8>,
+            old_layout: Layout,
+            new_layout: Layout,
+        ) -> Result<NonNull<[u8]>, AllocError> {
+            let addr = if self.is_virtual(new_layout) {
+                self.virtual_alloc(new_layout)?
+            } else {
+                self.fallback_alloc(new_layout)?
+            };
+            std::ptr::copy_nonoverlapping(
+                ptr.as_ptr(),
+                addr.cast().as_ptr(),
+                old_layout.size().min(new_layout.size()),
+            );
+            if self.is_virtual(old_layout) {
+                self.virtual_free(ptr, old_layout);
+            } else {
+                self.fallback_dealloc(ptr, old_layout);
+            }
+            Ok(addr)
+        }
+    }
+
+    unsafe impl<C: MmapConfig> Allocator for MmapAllocator<C> {
+        #[inline(always)]
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            if self.is_virtual(layout) {
+                self.virtual_alloc(layout)
+            } else {
+                self.fallback_alloc(layout)
+            }
+        }
+
+        #[inline(always)]
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            if self.is_virtual(layout) {
+                self.virtual_free(ptr, layout);
+            } else {
+                self.fallback_dealloc(ptr, layout);
+            }
+        }
+
+        #[inline(always)]
+        fn allocate_zeroed(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            if self.is_virtual(layout) {
+                self.virtual_alloc(layout)
+            } else {
+                self.fallback_alloc_zeroed(layout)
+            }
+        }
+
+        unsafe fn grow(
+            &self,
+            ptr: NonNull<u8>,
+            old_layout: Layout,
+            new_layout: Layout,
+        ) -> Result<NonNull<[u8]>, AllocError> {
+            if self.is_virtual(old_layout) || self.is_virtual(new_layout) {
+                self.realloc(ptr, old_layout, new_layout)
+            } else {
+                let addr = self.allocator.grow(ptr, old_layout, new_layout)?;
+                record_fallback_realloc(old_layout.size(), new_layout.size());
+                Ok(addr)
+            }
+        }
+
+        unsafe fn grow_zeroed(
+            &self,
+            ptr: NonNull<u8>,
+            old_layout: Layout,
+            new_layout: Layout,
+        ) -> Result<NonNull<[u8]>, AllocError> {
+            // The grown memory is above the threshold if the old one is,
+            // so it's committed by VirtualAlloc and zeroed.
+            if self.is_virtual(old_layout) || self.is_virtual(new_layout) {
+                self.realloc(ptr, old_layout, new_layout)
+            } else {
+                let addr = self.allocator.grow_zeroed(ptr, old_layout, new_layout)?;
+                record_fallback_realloc(old_layout.size(), new_layout.size());
+                Ok(addr)
+            }
+        }
+
+        unsafe fn shrink(
+            &self,
+            ptr: NonNull<u8>,
+            old_layout: Layout,
+            new_layout: Layout,
+        ) -> Result<NonNull<[u8]>, AllocError> {
+            if self.is_virtual(old_layout) || self.is_virtual(new_layout) {
+                self.realloc(ptr, old_layout, new_layout)
+            } else {
+                let addr = self.allocator.shrink(ptr, old_layout, new_layout)?;
+                record_fallback_realloc(old_layout.size(), new_layout.size());
+                Ok(addr)
+            }
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "linux", windows)))]
 pub mod not_linux {
     use std::alloc::AllocError;
     use std::alloc::Allocator;
     use std::alloc::Layout;
     use std::ptr::NonNull;
 
+    use super::record_fallback_alloc;
+    use super::record_fallback_dealloc;
+    use super::record_fallback_realloc;
     use super::MmapAllocator;
+    use super::MmapConfig;
 
-    unsafe impl Allocator for MmapAllocator {
+    unsafe impl<C: MmapConfig> Allocator for MmapAllocator<C> {
         #[inline(always)]
         fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
-            self.allocator.allocate(layout)
+            let addr = self.allocator.allocate(layout)?;
+            record_fallback_alloc(layout.size());
+            Ok(addr)
         }
 
         #[inline(always)]
         fn allocate_zeroed(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
-            self.allocator.allocate_zeroed(layout)
+            let addr = self.allocator.allocate_zeroed(layout)?;
+            record_fallback_alloc(layout.size());
+            Ok(addr)
         }
 
         #[inline(always)]
         unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
-            self.allocator.deallocate(ptr, layout)
+            self.allocator.deallocate(ptr, layout);
+            record_fallback_dealloc(layout.size());
         }
 
         unsafe fn grow(
@@ -335,7 +990,9 @@ pub mod not_linux {
             old_layout: Layout,
             new_layout: Layout,
         ) -> Result<NonNull<[u8]>, AllocError> {
-            self.allocator.grow(ptr, old_layout, new_layout)
+            let addr = self.allocator.grow(ptr, old_layout, new_layout)?;
+            record_fallback_realloc(old_layout.size(), new_layout.size());
+            Ok(addr)
         }
 
         unsafe fn grow_zeroed(
@@ -344,7 +1001,9 @@ pub mod not_linux {
             old_layout: Layout,
             new_layout: Layout,
         ) -> Result<NonNull<[u8]>, AllocError> {
-            self.allocator.grow_zeroed(ptr, old_layout, new_layout)
+            let addr = self.allocator.grow_zeroed(ptr, old_layout, new_layout)?;
+            record_fallback_realloc(old_layout.size(), new_layout.size());
+            Ok(addr)
         }
 
         unsafe fn shrink(
@@ -353,13 +1012,252 @@ pub mod not_linux {
             old_layout: Layout,
             new_layout: Layout,
         ) -> Result<NonNull<[u8]>, AllocError> {
-            self.allocator.shrink(ptr, old_layout, new_layout)
+            let addr = self.allocator.shrink(ptr, old_layout, new_layout)?;
+            record_fallback_realloc(old_layout.size(), new_layout.size());
+            Ok(addr)
         }
     }
 }
 
 #[cfg(test)]
 mod test {
+    use std::sync::Mutex;
+
+    use super::MmapAllocator;
+    use crate::mem_allocator::JEAllocator;
+
+    // The stats are shared by the process, so the tests allocating by mmap run one by one.
+    static LOCK: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn test_with_threshold() {
+        let _guard = LOCK.lock().unwrap();
+        // The default allocator is embedded in every hash table, it must stay zero-sized.
+        assert_eq!(std::mem::size_of::<MmapAllocator>(), 0);
+        assert_eq!(MmapAllocator::new().threshold(), 64 << 20);
+        assert_eq!(MmapAllocator::default().threshold(), 64 << 20);
+
+        // Grow across the threshold and shrink back below it.
+        let allocator = MmapAllocator::with_threshold(JEAllocator, 16 << 10);
+        assert_eq!(allocator.threshold(), 16 << 10);
+        let mut values: Vec<u8, _> = Vec::with_capacity_in(1 << 10, allocator);
+        values.extend(std::iter::repeat(1).take(64 << 10));
+        assert!(values.capacity() >= 64 << 10);
+        values.truncate(1 << 10);
+        values.shrink_to_fit();
+        assert_eq!(values.len(), 1 << 10);
+        assert!(values.iter().all(|value| *value == 1));
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_stats() {
+        let _guard = LOCK.lock().unwrap();
+        MmapAllocator::enable_stats();
+        let allocator = MmapAllocator::with_threshold(JEAllocator, 64 << 10);
+        let before = MmapAllocator::stats();
+
+        let mut large: Vec<u8, _> = Vec::with_capacity_in(128 << 10, allocator);
+        let mut small: Vec<u8, _> = Vec::with_capacity_in(1 << 10, allocator);
+        let stats = MmapAllocator::stats();
+        assert_eq!(stats.mmap_bytes - before.mmap_bytes, 128 << 10);
+        assert_eq!(stats.mmap_count - before.mmap_count, 1);
+        assert_eq!(stats.fallback_bytes - before.fallback_bytes, 1 << 10);
+
+        // Growing across the threshold moves the buffer to mmap.
+        small.reserve_exact(128 << 10);
+        let stats = MmapAllocator::stats();
+        assert_eq!(stats.mmap_bytes - before.mmap_bytes, 256 << 10);
+        assert_eq!(stats.mmap_count - before.mmap_count, 2);
+        assert_eq!(stats.fallback_bytes, before.fallback_bytes);
+
+        // Shrinking below the threshold moves it back to the inner allocator.
+        large.shrink_to(1 << 10);
+        let stats = MmapAllocator::stats();
+        assert_eq!(stats.mmap_bytes - before.mmap_bytes, 128 << 10);
+        assert_eq!(stats.mmap_count - before.mmap_count, 1);
+        assert_eq!(stats.fallback_bytes - before.fallback_bytes, 1 << 10);
+
+        drop(large);
+        drop(small);
+        assert_eq!(MmapAllocator::stats(), before);
+    }
+
+    #[test]
+    fn test_with_hugepage() {
+        let _guard = LOCK.lock().unwrap();
+        assert!(!MmapAllocator::new().hugepage());
+
+        let allocator = MmapAllocator::with_threshold(JEAllocator, 1 << 20).with_hugepage(true);
+        assert!(allocator.hugepage());
+        assert_eq!(allocator.threshold(), 1 << 20);
+
+        // Both the sizes of multiple of 2 MiB and the others are allocated.
+        let mut values: Vec<u8, _> = Vec::with_capacity_in(2 << 20, allocator);
+        values.extend(std::iter::repeat(1).take(2 << 20));
+        values.reserve_exact(3 << 20);
+        values.extend(std::iter::repeat(2).take(3 << 20));
+        assert_eq!(values.len(), 5 << 20);
+        assert!(values[..2 << 20].iter().all(|value| *value == 1));
+        assert!(values[2 << 20..].iter().all(|value| *value == 2));
+    }
+
+    #[test]
+    fn test_with_dontneed() {
+        let _guard = LOCK.lock().unwrap();
+        assert!(!MmapAllocator::new().dontneed());
+
+        // Shrink within the mmap path, and from the mmap path to the inner allocator.
+        for threshold in [1 << 20, 64 << 20] {
+            let allocator =
+                MmapAllocator::with_threshold(JEAllocator, threshold).with_dontneed(true);
+            assert!(allocator.dontneed());
+            let mut values: Vec<u8, _> = Vec::with_capacity_in(128 << 20, allocator);
+            values.extend(std::iter::repeat(1).take(128 << 20));
+            values.truncate(8 << 20);
+            values.shrink_to_fit();
+            assert_eq!(values.capacity(), 8 << 20);
+            assert!(values.iter().all(|value| *value == 1));
+        }
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_shrink_below_threshold() {
+        let _guard = LOCK.lock().unwrap();
+        MmapAllocator::enable_stats();
+        let allocator = MmapAllocator::with_threshold(JEAllocator, 64 << 10);
+        let before = MmapAllocator::stats();
+
+        // Neither of the sizes is a multiple of the page size.
+        let mut values: Vec<u8, _> = Vec::with_capacity_in((128 << 10) + 100, allocator);
+        values.extend((0..(128 << 10) + 100).map(|i| i as u8));
+        values.truncate((32 << 10) + 10);
+        values.shrink_to_fit();
+
+        // The buffer is handed over to the inner allocator, nothing of the mapping is kept.
+        let stats = MmapAllocator::stats();
+        assert_eq!(stats.mmap_bytes, before.mmap_bytes);
+        assert_eq!(stats.mmap_count, before.mmap_count);
+        assert_eq!(stats.fallback_bytes - before.fallback_bytes, (32 << 10) + 10);
+        assert!(values.iter().enumerate().all(|(i, value)| *value == i as u8));
+
+        // It's reallocated by the inner allocator afterwards.
+        values.truncate(1 << 10);
+        values.shrink_to_fit();
+        values.reserve_exact(16 << 10);
+        assert!(values.iter().enumerate().all(|(i, value)| *value == i as u8));
+
+        drop(values);
+        assert_eq!(MmapAllocator::stats(), before);
+    }
+
+    #[test]
+    fn test_with_numa_node() {
+        let _guard = LOCK.lock().unwrap();
+        assert_eq!(MmapAllocator::new().numa_node(), None);
+
+        // Node 0 always exists, the nodes out of range are left with the default policy.
+        for node in [0, 4096] {
+            let allocator =
+                MmapAllocator::with_threshold(JEAllocator, 1 << 20).with_numa_node(Some(node));
+            assert_eq!(allocator.numa_node(), Some(node));
+            let mut values: Vec<u8, _> = Vec::with_capacity_in(2 << 20, allocator);
+            values.extend(std::iter::repeat(1).take(2 << 20));
+            // Grow by mremap, the mapping may be moved.
+            values.reserve_exact(6 << 20);
+            values.extend(std::iter::repeat(2).take(6 << 20));
+            assert_eq!(values.len(), 8 << 20);
+            assert!(values[..2 << 20].iter().all(|value| *value == 1));
+            assert!(values[2 << 20..].iter().all(|value| *value == 2));
+        }
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_dealloc_failure() {
+        use std::alloc::Allocator;
+        use std::alloc::Layout;
+        use std::ptr::NonNull;
+
+        use super::AllocatorStats;
+        use crate::runtime::catch_unwind;
+        use crate::runtime::MemStat;
+        use crate::runtime::ThreadTracker;
+
+        let _guard = LOCK.lock().unwrap();
+        MmapAllocator::enable_stats();
+        let allocator = MmapAllocator::with_threshold(JEAllocator, 1 << 20);
+        let layout = Layout::from_size_align(2 << 20, 8).unwrap();
+        let before = MmapAllocator::stats();
+
+        let mem_stat = MemStat::create("test_dealloc_failure".to_string());
+        let ptr = {
+            let _entered = ThreadTracker::enter(Some(mem_stat.clone()));
+            let ptr = allocator.allocate(layout).unwrap().cast::<u8>();
+            // munmap rejects the address not aligned to the page.
+            let unaligned = unsafe { NonNull::new_unchecked(ptr.as_ptr().add(1)) };
+            let result = catch_unwind(|| unsafe { allocator.deallocate(unaligned, layout) });
+            // It only panics in the debug builds.
+            assert_eq!(result.is_err(), cfg!(debug_assertions));
+            ptr
+        };
+        assert_eq!(
+            MmapAllocator::stats().dealloc_failures,
+            before.dealloc_failures + 1
+        );
+        // The leaked mapping is still tracked.
+        assert!(mem_stat.get_memory_usage() >= layout.size() as i64);
+
+        // The mapping is left intact.
+        unsafe { allocator.deallocate(ptr, layout) };
+        assert_eq!(MmapAllocator::stats(), AllocatorStats {
+            dealloc_failures: before.dealloc_failures + 1,
+            ..before
+        });
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_supports_populate_write() {
+        use super::linux::linux_kernel_version;
+        use super::linux::supports_populate_write;
+
+        let expected = linux_kernel_version() >= (5, 14, 0);
+        // The first call resolves the flag, the following ones read the cached result.
+        assert_eq!(supports_populate_write(), expected);
+        assert_eq!(supports_populate_write(), expected);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_touch_pages() {
+        use std::ptr::NonNull;
+
+        use super::linux::touch_pages;
+
+        let size = 4 << 20;
+        let mut buf = vec![0u8; size];
+        for (i, v) in buf.iter_mut().enumerate() {
+            *v = i as u8;
+        }
+        // The pages are faulted in without changing their contents.
+        unsafe { touch_pages(NonNull::new(buf.as_mut_ptr().cast()).unwrap(), size) };
+        assert!(buf.iter().enumerate().all(|(i, v)| *v == i as u8));
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_checked_page_size() {
+        use super::linux::checked_page_size;
+
+        assert_eq!(checked_page_size(4096), 4096);
+        assert_eq!(checked_page_size(64 << 10), 64 << 10);
+        // The failure and the sizes not of a power of two fall back to 4 KiB.
+        assert_eq!(checked_page_size(-1), 4096);
+        assert_eq!(checked_page_size(0), 4096);
+        assert_eq!(checked_page_size(4095), 4096);
+    }
 
     #[test]
     fn test_semver() {
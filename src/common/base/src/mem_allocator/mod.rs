@@ -20,7 +20,11 @@ mod std_;
 pub use default::DefaultAllocator;
 pub use global::GlobalAllocator;
 pub use jemalloc::JEAllocator;
+pub use mmap::AllocatorStats;
+pub use mmap::DefaultMmapConfig;
 pub use mmap::MmapAllocator;
+pub use mmap::MmapConfig;
+pub use mmap::MmapOptions;
 pub use std_::StdAllocator;
 
 mod default;
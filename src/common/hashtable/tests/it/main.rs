@@ -181,3 +181,38 @@ fn test_dictionary_hash_map() {
         }
     }
 }
+
+#[test]
+fn test_short_string_hash_map_arena_with_skewed_keys() {
+    // The keys are copied into the arena only when they are inserted for the first time,
+    // repeated keys don't grow the arena.
+    let urls = (0..100)
+        .map(|i| format!("https://databend.rs/doc/sql-functions/page-{i}"))
+        .collect::<Vec<_>>();
+    let arena = Arc::new(Bump::new());
+    let mut hashtable = ShortStringHashMap::<[u8], u64>::new(arena.clone());
+    let mut insert = |key: &[u8]| match unsafe { hashtable.insert_and_entry(key) } {
+        Ok(mut e) => {
+            e.write(1);
+        }
+        Err(mut e) => {
+            *e.get_mut() += 1;
+        }
+    };
+
+    for url in urls.iter() {
+        insert(url.as_bytes());
+    }
+    let allocated_bytes = arena.allocated_bytes();
+
+    for i in 0..100000 {
+        // Most of the keys are the first few urls.
+        let url = match i % 101 {
+            0 => &urls[i % urls.len()],
+            _ => &urls[i % 7],
+        };
+        insert(url.as_bytes());
+    }
+    assert_eq!(arena.allocated_bytes(), allocated_bytes);
+    assert_eq!(hashtable.len(), urls.len());
+}
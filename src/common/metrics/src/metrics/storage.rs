@@ -14,11 +14,20 @@
 
 use std::sync::LazyLock;
 
+use prometheus_client::encoding::EncodeLabelSet;
+
 use crate::register_counter;
+use crate::register_counter_family;
 use crate::register_histogram_in_milliseconds;
 use crate::Counter;
+use crate::Family;
 use crate::Histogram;
 
+#[derive(Clone, Debug, EncodeLabelSet, Hash, PartialEq, Eq)]
+struct ColumnLabels {
+    column_id: String,
+}
+
 // Common metrics.
 static OMIT_FILTER_ROWGROUPS: LazyLock<Counter> =
     LazyLock::new(|| register_counter("omit_filter_rowgroups"));
@@ -187,6 +196,14 @@ static BYTES_BLOCK_BLOOM_PRUNING_AFTER: LazyLock<Counter> =
     LazyLock::new(|| register_counter("fuse_bytes_block_bloom_pruning_after"));
 static PRUNING_PREWHERE_NUMS: LazyLock<Counter> =
     LazyLock::new(|| register_counter("fuse_pruning_prewhere_nums"));
+static NATIVE_COLUMN_PAGES_READ: LazyLock<Family<ColumnLabels, Counter>> =
+    LazyLock::new(|| register_counter_family("fuse_native_column_pages_read"));
+static NATIVE_COLUMN_PAGES_SKIPPED: LazyLock<Family<ColumnLabels, Counter>> =
+    LazyLock::new(|| register_counter_family("fuse_native_column_pages_skipped"));
+static RUNTIME_FILTER_EXPRESSION_KEYS_SKIPPED: LazyLock<Counter> =
+    LazyLock::new(|| register_counter("fuse_runtime_filter_expression_keys_skipped"));
+static RUNTIME_FILTER_BLOOM_INDEX_PRUNED_PARTS: LazyLock<Counter> =
+    LazyLock::new(|| register_counter("fuse_runtime_filter_bloom_index_pruned_parts"));
 static PRUNING_MILLISECONDS: LazyLock<Histogram> =
     LazyLock::new(|| register_histogram_in_milliseconds("fuse_pruning_milliseconds"));
 static DELETION_BLOCK_RANGE_PRUNED_NUMS: LazyLock<Counter> =
@@ -555,6 +572,36 @@ pub fn metrics_inc_pruning_prewhere_nums(c: u64) {
     PRUNING_PREWHERE_NUMS.inc_by(c);
 }
 
+/// The bloom runtime filters not applied by the scan, as their probe keys are derived
+/// from expressions instead of the columns of the table.
+pub fn metrics_inc_runtime_filter_expression_keys_skipped(c: u64) {
+    RUNTIME_FILTER_EXPRESSION_KEYS_SKIPPED.inc_by(c);
+}
+
+/// The parts skipped by the scan, as the bloom filter index of their blocks doesn't match
+/// any value of the inlist runtime filters.
+pub fn metrics_inc_runtime_filter_bloom_index_pruned_parts(c: u64) {
+    RUNTIME_FILTER_BLOOM_INDEX_PRUNED_PARTS.inc_by(c);
+}
+
+/// The pages of the leaf column decoded by the native scan.
+pub fn metrics_inc_native_column_pages_read(column_id: u32, c: u64) {
+    NATIVE_COLUMN_PAGES_READ
+        .get_or_create(&ColumnLabels {
+            column_id: column_id.to_string(),
+        })
+        .inc_by(c);
+}
+
+/// The pages of the leaf column skipped by the prewhere filter or the runtime filters.
+pub fn metrics_inc_native_column_pages_skipped(column_id: u32, c: u64) {
+    NATIVE_COLUMN_PAGES_SKIPPED
+        .get_or_create(&ColumnLabels {
+            column_id: column_id.to_string(),
+        })
+        .inc_by(c);
+}
+
 pub fn metrics_inc_pruning_milliseconds(c: u64) {
     PRUNING_MILLISECONDS.observe(c as f64);
 }
@@ -16,9 +16,11 @@ use std::sync::LazyLock;
 
 use crate::register_counter;
 use crate::register_counter_family;
+use crate::register_gauge_family;
 use crate::register_histogram_family_in_milliseconds;
 use crate::Counter;
 use crate::Family;
+use crate::Gauge;
 use crate::Histogram;
 use crate::VecLabels;
 
@@ -26,6 +28,10 @@ pub static AGGREGATE_PARTIAL_SPILL_CELL_COUNT: LazyLock<Counter> =
     LazyLock::new(|| register_counter("transform_aggregate_partial_spill_cell_count"));
 pub static AGGREGATE_PARTIAL_HASHTABLE_ALLOCATED_BYTES: LazyLock<Counter> =
     LazyLock::new(|| register_counter("transform_aggregate_partial_hashtable_allocated_bytes"));
+pub static GROUP_BY_PARTIAL_HASHTABLE_KEYS: LazyLock<Family<VecLabels, Gauge>> =
+    LazyLock::new(|| register_gauge_family("transform_group_by_partial_hashtable_keys"));
+pub static GROUP_BY_PARTIAL_HASHTABLE_BYTES: LazyLock<Family<VecLabels, Gauge>> =
+    LazyLock::new(|| register_gauge_family("transform_group_by_partial_hashtable_bytes"));
 pub static SPILL_COUNT: LazyLock<Family<VecLabels, Counter>> =
     LazyLock::new(|| register_counter_family("transform_spill_count"));
 pub static SPILL_WRITE_COUNT: LazyLock<Family<VecLabels, Counter>> =
@@ -74,6 +80,34 @@ pub fn metrics_inc_aggregate_partial_hashtable_allocated_bytes(c: u64) {
     AGGREGATE_PARTIAL_HASHTABLE_ALLOCATED_BYTES.inc_by(c);
 }
 
+fn group_by_partial_labels(query_id: &str, processor_id: usize) -> VecLabels {
+    vec![
+        ("query_id", query_id.to_string()),
+        ("processor_id", processor_id.to_string()),
+    ]
+}
+
+pub fn metrics_set_group_by_partial_hashtable_size(
+    query_id: &str,
+    processor_id: usize,
+    keys: usize,
+    bytes: usize,
+) {
+    let labels = &group_by_partial_labels(query_id, processor_id);
+    GROUP_BY_PARTIAL_HASHTABLE_KEYS
+        .get_or_create(labels)
+        .set(keys as i64);
+    GROUP_BY_PARTIAL_HASHTABLE_BYTES
+        .get_or_create(labels)
+        .set(bytes as i64);
+}
+
+pub fn metrics_remove_group_by_partial_hashtable_size(query_id: &str, processor_id: usize) {
+    let labels = &group_by_partial_labels(query_id, processor_id);
+    GROUP_BY_PARTIAL_HASHTABLE_KEYS.remove(labels);
+    GROUP_BY_PARTIAL_HASHTABLE_BYTES.remove(labels);
+}
+
 pub fn metrics_inc_group_by_spill_write_count() {
     let labels = &vec![("spill", "group_by_spill".to_string())];
     SPILL_WRITE_COUNT.get_or_create(labels).inc();
@@ -943,6 +943,24 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             }),
         },
     );
+    // The lambda comes before the array argument, e.g. `array_map(x -> x * 2, [1, 2, 3])`.
+    let function_call_with_lambda_first = map(
+        rule! {
+            #function_name
+            ~ "(" ~ #ident ~ "->" ~ #subexpr(0) ~ "," ~ #subexpr(0) ~ ")"
+        },
+        |(name, _, param, _, expr, _, arg, _)| ExprElement::FunctionCall {
+            distinct: false,
+            name,
+            args: vec![arg],
+            params: vec![],
+            window: None,
+            lambda: Some(Lambda {
+                params: vec![param],
+                expr: Box::new(expr),
+            }),
+        },
+    );
     let function_call_with_window = map(
         rule! {
             #function_name
@@ -1207,16 +1225,17 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             | #extract : "`EXTRACT((YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | WEEK) FROM ...)`"
             | #date_part : "`DATE_PART((YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | WEEK), ...)`"
             | #position : "`POSITION(... IN ...)`"
+            | #substring : "`SUBSTRING(... [FROM ...] [FOR ...])`"
         ),
         rule!(
-            #substring : "`SUBSTRING(... [FROM ...] [FOR ...])`"
-            | #trim : "`TRIM(...)`"
+            #trim : "`TRIM(...)`"
             | #trim_from : "`TRIM([(BOTH | LEADEING | TRAILING) ... FROM ...)`"
             | #is_distinct_from: "`... IS [NOT] DISTINCT FROM ...`"
             | #chain_function_call : "x.function(...)"
             | #list_comprehensions: "[expr for x in ... [if ...]]"
             | #count_all_with_window : "`COUNT(*) OVER ...`"
             | #function_call_with_lambda : "`function(..., x -> ...)`"
+            | #function_call_with_lambda_first : "`function(x -> ..., ...)`"
             | #function_call_with_window : "`function(...) OVER ([ PARTITION BY <expr>, ... ] [ ORDER BY <expr>, ... ] [ <window frame> ])`"
             | #function_call_with_params : "`function(...)(...)`"
             | #function_call : "`function(...)`"
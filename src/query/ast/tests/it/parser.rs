@@ -827,6 +827,7 @@ fn test_expr() {
         r#"COUNT() OVER (ORDER BY hire_date ROWS 3 PRECEDING)"#,
         r#"ARRAY_APPLY([1,2,3], x -> x + 1)"#,
         r#"ARRAY_FILTER(col, y -> y % 2 = 0)"#,
+        r#"ARRAY_MAP(x -> x * 2, [1,2,3])"#,
         r#"(current_timestamp, current_timestamp(), now())"#,
     ];
 
@@ -0,0 +1,84 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::BlockMetaInfoPtr;
+
+/// The lineage of a block emitted by the scan, records the part it derived from.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct LineageMeta {
+    pub part_location: String,
+    pub segment_idx: Option<usize>,
+    pub block_idx: Option<usize>,
+    /// The meta wrapped by the lineage, such as `InternalColumnMeta` and `StreamColumnMeta`.
+    pub inner: Option<BlockMetaInfoPtr>,
+}
+
+#[typetag::serde(name = "lineage_meta")]
+impl BlockMetaInfo for LineageMeta {
+    fn equals(&self, info: &Box<dyn BlockMetaInfo>) -> bool {
+        LineageMeta::downcast_ref_from(info).is_some_and(|other| self == other)
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        Box::new(self.clone())
+    }
+}
+
+impl LineageMeta {
+    pub fn from_meta(info: &BlockMetaInfoPtr) -> Result<&LineageMeta> {
+        LineageMeta::downcast_ref_from(info).ok_or_else(|| {
+            ErrorCode::Internal("Cannot downcast from BlockMetaInfo to LineageMeta.")
+        })
+    }
+
+    /// Split the meta into the lineage and the meta wrapped by it,
+    /// the meta is returned as it is if it's not a lineage.
+    pub fn split(meta: BlockMetaInfoPtr) -> (Option<LineageMeta>, Option<BlockMetaInfoPtr>) {
+        if LineageMeta::downcast_ref_from(&meta).is_none() {
+            return (None, Some(meta));
+        }
+        let mut lineage = LineageMeta::downcast_from(meta).unwrap();
+        let inner = lineage.inner.take();
+        (Some(lineage), inner)
+    }
+
+    /// Wrap the inner meta with the lineage if any, the reverse of `split`.
+    pub fn wrap(
+        lineage: Option<LineageMeta>,
+        inner: Option<BlockMetaInfoPtr>,
+    ) -> Option<BlockMetaInfoPtr> {
+        match lineage {
+            Some(lineage) => Some(Box::new(LineageMeta { inner, ..lineage })),
+            None => inner,
+        }
+    }
+}
+
+pub fn gen_lineage_meta(
+    inner: Option<BlockMetaInfoPtr>,
+    part_location: &str,
+    segment_idx: Option<usize>,
+    block_idx: Option<usize>,
+) -> LineageMeta {
+    LineageMeta {
+        part_location: part_location.to_string(),
+        segment_idx,
+        block_idx,
+        inner,
+    }
+}
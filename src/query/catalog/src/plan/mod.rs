@@ -15,6 +15,7 @@
 mod agg_index;
 mod datasource;
 mod internal_column;
+mod lineage;
 mod partition;
 mod partition_statistics;
 mod projection;
@@ -25,6 +26,7 @@ mod stream_column;
 pub use agg_index::*;
 pub use datasource::*;
 pub use internal_column::*;
+pub use lineage::*;
 pub use partition::*;
 pub use partition_statistics::PartStatistics;
 pub use projection::Projection;
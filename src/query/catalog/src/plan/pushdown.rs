@@ -112,6 +112,7 @@ pub struct TopK {
     /// (only used for fuse engine, for parquet table, we will use `leaf_id`).
     pub field: TableField,
     pub asc: bool,
+    pub nulls_first: bool,
     /// The index in `table_schema.leaf_fields()`.
     /// It's only used for external parquet files reading.
     pub leaf_id: usize,
@@ -146,6 +147,7 @@ impl PushDownInfo {
                     limit: self.limit.unwrap(),
                     field: f.clone(),
                     asc: order.1,
+                    nulls_first: order.2,
                     leaf_id,
                 };
                 Some(top_k)
@@ -165,6 +167,14 @@ impl PushDownInfo {
         }
     }
 
+    /// The limit the scan can stop reading at, the rows must not be filtered or sorted
+    /// after the scan, otherwise the rows to emit are unknown until the scan finishes.
+    pub fn scan_limit_of_push_downs(push_downs: Option<&PushDownInfo>) -> Option<usize> {
+        push_downs
+            .filter(|p| p.order_by.is_empty() && p.filters.is_none())
+            .and_then(|p| p.limit)
+    }
+
     pub fn projection_of_push_downs(
         schema: &TableSchema,
         push_downs: Option<&PushDownInfo>,
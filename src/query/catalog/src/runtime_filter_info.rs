@@ -15,11 +15,27 @@
 use databend_common_expression::Expr;
 use xorf::BinaryFuse8;
 
+/// How a group of the bloom runtime filters of a table are combined to select the probe rows.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RuntimeFilterCombinator {
+    /// The rows must pass all the filters of the group, e.g. the join keys are conjunctive.
+    #[default]
+    And,
+    /// The rows pass if any filter of the group passes, e.g. the join condition is
+    /// `probe.a = build.x OR probe.b = build.y`.
+    Or,
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct RuntimeFilterInfo {
     inlist: Vec<Expr<String>>,
     min_max: Vec<Expr<String>>,
     bloom: Vec<(String, BinaryFuse8)>,
+    // The groups of the bloom filters built from the disjunctive join conditions, the filters
+    // of a group are combined with OR, and the groups are ANDed with the other filters.
+    disjunctive_bloom: Vec<Vec<(String, BinaryFuse8)>>,
+    // The build side of the join is empty, no probe rows can match.
+    build_empty: bool,
 }
 
 impl RuntimeFilterInfo {
@@ -35,6 +51,18 @@ impl RuntimeFilterInfo {
         self.min_max.push(expr);
     }
 
+    pub fn add_disjunctive_bloom(&mut self, blooms: Vec<(String, BinaryFuse8)>) {
+        self.disjunctive_bloom.push(blooms);
+    }
+
+    pub fn set_build_empty(&mut self) {
+        self.build_empty = true;
+    }
+
+    pub fn is_build_empty(&self) -> bool {
+        self.build_empty
+    }
+
     pub fn get_inlist(&self) -> &Vec<Expr<String>> {
         &self.inlist
     }
@@ -47,6 +75,10 @@ impl RuntimeFilterInfo {
         &self.min_max
     }
 
+    pub fn get_disjunctive_bloom(&self) -> &Vec<Vec<(String, BinaryFuse8)>> {
+        &self.disjunctive_bloom
+    }
+
     pub fn blooms(self) -> Vec<(String, BinaryFuse8)> {
         self.bloom
     }
@@ -59,7 +91,15 @@ impl RuntimeFilterInfo {
         self.min_max
     }
 
+    pub fn disjunctive_blooms(self) -> Vec<Vec<(String, BinaryFuse8)>> {
+        self.disjunctive_bloom
+    }
+
     pub fn is_empty(&self) -> bool {
-        self.inlist.is_empty() && self.bloom.is_empty() && self.min_max.is_empty()
+        !self.build_empty
+            && self.inlist.is_empty()
+            && self.bloom.is_empty()
+            && self.disjunctive_bloom.is_empty()
+            && self.min_max.is_empty()
     }
 }
@@ -19,6 +19,7 @@ use std::collections::HashSet;
 use std::fmt::Display;
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
 use std::time::SystemTime;
 
 use dashmap::DashMap;
@@ -26,6 +27,7 @@ use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::Column;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Expr;
 use databend_common_expression::FunctionContext;
@@ -61,6 +63,30 @@ use crate::table::Table;
 
 pub type MaterializedCtesBlocks = Arc<RwLock<HashMap<(usize, usize), Arc<RwLock<Vec<DataBlock>>>>>>;
 
+/// Rewrite the values of a column read by the scan, such as hashing or redacting them
+/// for masking policies. It must preserve the data type and the number of rows.
+pub type ColumnMask = Arc<dyn Fn(&Column) -> Result<Column> + Send + Sync>;
+
+/// A page the scan failed to decode, it's collected instead of aborting the query
+/// if the scan is asked to tolerate the corrupted data.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScanDecodeError {
+    pub part_location: String,
+    pub column: String,
+    pub page: usize,
+    pub message: String,
+}
+
+/// The time the scan spent on a part, from initialising its readers to finishing it,
+/// along with the pages read and skipped, collected if the scan is asked to profile the parts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScanPartProfile {
+    pub part_location: String,
+    pub elapsed: Duration,
+    pub pages_read: u64,
+    pub pages_skipped: u64,
+}
+
 #[derive(Debug)]
 pub struct ProcessInfo {
     pub id: String,
@@ -131,6 +157,9 @@ pub trait TableContext: Send + Sync {
     fn get_status_info(&self) -> String;
     fn set_status_info(&self, info: &str);
 
+    /// The time the query is created, the time limits of the query are counted from it.
+    fn get_created_time(&self) -> SystemTime;
+
     fn get_partition(&self) -> Option<PartInfoPtr>;
     fn get_partitions(&self, num: usize) -> Vec<PartInfoPtr>;
     fn partition_num(&self) -> usize {
@@ -226,6 +255,10 @@ pub trait TableContext: Send + Sync {
 
     fn get_segment_locations(&self) -> Result<Vec<Location>>;
 
+    fn add_block_location(&self, block_loc: String) -> Result<()>;
+
+    fn get_block_locations(&self) -> Result<Vec<String>>;
+
     fn add_file_status(&self, file_path: &str, file_status: FileStatus) -> Result<()>;
 
     fn get_copy_status(&self) -> Arc<CopyStatus>;
@@ -245,9 +278,37 @@ pub trait TableContext: Send + Sync {
 
     fn get_bloom_runtime_filter_with_id(&self, id: usize) -> Vec<(String, BinaryFuse8)>;
 
+    fn get_disjunctive_bloom_runtime_filter_with_id(
+        &self,
+        id: usize,
+    ) -> Vec<Vec<(String, BinaryFuse8)>>;
+
     fn get_inlist_runtime_filter_with_id(&self, id: usize) -> Vec<Expr<String>>;
 
     fn get_min_max_runtime_filter_with_id(&self, id: usize) -> Vec<Expr<String>>;
 
     fn has_bloom_runtime_filters(&self, id: usize) -> bool;
+
+    fn is_runtime_filter_build_empty(&self, id: usize) -> bool;
+
+    /// Add the rows emitted by a scan of the table under a pushed down limit,
+    /// returns the total rows emitted by all the scan processors of the table.
+    fn add_limited_scan_rows(&self, table_index: usize, rows: usize) -> usize;
+
+    fn get_limited_scan_rows(&self, table_index: usize) -> usize;
+
+    /// Set the masks of the columns of the table, keyed by the column name.
+    fn set_column_masks(&self, table_index: usize, masks: HashMap<String, ColumnMask>);
+
+    fn get_column_masks(&self, table_index: usize) -> HashMap<String, ColumnMask>;
+
+    /// Collect the pages failed to be decoded by the scans of the table.
+    fn add_scan_decode_error(&self, table_index: usize, error: ScanDecodeError);
+
+    fn get_scan_decode_errors(&self, table_index: usize) -> Vec<ScanDecodeError>;
+
+    /// Collect the profiles of the parts decoded by the scans of the table.
+    fn add_scan_part_profile(&self, table_index: usize, profile: ScanPartProfile);
+
+    fn get_scan_part_profiles(&self, table_index: usize) -> Vec<ScanPartProfile>;
 }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use databend_common_catalog::plan::Projection;
@@ -48,6 +49,7 @@ pub async fn do_refresh_virtual_column(
     ctx: Arc<dyn TableContext>,
     virtual_exprs: Vec<String>,
     segment_locs: Option<Vec<Location>>,
+    block_locs: Option<Vec<String>>,
 ) -> Result<()> {
     if virtual_exprs.is_empty() {
         return Ok(());
@@ -101,6 +103,8 @@ pub async fn do_refresh_virtual_column(
     } else {
         snapshot.segments.clone()
     };
+    // If no block locations are specified, iterates through all blocks of the segments
+    let block_locs = block_locs.map(HashSet::<String>::from_iter);
 
     // Read source variant columns and extract inner fields as virtual columns.
     for (location, ver) in segment_locs {
@@ -115,6 +119,11 @@ pub async fn do_refresh_virtual_column(
 
         let block_metas = segment_info.block_metas()?;
         for block_meta in block_metas {
+            if let Some(block_locs) = &block_locs {
+                if !block_locs.contains(&block_meta.location.0) {
+                    continue;
+                }
+            }
             let block = block_reader
                 .read_by_meta(&settings, &block_meta, &storage_format)
                 .await?;
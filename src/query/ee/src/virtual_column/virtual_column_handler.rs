@@ -79,8 +79,9 @@ impl VirtualColumnHandler for RealVirtualColumnHandler {
         ctx: Arc<dyn TableContext>,
         virtual_columns: Vec<String>,
         segment_locs: Option<Vec<Location>>,
+        block_locs: Option<Vec<String>>,
     ) -> Result<()> {
-        do_refresh_virtual_column(fuse_table, ctx, virtual_columns, segment_locs).await
+        do_refresh_virtual_column(fuse_table, ctx, virtual_columns, segment_locs, block_locs).await
     }
 }
 
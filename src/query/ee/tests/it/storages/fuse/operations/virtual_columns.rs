@@ -56,7 +56,7 @@ async fn test_fuse_do_refresh_virtual_column() -> Result<()> {
     let storage_format = write_settings.storage_format;
 
     let segment_locs = Some(snapshot.segments.clone());
-    do_refresh_virtual_column(fuse_table, table_ctx, virtual_columns, segment_locs).await?;
+    do_refresh_virtual_column(fuse_table, table_ctx, virtual_columns, segment_locs, None).await?;
 
     let segment_reader =
         MetaReaders::segment_info_reader(fuse_table.get_operator(), table_schema.clone());
@@ -91,3 +91,63 @@ async fn test_fuse_do_refresh_virtual_column() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_do_refresh_virtual_column_of_blocks() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    fixture
+        .default_session()
+        .get_settings()
+        .set_retention_period(0)?;
+    fixture.create_default_database().await?;
+    fixture.create_variant_table().await?;
+
+    let number_of_block = 2;
+    append_variant_sample_data(number_of_block, &fixture).await?;
+
+    let table = fixture.latest_default_table().await?;
+    let table_schema = table.schema();
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let dal = fuse_table.get_operator_ref();
+
+    let virtual_columns = vec!["v['a']".to_string(), "v[0]".to_string()];
+    let table_ctx = fixture.new_query_ctx().await?;
+
+    let snapshot = fuse_table.read_table_snapshot().await?.unwrap();
+    let segment_reader =
+        MetaReaders::segment_info_reader(fuse_table.get_operator(), table_schema.clone());
+    let mut block_locs = vec![];
+    for (location, ver) in &snapshot.segments {
+        let segment_info = segment_reader
+            .read(&LoadParams {
+                location: location.to_string(),
+                len_hint: None,
+                ver: *ver,
+                put_cache: false,
+            })
+            .await?;
+        for block_meta in segment_info.block_metas()? {
+            block_locs.push(block_meta.location.0.clone());
+        }
+    }
+    assert!(block_locs.len() > 1);
+
+    // Only the first block is refreshed.
+    let segment_locs = Some(snapshot.segments.clone());
+    do_refresh_virtual_column(
+        fuse_table,
+        table_ctx,
+        virtual_columns,
+        segment_locs,
+        Some(vec![block_locs[0].clone()]),
+    )
+    .await?;
+
+    for (i, block_loc) in block_locs.iter().enumerate() {
+        let virtual_loc = TableMetaLocationGenerator::gen_virtual_block_location(block_loc);
+        assert_eq!(dal.is_exist(&virtual_loc).await?, i == 0);
+    }
+
+    Ok(())
+}
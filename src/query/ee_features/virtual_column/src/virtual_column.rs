@@ -61,6 +61,7 @@ pub trait VirtualColumnHandler: Sync + Send {
         ctx: Arc<dyn TableContext>,
         virtual_columns: Vec<String>,
         segment_locs: Option<Vec<Location>>,
+        block_locs: Option<Vec<String>>,
     ) -> Result<()>;
 }
 
@@ -116,9 +117,10 @@ impl VirtualColumnHandlerWrapper {
         ctx: Arc<dyn TableContext>,
         virtual_columns: Vec<String>,
         segment_locs: Option<Vec<Location>>,
+        block_locs: Option<Vec<String>>,
     ) -> Result<()> {
         self.handler
-            .do_refresh_virtual_column(fuse_table, ctx, virtual_columns, segment_locs)
+            .do_refresh_virtual_column(fuse_table, ctx, virtual_columns, segment_locs, block_locs)
             .await
     }
 }
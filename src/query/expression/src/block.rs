@@ -496,17 +496,17 @@ impl DataBlock {
         default_vals: &[Scalar],
         num_rows: usize,
     ) -> Result<DataBlock> {
-        let default_opt_vals: Vec<Option<Scalar>> = default_vals
+        // Build the entries from the default values directly, each value is only cloned once.
+        let columns = schema
+            .fields()
             .iter()
-            .map(|default_val| Some(default_val.to_owned()))
+            .zip(default_vals.iter())
+            .map(|(field, default_val)| {
+                BlockEntry::new(field.data_type().clone(), Value::Scalar(default_val.to_owned()))
+            })
             .collect();
 
-        Self::create_with_default_value_and_chunk(
-            schema,
-            &ArrowChunk::<ArrayRef>::new(vec![]),
-            &default_opt_vals[0..],
-            num_rows,
-        )
+        Ok(DataBlock::new(columns, num_rows))
     }
 
     // If block_column_ids not contain schema.field[i].column_id,
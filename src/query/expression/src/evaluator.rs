@@ -12,12 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
+use std::hash::Hash;
+use std::hash::Hasher;
 use std::ops::Not;
 
 use databend_common_arrow::arrow::bitmap;
 use databend_common_arrow::arrow::bitmap::Bitmap;
 use databend_common_arrow::arrow::bitmap::MutableBitmap;
+use databend_common_arrow::arrow::buffer::Buffer;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_exception::Span;
@@ -39,9 +44,12 @@ use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::NullableType;
 use crate::types::NumberScalar;
+use crate::types::UInt64Type;
+use crate::types::ValueType;
 use crate::values::Column;
 use crate::values::ColumnBuilder;
 use crate::values::Scalar;
+use crate::values::ScalarRef;
 use crate::values::Value;
 use crate::BlockEntry;
 use crate::ColumnIndex;
@@ -967,41 +975,31 @@ impl<'a> Evaluator<'a> {
         lambda_expr: &RemoteExpr,
     ) -> Result<Value<AnyType>> {
         let expr = lambda_expr.as_expr(self.fn_registry);
+        if func_name == "array_zip_with" {
+            return self.run_array_zip_with(args, &expr);
+        }
+        if func_name == "array_scan" {
+            return self.run_array_scan(args, &expr);
+        }
         // TODO: Support multi args
         match &args[0] {
             Value::Scalar(s) => match s {
                 Scalar::Array(c) => {
-                    let entry = BlockEntry::new(c.data_type(), Value::Column(c.clone()));
-                    let block = DataBlock::new(vec![entry], c.len());
-
-                    let evaluator = Evaluator::new(&block, self.func_ctx, self.fn_registry);
-                    let result = evaluator.run(&expr)?;
-                    let result_col = result.convert_to_full_column(expr.data_type(), c.len());
-
-                    let val = if func_name == "array_filter" {
-                        let result_col = result_col.remove_nullable();
-                        let bitmap = result_col.as_boolean().unwrap();
-                        let filtered_inner_col = c.filter(bitmap);
-                        Value::Scalar(Scalar::Array(filtered_inner_col))
-                    } else {
-                        Value::Scalar(Scalar::Array(result_col))
-                    };
-                    Ok(val)
+                    // A scalar array is evaluated as an array column with only one row.
+                    let offsets = vec![0, c.len() as u64].into();
+                    let col = self.run_array_lambda(func_name, c.clone(), offsets, &expr)?;
+                    Ok(Value::Scalar(col.index(0).unwrap().to_owned()))
                 }
                 _ => unreachable!(),
             },
             Value::Column(c) => {
-                let (inner_col, inner_ty, offsets, validity) = match c {
-                    Column::Array(box array_col) => (
-                        array_col.values.clone(),
-                        array_col.values.data_type(),
-                        array_col.offsets.clone(),
-                        None,
-                    ),
+                let (inner_col, offsets, validity) = match c {
+                    Column::Array(box array_col) => {
+                        (array_col.values.clone(), array_col.offsets.clone(), None)
+                    }
                     Column::Nullable(box nullable_col) => match &nullable_col.column {
                         Column::Array(box array_col) => (
                             array_col.values.clone(),
-                            array_col.values.data_type(),
                             array_col.offsets.clone(),
                             Some(nullable_col.validity.clone()),
                         ),
@@ -1009,51 +1007,369 @@ impl<'a> Evaluator<'a> {
                     },
                     _ => unreachable!(),
                 };
-                let entry = BlockEntry::new(inner_ty, Value::Column(inner_col.clone()));
-                let block = DataBlock::new(vec![entry], inner_col.len());
-
-                let evaluator = Evaluator::new(&block, self.func_ctx, self.fn_registry);
-                let result = evaluator.run(&expr)?;
-                let result_col = result.convert_to_full_column(expr.data_type(), inner_col.len());
-
-                let array_col = if func_name == "array_filter" {
-                    let result_col = result_col.remove_nullable();
-                    let bitmap = result_col.as_boolean().unwrap();
-                    let filtered_inner_col = inner_col.filter(bitmap);
-                    // generate new offsets after filter.
-                    let mut new_offset = 0;
-                    let mut filtered_offsets = Vec::with_capacity(offsets.len());
-                    filtered_offsets.push(0);
-                    for offset in offsets.windows(2) {
-                        let off = offset[0] as usize;
-                        let len = (offset[1] - offset[0]) as usize;
-                        let unset_count = bitmap.null_count_range(off, len);
-                        new_offset += (len - unset_count) as u64;
-                        filtered_offsets.push(new_offset);
+                let col = self.run_array_lambda(func_name, inner_col, offsets, &expr)?;
+                let col = match validity {
+                    Some(validity) => col.wrap_nullable(Some(validity)),
+                    None => col,
+                };
+                Ok(Value::Column(col))
+            }
+        }
+    }
+
+    /// Evaluate `array_zip_with`, the elements at the same position of the arrays are passed
+    /// to the lambda expression as multiple columns. The arrays of each row are truncated to
+    /// the shortest one, or an error is returned if `strict_array_zip_with` is enabled.
+    fn run_array_zip_with(&self, args: Vec<Value<AnyType>>, expr: &Expr) -> Result<Value<AnyType>> {
+        let len = args.iter().find_map(|arg| match arg {
+            Value::Column(col) => Some(col.len()),
+            _ => None,
+        });
+        let num_rows = len.unwrap_or(1);
+
+        let mut arrays = Vec::with_capacity(args.len());
+        let mut validity: Option<Bitmap> = None;
+        for arg in args {
+            let col = match arg {
+                Value::Scalar(Scalar::Array(c)) => {
+                    let data_type = DataType::Array(Box::new(c.data_type()));
+                    ColumnBuilder::repeat(&ScalarRef::Array(c), num_rows, &data_type).build()
+                }
+                Value::Column(c) => c,
+                _ => unreachable!(),
+            };
+            let array_col = match col {
+                Column::Array(box array_col) => array_col,
+                Column::Nullable(box nullable_col) => {
+                    validity = Some(match validity {
+                        Some(v) => &v & (&nullable_col.validity),
+                        None => nullable_col.validity.clone(),
+                    });
+                    *nullable_col.column.into_array().unwrap()
+                }
+                _ => unreachable!(),
+            };
+            arrays.push(array_col);
+        }
+
+        let mut lengths = Vec::with_capacity(num_rows);
+        for row in 0..num_rows {
+            let row_lengths = arrays
+                .iter()
+                .map(|array| (array.offsets[row + 1] - array.offsets[row]) as usize);
+            let min_len = row_lengths.clone().min().unwrap();
+            let is_valid = validity.as_ref().map_or(true, |v| v.get_bit(row));
+            if self.func_ctx.strict_array_zip_with
+                && is_valid
+                && row_lengths.clone().any(|len| len != min_len)
+            {
+                return Err(ErrorCode::BadArguments(format!(
+                    "array_zip_with requires the arrays to have the same length, but got lengths {:?}",
+                    row_lengths.collect::<Vec<_>>()
+                )));
+            }
+            lengths.push(min_len);
+        }
+
+        // Keep the first `min_len` elements of each row in all the arrays.
+        let mut offsets = Vec::with_capacity(num_rows + 1);
+        offsets.push(0);
+        for len in lengths.iter() {
+            offsets.push(offsets.last().unwrap() + *len as u64);
+        }
+        let num_elements = *offsets.last().unwrap() as usize;
+        let entries = arrays
+            .iter()
+            .map(|array| {
+                let mut bitmap = MutableBitmap::from_len_zeroed(array.values.len());
+                for (row, len) in lengths.iter().enumerate() {
+                    let start = array.offsets[row] as usize;
+                    for i in start..start + len {
+                        bitmap.set(i, true);
                     }
+                }
+                let values = array.values.filter(&bitmap.into());
+                BlockEntry::new(values.data_type(), Value::Column(values))
+            })
+            .collect::<Vec<_>>();
+        let block = DataBlock::new(entries, num_elements);
 
-                    Column::Array(Box::new(ArrayColumn {
-                        values: filtered_inner_col,
-                        offsets: filtered_offsets.into(),
-                    }))
-                } else {
-                    Column::Array(Box::new(ArrayColumn {
-                        values: result_col,
-                        offsets,
-                    }))
+        let evaluator = Evaluator::new(&block, self.func_ctx, self.fn_registry);
+        let result = evaluator.run(expr)?;
+        let result_col = result.convert_to_full_column(expr.data_type(), num_elements);
+        let col = Column::Array(Box::new(ArrayColumn {
+            values: result_col,
+            offsets: offsets.into(),
+        }));
+
+        match len {
+            Some(_) => {
+                let col = match validity {
+                    Some(validity) => col.wrap_nullable(Some(validity)),
+                    None => col,
                 };
+                Ok(Value::Column(col))
+            }
+            None => Ok(Value::Scalar(col.index(0).unwrap().to_owned())),
+        }
+    }
+
+    /// Evaluate `array_scan`, the lambda expression is evaluated position by position,
+    /// on the accumulators and the elements at the same position of all the arrays at once,
+    /// the results are the accumulators of the next position.
+    fn run_array_scan(&self, args: Vec<Value<AnyType>>, expr: &Expr) -> Result<Value<AnyType>> {
+        let len = args.iter().find_map(|arg| match arg {
+            Value::Column(col) => Some(col.len()),
+            _ => None,
+        });
+        let num_rows = len.unwrap_or(1);
+        let acc_type = expr.data_type();
+
+        let col = match &args[0] {
+            Value::Scalar(Scalar::Array(c)) => {
+                let data_type = DataType::Array(Box::new(c.data_type()));
+                ColumnBuilder::repeat(&ScalarRef::Array(c.clone()), num_rows, &data_type).build()
+            }
+            Value::Column(c) => c.clone(),
+            _ => unreachable!(),
+        };
+        let (array_col, validity) = match col {
+            Column::Array(box array_col) => (array_col, None),
+            Column::Nullable(box nullable_col) => (
+                *nullable_col.column.into_array().unwrap(),
+                Some(nullable_col.validity),
+            ),
+            _ => unreachable!(),
+        };
+        let lengths = array_col
+            .offsets
+            .windows(2)
+            .map(|offset| (offset[1] - offset[0]) as usize)
+            .collect::<Vec<_>>();
+        let max_len = lengths.iter().max().cloned().unwrap_or(0);
+
+        // The rows having the element at the current position, and their accumulators.
+        let mut rows = (0..num_rows)
+            .filter(|row| lengths[*row] > 0)
+            .collect::<Vec<_>>();
+        let bitmap: Bitmap = lengths.iter().map(|len| *len > 0).collect();
+        let mut acc = args[1]
+            .convert_to_full_column(acc_type, num_rows)
+            .filter(&bitmap);
+        let mut results = Vec::with_capacity(max_len);
+        for pos in 0..max_len {
+            let indices = rows
+                .iter()
+                .map(|row| array_col.offsets[*row] + pos as u64)
+                .collect::<Vec<_>>();
+            let elements = array_col.values.take(&indices, &mut None);
+            let entries = vec![
+                BlockEntry::new(acc_type.clone(), Value::Column(acc)),
+                BlockEntry::new(elements.data_type(), Value::Column(elements)),
+            ];
+            let block = DataBlock::new(entries, rows.len());
+            let evaluator = Evaluator::new(&block, self.func_ctx, self.fn_registry);
+            let result = evaluator
+                .run(expr)?
+                .convert_to_full_column(acc_type, rows.len());
+
+            let bitmap: Bitmap = rows.iter().map(|row| lengths[*row] > pos + 1).collect();
+            acc = result.filter(&bitmap);
+            rows.retain(|row| lengths[*row] > pos + 1);
+            results.push(result);
+        }
+
+        // Assemble the accumulators of each row in the order of their positions.
+        let mut cursors = vec![0; max_len];
+        let mut offsets = Vec::with_capacity(num_rows + 1);
+        offsets.push(0);
+        let mut builder = ColumnBuilder::with_capacity(acc_type, array_col.values.len());
+        for len in lengths.iter() {
+            for (result, cursor) in results.iter().zip(cursors.iter_mut()).take(*len) {
+                builder.push(result.index(*cursor).unwrap());
+                *cursor += 1;
+            }
+            offsets.push(offsets.last().unwrap() + *len as u64);
+        }
+        let col = Column::Array(Box::new(ArrayColumn {
+            values: builder.build(),
+            offsets: offsets.into(),
+        }));
+
+        match len {
+            Some(_) => {
                 let col = match validity {
-                    Some(validity) => Value::Column(Column::Nullable(Box::new(NullableColumn {
-                        column: array_col,
-                        validity,
-                    }))),
-                    None => Value::Column(array_col),
+                    Some(validity) => col.wrap_nullable(Some(validity)),
+                    None => col,
                 };
-                Ok(col)
+                Ok(Value::Column(col))
             }
+            None => Ok(Value::Scalar(col.index(0).unwrap().to_owned())),
         }
     }
 
+    /// Evaluate the lambda expression on all the elements of the arrays at once,
+    /// and then assemble the result of each array according to the `offsets`.
+    fn run_array_lambda(
+        &self,
+        func_name: &str,
+        inner_col: Column,
+        offsets: Buffer<u64>,
+        expr: &Expr,
+    ) -> Result<Column> {
+        let entry = BlockEntry::new(inner_col.data_type(), Value::Column(inner_col.clone()));
+        let block = DataBlock::new(vec![entry], inner_col.len());
+
+        let evaluator = Evaluator::new(&block, self.func_ctx, self.fn_registry);
+        let result = evaluator.run(expr)?;
+        let result_col = result.convert_to_full_column(expr.data_type(), inner_col.len());
+
+        let col = match func_name {
+            "array_filter" => {
+                let bitmap = lambda_result_to_bitmap(&result_col);
+                let filtered_inner_col = inner_col.filter(&bitmap);
+                Column::Array(Box::new(ArrayColumn {
+                    values: filtered_inner_col,
+                    offsets: filter_offsets(&offsets, &bitmap),
+                }))
+            }
+            "array_partition" => {
+                let bitmap = lambda_result_to_bitmap(&result_col);
+                let not_bitmap = bitmap.not();
+                let matched_col = Column::Array(Box::new(ArrayColumn {
+                    values: inner_col.filter(&bitmap),
+                    offsets: filter_offsets(&offsets, &bitmap),
+                }));
+                let unmatched_col = Column::Array(Box::new(ArrayColumn {
+                    values: inner_col.filter(&not_bitmap),
+                    offsets: filter_offsets(&offsets, &not_bitmap),
+                }));
+                Column::Tuple(vec![matched_col, unmatched_col])
+            }
+            "array_find" => {
+                let bitmap = lambda_result_to_bitmap(&result_col);
+                let data_type = inner_col.data_type().wrap_nullable();
+                let mut builder = ColumnBuilder::with_capacity(&data_type, offsets.len() - 1);
+                for offset in offsets.windows(2) {
+                    let range = offset[0] as usize..offset[1] as usize;
+                    match range.into_iter().find(|i| bitmap.get_bit(*i)) {
+                        Some(i) => builder.push(inner_col.index(i).unwrap()),
+                        None => builder.push_default(),
+                    }
+                }
+                builder.build()
+            }
+            "array_min_by" | "array_max_by" => {
+                // Pick the element with the extreme key, the first one wins on ties,
+                // NULL keys are skipped and the result is NULL if all the keys are NULL.
+                let expected = if func_name == "array_min_by" {
+                    Ordering::Less
+                } else {
+                    Ordering::Greater
+                };
+                let data_type = inner_col.data_type().wrap_nullable();
+                let mut builder = ColumnBuilder::with_capacity(&data_type, offsets.len() - 1);
+                for offset in offsets.windows(2) {
+                    let mut extreme: Option<(usize, ScalarRef)> = None;
+                    for i in offset[0] as usize..offset[1] as usize {
+                        let key = result_col.index(i).unwrap();
+                        if key == ScalarRef::Null {
+                            continue;
+                        }
+                        let is_extreme = extreme.as_ref().map_or(true, |(_, extreme_key)| {
+                            key.partial_cmp(extreme_key) == Some(expected)
+                        });
+                        if is_extreme {
+                            extreme = Some((i, key));
+                        }
+                    }
+                    match extreme {
+                        Some((i, _)) => builder.push(inner_col.index(i).unwrap()),
+                        None => builder.push_default(),
+                    }
+                }
+                builder.build()
+            }
+            "array_count" => {
+                let bitmap = lambda_result_to_bitmap(&result_col);
+                let counts = offsets
+                    .windows(2)
+                    .map(|offset| {
+                        let off = offset[0] as usize;
+                        let len = (offset[1] - offset[0]) as usize;
+                        (len - bitmap.null_count_range(off, len)) as u64
+                    })
+                    .collect::<Vec<_>>();
+                UInt64Type::upcast_column(counts.into())
+            }
+            "array_dedup_by" => {
+                // Keep the first element of each key, NULL keys are treated as the same key.
+                let mut bitmap = MutableBitmap::from_len_zeroed(inner_col.len());
+                for offset in offsets.windows(2) {
+                    let mut keys: HashMap<u64, Vec<ScalarRef>> = HashMap::new();
+                    for i in offset[0] as usize..offset[1] as usize {
+                        let key = result_col.index(i).unwrap();
+                        let mut hasher = DefaultHasher::new();
+                        key.hash(&mut hasher);
+                        let same_hash_keys = keys.entry(hasher.finish()).or_default();
+                        if !same_hash_keys.contains(&key) {
+                            same_hash_keys.push(key);
+                            bitmap.set(i, true);
+                        }
+                    }
+                }
+                let bitmap: Bitmap = bitmap.into();
+                Column::Array(Box::new(ArrayColumn {
+                    values: inner_col.filter(&bitmap),
+                    offsets: filter_offsets(&offsets, &bitmap),
+                }))
+            }
+            "array_all" | "array_any" => {
+                // Follow the three-valued logic, `array_all` is false if any element is false,
+                // `array_any` is true if any element is true, otherwise the result is NULL
+                // if any element is NULL. Empty arrays are true for `array_all` and false for `array_any`.
+                let is_all = func_name == "array_all";
+                let (bitmap, validity) = match &result_col {
+                    Column::Nullable(box nullable_col) => (
+                        nullable_col.column.as_boolean().unwrap(),
+                        Some(&nullable_col.validity),
+                    ),
+                    _ => (result_col.as_boolean().unwrap(), None),
+                };
+                let num_rows = offsets.len() - 1;
+                let mut values = MutableBitmap::with_capacity(num_rows);
+                let mut result_validity = MutableBitmap::with_capacity(num_rows);
+                for offset in offsets.windows(2) {
+                    let mut value = is_all;
+                    let mut has_null = false;
+                    for i in offset[0] as usize..offset[1] as usize {
+                        if validity.map_or(true, |v| v.get_bit(i)) {
+                            if bitmap.get_bit(i) != is_all {
+                                value = !is_all;
+                                has_null = false;
+                                break;
+                            }
+                        } else {
+                            has_null = true;
+                        }
+                    }
+                    values.push(value);
+                    result_validity.push(!has_null);
+                }
+                let col = Column::Boolean(values.into());
+                match validity {
+                    Some(_) => col.wrap_nullable(Some(result_validity.into())),
+                    None => col,
+                }
+            }
+            _ => Column::Array(Box::new(ArrayColumn {
+                values: result_col,
+                offsets,
+            })),
+        };
+        Ok(col)
+    }
+
     pub fn get_children(
         &self,
         args: &[Expr],
@@ -1843,3 +2159,29 @@ impl<'a, Index: ColumnIndex> ConstantFolder<'a, Index> {
         Some(output_domain)
     }
 }
+
+/// Generate the new offsets of the arrays after their elements are filtered by the `bitmap`.
+fn filter_offsets(offsets: &Buffer<u64>, bitmap: &Bitmap) -> Buffer<u64> {
+    let mut new_offset = 0;
+    let mut filtered_offsets = Vec::with_capacity(offsets.len());
+    filtered_offsets.push(0);
+    for offset in offsets.windows(2) {
+        let off = offset[0] as usize;
+        let len = (offset[1] - offset[0]) as usize;
+        let unset_count = bitmap.null_count_range(off, len);
+        new_offset += (len - unset_count) as u64;
+        filtered_offsets.push(new_offset);
+    }
+    filtered_offsets.into()
+}
+
+/// Convert the result of a predicate lambda into a bitmap, `NULL` is treated as `false`.
+fn lambda_result_to_bitmap(result_col: &Column) -> Bitmap {
+    match result_col {
+        Column::Nullable(box nullable_col) => {
+            let bitmap = nullable_col.column.as_boolean().unwrap();
+            bitmap & (&nullable_col.validity)
+        }
+        _ => result_col.as_boolean().unwrap().clone(),
+    }
+}
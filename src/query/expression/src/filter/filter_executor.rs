@@ -78,6 +78,7 @@ impl FilterExecutor {
 
     // Store the filtered indices of data_block in `true_selection` and return the number of filtered indices.
     pub fn select(&mut self, data_block: &DataBlock) -> Result<usize> {
+        self.reserve_selection(data_block.num_rows());
         let evaluator = Evaluator::new(data_block, &self.func_ctx, self.fn_registry);
         let selector = Selector::new(evaluator, data_block.num_rows());
         selector.select(
@@ -87,6 +88,40 @@ impl FilterExecutor {
         )
     }
 
+    // Same as `select`, but evaluate the filter on the sub-blocks of `batch_rows` rows one by one,
+    // the indices of all the sub-blocks are accumulated in `true_selection` in order.
+    // A `batch_rows` of 0 or no less than the rows of the DataBlock evaluates the whole DataBlock.
+    pub fn select_in_batches(
+        &mut self,
+        data_block: &DataBlock,
+        batch_rows: usize,
+    ) -> Result<usize> {
+        let num_rows = data_block.num_rows();
+        if batch_rows == 0 || batch_rows >= num_rows {
+            return self.select(data_block);
+        }
+        self.reserve_selection(num_rows);
+
+        let mut count = 0;
+        for start in (0..num_rows).step_by(batch_rows) {
+            let end = (start + batch_rows).min(num_rows);
+            let batch = data_block.slice(start..end);
+            let evaluator = Evaluator::new(&batch, &self.func_ctx, self.fn_registry);
+            let selector = Selector::new(evaluator, batch.num_rows());
+            let batch_count = selector.select(
+                &self.select_expr,
+                &mut self.true_selection[count..],
+                &mut self.false_selection,
+            )?;
+            // The indices are relative to the sub-block, shift them to the DataBlock.
+            for idx in &mut self.true_selection[count..count + batch_count] {
+                *idx += start as u32;
+            }
+            count += batch_count;
+        }
+        Ok(count)
+    }
+
     // Generate a new DataBlock from the filtered indices stored in `true_selection`.
     pub fn take(
         &mut self,
@@ -170,6 +205,7 @@ impl FilterExecutor {
 
     // Initialize the `true_selection` by `MutableBitmap`, return the number of filtered indices.
     pub fn from_bitmap(&mut self, bitmap: MutableBitmap) -> usize {
+        self.reserve_selection(bitmap.len());
         let mut true_idx = 0;
         let true_selection = self.true_selection.as_mut_slice();
         for (idx, ret) in bitmap.iter().enumerate() {
@@ -179,6 +215,24 @@ impl FilterExecutor {
         true_idx
     }
 
+    // Grow the selection vectors if the DataBlock has more rows than `max_block_size`.
+    pub fn reserve_selection(&mut self, num_rows: usize) {
+        if num_rows <= self.max_block_size {
+            return;
+        }
+        self.max_block_size = num_rows;
+        self.true_selection.resize(num_rows, 0);
+        if self.has_or {
+            self.false_selection.resize(num_rows, 0);
+        }
+        // `build_selection_range` allocates it again with the new size.
+        self.selection_range.clear();
+    }
+
+    pub fn true_selection(&self) -> &[u32] {
+        &self.true_selection
+    }
+
     pub fn mut_true_selection(&mut self) -> &mut [u32] {
         &mut self.true_selection
     }
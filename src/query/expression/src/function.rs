@@ -95,6 +95,9 @@ pub enum FunctionEval {
 pub struct FunctionContext {
     pub tz: TzLUT,
     pub rounding_mode: bool,
+    pub strict_array_zip_with: bool,
+    pub strict_array_zip: bool,
+    pub strict_array_set: bool,
 
     pub openai_api_chat_base_url: String,
     pub openai_api_embedding_base_url: String,
@@ -75,6 +75,10 @@ impl TopKSorter {
 
     // Push the column into this sorted and update the selection
     // The selection could be used in filter
+    // The rows kept in the selection are in their original order, they are not sorted by
+    // the values. The native reader sorts the rows after taking them by the selection if
+    // `storage_native_topk_approx_order` is enabled, the selection itself is kept in order
+    // as the internal columns and the virtual columns are generated from it.
     pub fn push_column_with_selection(
         &mut self,
         col: &Column,
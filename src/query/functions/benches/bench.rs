@@ -19,9 +19,15 @@ extern crate criterion;
 mod parser;
 
 use criterion::Criterion;
+use databend_common_expression::filter::build_select_expr;
+use databend_common_expression::filter::FilterExecutor;
 use databend_common_expression::type_check;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::NumberDataType;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Evaluator;
+use databend_common_expression::FromData;
 use databend_common_expression::FunctionContext;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 
@@ -50,5 +56,41 @@ fn bench(c: &mut Criterion) {
     }
 }
 
-criterion_group!(benches, bench);
+fn bench_filter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bench_filter");
+
+    // A large page with the prewhere filter keeping about 1% of the rows.
+    let num_rows = 1024 * 1024;
+    let columns = [
+        ("a", DataType::Number(NumberDataType::Int32)),
+        ("b", DataType::Number(NumberDataType::Int32)),
+    ];
+    let block = DataBlock::new_from_columns(vec![
+        Int32Type::from_data((0..num_rows as i32).collect::<Vec<_>>()),
+        Int32Type::from_data((0..num_rows as i32).map(|i| i % 100).collect::<Vec<_>>()),
+    ]);
+    let raw_expr = parser::parse_raw_expr("a % 7 = 3 and b < 7", &columns);
+    let expr = type_check::check(&raw_expr, &BUILTIN_FUNCTIONS).unwrap();
+    let (select_expr, has_or) = build_select_expr(&expr);
+    let mut filter_executor = FilterExecutor::new(
+        select_expr,
+        FunctionContext::default(),
+        has_or,
+        num_rows,
+        None,
+        &BUILTIN_FUNCTIONS,
+        false,
+    );
+
+    group.bench_function("whole_page", |b| {
+        b.iter(|| filter_executor.select(&block).unwrap())
+    });
+    for batch_rows in [4096, 16384, 65536] {
+        group.bench_function(format!("sub_batch/{batch_rows}"), |b| {
+            b.iter(|| filter_executor.select_in_batches(&block, batch_rows).unwrap())
+        });
+    }
+}
+
+criterion_group!(benches, bench, bench_filter);
 criterion_main!(benches);
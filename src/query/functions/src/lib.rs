@@ -56,11 +56,21 @@ pub const GENERAL_WINDOW_FUNCTIONS: [&str; 13] = [
     "cume_dist",
 ];
 
-pub const GENERAL_LAMBDA_FUNCTIONS: [&str; 4] = [
+pub const GENERAL_LAMBDA_FUNCTIONS: [&str; 14] = [
     "array_transform",
     "array_apply",
     "array_map",
     "array_filter",
+    "array_partition",
+    "array_find",
+    "array_count",
+    "array_all",
+    "array_any",
+    "array_dedup_by",
+    "array_min_by",
+    "array_max_by",
+    "array_zip_with",
+    "array_scan",
 ];
 
 fn builtin_functions() -> FunctionRegistry {
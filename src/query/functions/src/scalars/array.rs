@@ -12,13 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::hash::Hash;
 use std::ops::Range;
 use std::sync::Arc;
 
+use databend_common_expression::types::array::ArrayColumn;
 use databend_common_expression::types::array::ArrayColumnBuilder;
 use databend_common_expression::types::boolean::BooleanDomain;
+use databend_common_expression::types::nullable::NullableColumn;
 use databend_common_expression::types::nullable::NullableDomain;
+use databend_common_expression::types::number::Int64Type;
 use databend_common_expression::types::number::NumberScalar;
 use databend_common_expression::types::number::SimpleDomain;
 use databend_common_expression::types::number::UInt64Type;
@@ -40,6 +46,7 @@ use databend_common_expression::types::ValueType;
 use databend_common_expression::types::ALL_NUMERICS_TYPES;
 use databend_common_expression::vectorize_1_arg;
 use databend_common_expression::vectorize_2_arg;
+use databend_common_expression::vectorize_3_arg;
 use databend_common_expression::vectorize_with_builder_1_arg;
 use databend_common_expression::vectorize_with_builder_2_arg;
 use databend_common_expression::vectorize_with_builder_3_arg;
@@ -87,6 +94,12 @@ const ARRAY_AGGREGATE_FUNCTIONS: &[(&str, &str); 14] = &[
     ("array_skewness", "skewness"),
 ];
 
+const ARRAY_SET_FUNCTIONS: &[(&str, SetOperation); 3] = &[
+    ("array_intersect", SetOperation::Intersect),
+    ("array_union", SetOperation::Union),
+    ("array_except", SetOperation::Except),
+];
+
 const ARRAY_SORT_FUNCTIONS: &[(&str, (bool, bool)); 4] = &[
     ("array_sort_asc_null_first", (true, true)),
     ("array_sort_desc_null_first", (false, true)),
@@ -99,6 +112,9 @@ pub fn register(registry: &mut FunctionRegistry) {
     registry.register_aliases("get", &["array_get"]);
     registry.register_aliases("length", &["array_length"]);
     registry.register_aliases("slice", &["array_slice"]);
+    registry.register_aliases("array_positions", &["array_position_all"]);
+    registry.register_aliases("array_grouped_sum", &["array_group_sum_by"]);
+    registry.register_aliases("array_set", &["array_slice_assign"]);
 
     register_array_aggr(registry);
 
@@ -223,6 +239,42 @@ pub fn register(registry: &mut FunctionRegistry) {
         ),
     );
 
+    registry.register_2_arg_core::<NullableType<EmptyArrayType>, NullableType<Int64Type>, NullType, _, _>(
+        "get",
+        |_, _, _| FunctionDomain::Full,
+        |_, _, _| Value::Scalar(()),
+    );
+
+    registry.register_2_arg_core::<NullableType<ArrayType<NullType>>, NullableType<Int64Type>, NullType, _, _>(
+        "get",
+        |_, _, _| FunctionDomain::Full,
+        |_, _, _| Value::Scalar(()),
+    );
+
+    registry.register_combine_nullable_2_arg::<ArrayType<NullableType<GenericType<0>>>, Int64Type, GenericType<0>, _, _>(
+        "get",
+        |_, domain, _| FunctionDomain::Domain(NullableDomain {
+            has_null: true,
+            value: domain.as_ref().and_then(|domain| domain.value.clone()),
+        }),
+        vectorize_with_builder_2_arg::<ArrayType<NullableType<GenericType<0>>>, Int64Type, NullableType<GenericType<0>>>(
+            |arr, idx, output, _| {
+                // A negative index counts from the end of the array, e.g. -1 is the last element.
+                let pos = if idx > 0 {
+                    Some(idx as usize - 1)
+                } else if idx < 0 {
+                    usize::try_from(arr.len() as i64 + idx).ok()
+                } else {
+                    None
+                };
+                match pos.and_then(|pos| arr.index(pos)) {
+                    Some(Some(item)) => output.push(item),
+                    _ => output.push_null(),
+                }
+            }
+        ),
+    );
+
     registry.register_2_arg_core::<NullType, NullType, NullType, _, _>(
         "array_indexof",
         |_, _, _| FunctionDomain::Full,
@@ -239,6 +291,26 @@ pub fn register(registry: &mut FunctionRegistry) {
         ),
     );
 
+    registry.register_2_arg_core::<NullType, NullType, NullType, _, _>(
+        "array_positions",
+        |_, _, _| FunctionDomain::Full,
+        |_, _, _| Value::Scalar(()),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<ArrayType<GenericType<0>>, GenericType<0>, ArrayType<UInt64Type>, _, _>(
+        "array_positions",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_2_arg::<ArrayType<GenericType<0>>, GenericType<0>, ArrayType<UInt64Type>>(
+            |arr, val, _| {
+                arr.iter()
+                    .enumerate()
+                    .filter(|(_, item)| *item == val)
+                    .map(|(pos, _)| pos as u64 + 1)
+                    .collect()
+            },
+        ),
+    );
+
     registry.register_2_arg_core::<NullableType<EmptyArrayType>, NullableType<EmptyArrayType>, EmptyArrayType, _, _>(
         "array_concat",
         |_, _, _| FunctionDomain::Full,
@@ -257,6 +329,22 @@ pub fn register(registry: &mut FunctionRegistry) {
         ),
     );
 
+    for (fn_name, operation) in ARRAY_SET_FUNCTIONS {
+        registry.register_passthrough_nullable_2_arg::<EmptyArrayType, EmptyArrayType, EmptyArrayType, _, _>(
+            fn_name,
+            |_, _, _| FunctionDomain::Full,
+            vectorize_2_arg::<EmptyArrayType, EmptyArrayType, EmptyArrayType>(|_, _, _| ()),
+        );
+
+        registry.register_passthrough_nullable_2_arg::<ArrayType<GenericType<0>>, ArrayType<GenericType<0>>, ArrayType<GenericType<0>>, _, _>(
+            fn_name,
+            |_, _, _| FunctionDomain::Full,
+            vectorize_2_arg::<ArrayType<GenericType<0>>, ArrayType<GenericType<0>>, ArrayType<GenericType<0>>>(
+                move |lhs, rhs, _| array_set_operation(*operation, &lhs, &rhs)
+            ),
+        );
+    }
+
     registry
         .register_passthrough_nullable_1_arg::<ArrayType<ArrayType<GenericType<0>>>, ArrayType<GenericType<0>>, _, _>(
             "array_flatten",
@@ -272,6 +360,33 @@ pub fn register(registry: &mut FunctionRegistry) {
             ),
         );
 
+    registry
+        .register_passthrough_nullable_1_arg::<ArrayType<NullableType<ArrayType<GenericType<0>>>>, ArrayType<GenericType<0>>, _, _>(
+            "array_flatten",
+            |_, _| FunctionDomain::Full,
+            vectorize_1_arg::<ArrayType<NullableType<ArrayType<GenericType<0>>>>, ArrayType<GenericType<0>>>(
+            |a, b| {
+                // The NULL sub-arrays are skipped.
+                let mut builder = ColumnBuilder::with_capacity(&b.generics[0], a.len());
+                for a in a.iter().flatten() {
+                    builder.append_column(&a);
+                }
+                builder.build()
+            }
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<EmptyArrayType, StringType, StringType, _, _>(
+            "array_to_string",
+            |_, _, _| FunctionDomain::Full,
+            vectorize_with_builder_2_arg::<EmptyArrayType, StringType, StringType>(
+                |_, _, output, _| {
+                    output.commit_row();
+                },
+            ),
+        );
+
     registry
         .register_passthrough_nullable_2_arg::<ArrayType<StringType>, StringType, StringType, _, _>(
             "array_to_string",
@@ -289,6 +404,86 @@ pub fn register(registry: &mut FunctionRegistry) {
             ),
         );
 
+    registry
+        .register_passthrough_nullable_2_arg::<ArrayType<NullableType<StringType>>, StringType, StringType, _, _>(
+            "array_to_string",
+            |_, _, _| FunctionDomain::Full,
+            vectorize_with_builder_2_arg::<ArrayType<NullableType<StringType>>, StringType, StringType>(
+                |lhs, rhs, output, _| {
+                    // The NULL elements are skipped.
+                    for (i, d) in lhs.iter().flatten().enumerate() {
+                        if i != 0 {
+                            output.put_slice(rhs);
+                        }
+                        output.put_slice(d);
+                    }
+                    output.commit_row();
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_3_arg::<EmptyArrayType, StringType, StringType, StringType, _, _>(
+            "array_to_string",
+            |_, _, _, _| FunctionDomain::Full,
+            vectorize_with_builder_3_arg::<EmptyArrayType, StringType, StringType, StringType>(
+                |_, _, _, output, _| {
+                    output.commit_row();
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_3_arg::<ArrayType<NullableType<StringType>>, StringType, StringType, StringType, _, _>(
+            "array_to_string",
+            |_, _, _, _| FunctionDomain::Full,
+            vectorize_with_builder_3_arg::<ArrayType<NullableType<StringType>>, StringType, StringType, StringType>(
+                |lhs, delimiter, null_replacement, output, _| {
+                    // The NULL elements are replaced with `null_replacement`.
+                    for (i, d) in lhs.iter().enumerate() {
+                        if i != 0 {
+                            output.put_slice(delimiter);
+                        }
+                        output.put_slice(d.unwrap_or(null_replacement));
+                    }
+                    output.commit_row();
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<StringType, StringType, ArrayType<StringType>, _, _>(
+            "string_to_array",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<StringType, StringType, ArrayType<StringType>>(
+                |str, delimiter, output, ctx| {
+                    let (s, delimiter) =
+                        match (std::str::from_utf8(str), std::str::from_utf8(delimiter)) {
+                            (Ok(s), Ok(delimiter)) => (s, delimiter),
+                            (Err(e), _) | (_, Err(e)) => {
+                                ctx.set_error(output.len(), e.to_string());
+                                output.commit_row();
+                                return;
+                            }
+                        };
+                    // An empty string is an empty array,
+                    // and an empty delimiter keeps the whole string as the only element.
+                    if !s.is_empty() {
+                        if delimiter.is_empty() {
+                            output.builder.put_slice(str);
+                            output.builder.commit_row();
+                        } else {
+                            for part in s.split(delimiter) {
+                                output.builder.put_slice(part.as_bytes());
+                                output.builder.commit_row();
+                            }
+                        }
+                    }
+                    output.commit_row();
+                },
+            ),
+        );
+
     registry
         .register_passthrough_nullable_2_arg::<EmptyArrayType, UInt64Type, EmptyArrayType, _, _>(
             "slice",
@@ -358,6 +553,110 @@ pub fn register(registry: &mut FunctionRegistry) {
         ),
     );
 
+    // The overloads with signed positions, a negative position counts from the tail.
+    // They are registered after the unsigned ones, so only the signed positions resolve to them.
+    registry
+        .register_passthrough_nullable_2_arg::<EmptyArrayType, NumberType<i64>, EmptyArrayType, _, _>(
+            "slice",
+            |_, _, _| FunctionDomain::Full,
+            vectorize_with_builder_2_arg::<EmptyArrayType, NumberType<i64>, EmptyArrayType>(
+                |_, _, output, _| {
+                    *output += 1;
+                },
+            ),
+        );
+
+    registry.register_passthrough_nullable_2_arg::<ArrayType<GenericType<0>>, NumberType<i64>, ArrayType<GenericType<0>>, _, _>(
+        "slice",
+        |_, domain, _| FunctionDomain::Domain(domain.clone()),
+        vectorize_with_builder_2_arg::<ArrayType<GenericType<0>>, NumberType<i64>, ArrayType<GenericType<0>>>(
+            |arr, start, output, _| {
+                let start = slice_start(start, arr.len());
+                if start >= arr.len() {
+                    output.push_default();
+                } else {
+                    output.push(arr.slice(Range { start, end: arr.len() }));
+                }
+            }
+        ),
+    );
+
+    registry.register_passthrough_nullable_3_arg::<EmptyArrayType, NumberType<i64>, NumberType<i64>, EmptyArrayType, _, _>(
+        "slice",
+        |_, _, _, _| FunctionDomain::Full,
+        vectorize_with_builder_3_arg::<EmptyArrayType, NumberType<i64>, NumberType<i64>, EmptyArrayType>(
+            |_, _, _, output, _| {
+                *output += 1;
+            }
+        ),
+    );
+
+    registry.register_passthrough_nullable_3_arg::<ArrayType<GenericType<0>>, NumberType<i64>, NumberType<i64>, ArrayType<GenericType<0>>, _, _>(
+        "slice",
+        |_, domain, _, _| FunctionDomain::Domain(domain.clone()),
+        vectorize_with_builder_3_arg::<ArrayType<GenericType<0>>, NumberType<i64>, NumberType<i64>, ArrayType<GenericType<0>>>(
+            |arr, start, end, output, _| {
+                let start = slice_start(start, arr.len());
+                let end = slice_end(end, arr.len());
+                if start >= end {
+                    output.push_default();
+                } else {
+                    output.push(arr.slice(Range { start, end }));
+                }
+            }
+        ),
+    );
+
+    registry.register_passthrough_nullable_3_arg::<EmptyArrayType, NumberType<i64>, EmptyArrayType, EmptyArrayType, _, _>(
+        "array_splice",
+        |_, _, _, _| FunctionDomain::Full,
+        vectorize_with_builder_3_arg::<EmptyArrayType, NumberType<i64>, EmptyArrayType, EmptyArrayType>(
+            |_, _, _, output, _| {
+                *output += 1;
+            }
+        ),
+    );
+
+    registry.register_passthrough_nullable_3_arg::<ArrayType<GenericType<0>>, NumberType<i64>, ArrayType<GenericType<0>>, ArrayType<GenericType<0>>, _, _>(
+        "array_splice",
+        |_, _, _, _| FunctionDomain::Full,
+        vectorize_with_builder_3_arg::<ArrayType<GenericType<0>>, NumberType<i64>, ArrayType<GenericType<0>>, ArrayType<GenericType<0>>>(
+            |arr, pos, values, output, _| {
+                // The values are inserted before the 1-based position,
+                // the position less than 1 prepends and beyond the length appends.
+                let pos = (pos.max(1) as usize - 1).min(arr.len());
+                output.builder.append_column(&arr.slice(0..pos));
+                output.builder.append_column(&values);
+                output.builder.append_column(&arr.slice(pos..arr.len()));
+                output.commit_row()
+            }
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<EmptyArrayType, GenericType<0>, EmptyArrayType, _, _>(
+        "array_fill_value",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<EmptyArrayType, GenericType<0>, EmptyArrayType>(
+            |_, _, output, _| {
+                *output += 1;
+            }
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<ArrayType<NullableType<GenericType<0>>>, GenericType<0>, ArrayType<GenericType<0>>, _, _>(
+        "array_fill_value",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<ArrayType<NullableType<GenericType<0>>>, GenericType<0>, ArrayType<GenericType<0>>>(
+            |arr, val, output, _| {
+                // Replace the NULL elements with the fill value.
+                for item in arr.iter() {
+                    output.put_item(item.unwrap_or_else(|| val.clone()));
+                }
+                output.commit_row()
+            }
+        ),
+    );
+
     registry.register_passthrough_nullable_1_arg::<EmptyArrayType, EmptyArrayType, _, _>(
         "array_remove_first",
         |_, _| FunctionDomain::Full,
@@ -406,6 +705,25 @@ pub fn register(registry: &mut FunctionRegistry) {
         ),
     );
 
+    registry.register_passthrough_nullable_1_arg::<EmptyArrayType, EmptyArrayType, _, _>(
+        "array_reverse",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<EmptyArrayType, EmptyArrayType>(|arr, _| arr),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<ArrayType<GenericType<0>>, ArrayType<GenericType<0>>, _, _>(
+        "array_reverse",
+        |_, domain| FunctionDomain::Domain(domain.clone()),
+        vectorize_1_arg::<ArrayType<GenericType<0>>, ArrayType<GenericType<0>>>(|arr, _| {
+            let data_type = arr.data_type();
+            let mut builder = ColumnBuilder::with_capacity(&data_type, arr.len());
+            for idx in (0..arr.len()).rev() {
+                builder.push(unsafe { arr.index_unchecked(idx) });
+            }
+            builder.build()
+        }),
+    );
+
     registry.register_2_arg_core::<GenericType<0>, ArrayType<GenericType<0>>, ArrayType<GenericType<0>>, _, _>(
         "array_prepend",
         |_, _, _| FunctionDomain::Full,
@@ -430,6 +748,135 @@ pub fn register(registry: &mut FunctionRegistry) {
         }),
     );
 
+    registry.register_3_arg_core::<ArrayType<NullableType<GenericType<0>>>, NumberType<i64>, NullableType<GenericType<0>>, ArrayType<NullableType<GenericType<0>>>, _, _>(
+        "array_set",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<ArrayType<NullableType<GenericType<0>>>, NumberType<i64>, NullableType<GenericType<0>>, ArrayType<NullableType<GenericType<0>>>>(
+            |arr, index, val, output, ctx| array_set_fn(arr, index, val, output, ctx)
+        ),
+    );
+
+    // The NULL array or index passes through, while the NULL value is set as the element.
+    registry.register_3_arg_core::<NullableType<ArrayType<NullableType<GenericType<0>>>>, NullableType<NumberType<i64>>, NullableType<GenericType<0>>, NullableType<ArrayType<NullableType<GenericType<0>>>>, _, _>(
+        "array_set",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<NullableType<ArrayType<NullableType<GenericType<0>>>>, NullableType<NumberType<i64>>, NullableType<GenericType<0>>, NullableType<ArrayType<NullableType<GenericType<0>>>>>(
+            |arr, index, val, output, ctx| match (arr, index) {
+                (Some(arr), Some(index)) => {
+                    array_set_fn(arr, index, val, &mut output.builder, ctx);
+                    output.validity.push(true);
+                }
+                _ => output.push_null(),
+            }
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<ArrayType<NullType>, NumberType<i64>, ArrayType<NullType>, _, _>(
+        "array_resize",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<ArrayType<NullType>, NumberType<i64>, ArrayType<NullType>>(
+            |_, len, output, ctx| {
+                if len < 0 {
+                    ctx.set_error(
+                        output.len(),
+                        format!("the length of array_resize must be non-negative, but got {len}"),
+                    );
+                    output.push_default();
+                    return;
+                }
+                for _ in 0..len {
+                    output.put_item(());
+                }
+                output.commit_row()
+            }
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<ArrayType<NullableType<GenericType<0>>>, NumberType<i64>, ArrayType<NullableType<GenericType<0>>>, _, _>(
+        "array_resize",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<ArrayType<NullableType<GenericType<0>>>, NumberType<i64>, ArrayType<NullableType<GenericType<0>>>>(
+            |arr, len, output, ctx| {
+                if len < 0 {
+                    ctx.set_error(
+                        output.len(),
+                        format!("the length of array_resize must be non-negative, but got {len}"),
+                    );
+                    output.push_default();
+                    return;
+                }
+                // Truncate to the length, or pad with NULLs to reach it.
+                let len = len as usize;
+                for item in arr.iter().take(len) {
+                    output.put_item(item);
+                }
+                for _ in arr.len()..len {
+                    output.put_item(None);
+                }
+                output.commit_row()
+            }
+        ),
+    );
+
+    registry.register_passthrough_nullable_3_arg::<ArrayType<GenericType<0>>, NumberType<i64>, GenericType<0>, ArrayType<GenericType<0>>, _, _>(
+        "array_resize",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<ArrayType<GenericType<0>>, NumberType<i64>, GenericType<0>, ArrayType<GenericType<0>>>(
+            |arr, len, fill, output, ctx| {
+                if len < 0 {
+                    ctx.set_error(
+                        output.len(),
+                        format!("the length of array_resize must be non-negative, but got {len}"),
+                    );
+                    output.push_default();
+                    return;
+                }
+                // Truncate to the length, or pad with the fill value to reach it.
+                let len = len as usize;
+                output.builder.append_column(&arr.slice(0..arr.len().min(len)));
+                for _ in arr.len()..len {
+                    output.put_item(fill.clone());
+                }
+                output.commit_row()
+            }
+        ),
+    );
+
+    registry.register_function_factory("array_zip", |_, args_type| {
+        if args_type.len() != 2 {
+            return None;
+        }
+        let inner_types = args_type
+            .iter()
+            .map(|arg_type| match arg_type.remove_nullable() {
+                DataType::Array(box inner_type) => Some(inner_type),
+                DataType::EmptyArray => Some(DataType::Null),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let tuple_type = DataType::Tuple(inner_types.clone());
+        let f = Function {
+            signature: FunctionSignature {
+                name: "array_zip".to_string(),
+                args_type: inner_types
+                    .into_iter()
+                    .map(|inner_type| DataType::Array(Box::new(inner_type)))
+                    .collect(),
+                return_type: DataType::Array(Box::new(tuple_type.clone())),
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::MayThrow),
+                eval: Box::new(move |args, ctx| array_zip_fn(args, &tuple_type, ctx)),
+            },
+        };
+
+        if args_type.iter().any(|arg_type| arg_type.is_nullable()) {
+            Some(Arc::new(f.passthrough_nullable()))
+        } else {
+            Some(Arc::new(f))
+        }
+    });
+
     fn eval_contains<T: ArgType>(
         lhs: ValueRef<ArrayType<T>>,
         rhs: ValueRef<T>,
@@ -681,6 +1128,731 @@ pub fn register(registry: &mut FunctionRegistry) {
             }
         }),
     );
+
+    registry.register_passthrough_nullable_1_arg::<EmptyArrayType, EmptyArrayType, _, _>(
+        "array_compact",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<EmptyArrayType, EmptyArrayType>(|arr, _| arr),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<ArrayType<NullType>, EmptyArrayType, _, _>(
+        "array_compact",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<ArrayType<NullType>, EmptyArrayType>(|_, output, _| {
+            *output += 1;
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<ArrayType<NullableType<GenericType<0>>>, ArrayType<GenericType<0>>, _, _>(
+        "array_compact",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<ArrayType<NullableType<GenericType<0>>>, ArrayType<GenericType<0>>>(
+            |arr, output, _| {
+                // Keep the non-NULL elements only.
+                for item in arr.iter().flatten() {
+                    output.put_item(item);
+                }
+                output.commit_row()
+            }
+        ),
+    );
+
+    registry.register_2_arg::<EmptyArrayType, EmptyArrayType, BooleanType, _, _>(
+        "array_equals_unordered",
+        |_, _, _| FunctionDomain::Full,
+        |_, _, _| true,
+    );
+
+    registry.register_3_arg::<EmptyArrayType, EmptyArrayType, BooleanType, BooleanType, _, _>(
+        "array_equals_unordered",
+        |_, _, _, _| FunctionDomain::Full,
+        |_, _, _, _| true,
+    );
+
+    registry.register_passthrough_nullable_2_arg::<ArrayType<GenericType<0>>, ArrayType<GenericType<0>>, BooleanType, _, _>(
+        "array_equals_unordered",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_2_arg::<ArrayType<GenericType<0>>, ArrayType<GenericType<0>>, BooleanType>(|lhs, rhs, _| {
+            array_equals_unordered(&lhs, &rhs, false)
+        }),
+    );
+
+    registry.register_passthrough_nullable_3_arg::<ArrayType<GenericType<0>>, ArrayType<GenericType<0>>, BooleanType, BooleanType, _, _>(
+        "array_equals_unordered",
+        |_, _, _, _| FunctionDomain::Full,
+        vectorize_3_arg::<ArrayType<GenericType<0>>, ArrayType<GenericType<0>>, BooleanType, BooleanType>(|lhs, rhs, multiset, _| {
+            array_equals_unordered(&lhs, &rhs, multiset)
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<EmptyArrayType, EmptyArrayType, _, _>(
+        "array_cumulative_distinct_count",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<EmptyArrayType, EmptyArrayType>(|arr, _| arr),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<ArrayType<GenericType<0>>, ArrayType<UInt64Type>, _, _>(
+        "array_cumulative_distinct_count",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<ArrayType<GenericType<0>>, ArrayType<UInt64Type>>(|arr, _| {
+            let mut set: StackHashSet<u128, 16> = StackHashSet::with_capacity(arr.len());
+            arr.iter()
+                .map(|val| {
+                    let mut hasher = SipHasher24::new();
+                    val.hash(&mut hasher);
+                    let _ = set.set_insert(hasher.finish128().into());
+                    set.len() as u64
+                })
+                .collect()
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<EmptyArrayType, EmptyArrayType, _, _>(
+        "array_group_consecutive",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<EmptyArrayType, EmptyArrayType>(|arr, _| arr),
+    );
+
+    registry.register_function_factory("array_group_consecutive", |_, args_type| {
+        if args_type.len() != 1 {
+            return None;
+        }
+        let inner_type = match args_type[0].remove_nullable() {
+            DataType::Array(box inner_type) => inner_type,
+            _ => return None,
+        };
+        let tuple_type = DataType::Tuple(vec![
+            inner_type.clone(),
+            DataType::Number(NumberDataType::UInt64),
+        ]);
+        let f = Function {
+            signature: FunctionSignature {
+                name: "array_group_consecutive".to_string(),
+                args_type: vec![DataType::Array(Box::new(inner_type))],
+                return_type: DataType::Array(Box::new(tuple_type.clone())),
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::Full),
+                eval: Box::new(move |args, _| array_group_consecutive_fn(&args[0], &tuple_type)),
+            },
+        };
+
+        if args_type[0].is_nullable() {
+            Some(Arc::new(f.passthrough_nullable()))
+        } else {
+            Some(Arc::new(f))
+        }
+    });
+
+    // The field names are passed as params, which are converted from the
+    // constant array argument `array_to_struct(arr, ['name1', 'name2'])`.
+    registry.register_function_factory("array_to_struct", |params, args_type| {
+        if params.is_empty() || args_type.len() != 1 {
+            return None;
+        }
+        if params.iter().any(|param| !matches!(param, Scalar::String(_))) {
+            return None;
+        }
+        let inner_type = match args_type[0].remove_nullable() {
+            DataType::Array(box inner_type) => inner_type,
+            _ => return None,
+        };
+        let num_fields = params.len();
+        let tuple_type = DataType::Tuple(vec![inner_type.clone(); num_fields]);
+        let f = Function {
+            signature: FunctionSignature {
+                name: "array_to_struct".to_string(),
+                args_type: vec![DataType::Array(Box::new(inner_type))],
+                return_type: tuple_type.clone(),
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::MayThrow),
+                eval: Box::new(move |args, ctx| {
+                    array_to_struct_fn(&args[0], num_fields, &tuple_type, ctx)
+                }),
+            },
+        };
+
+        if args_type[0].is_nullable() {
+            Some(Arc::new(f.passthrough_nullable()))
+        } else {
+            Some(Arc::new(f))
+        }
+    });
+
+    // The keys to select are passed as params, which are converted from the
+    // constant array argument `array_pivot(arr, ['key1', 'key2'])`.
+    registry.register_function_factory("array_pivot", |params, args_type| {
+        if params.is_empty() || args_type.len() != 1 {
+            return None;
+        }
+        let keys = params
+            .iter()
+            .map(|param| match param {
+                Scalar::String(key) => Some(key.clone()),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let inner_type = match args_type[0].remove_nullable() {
+            DataType::Array(box inner_type) => inner_type,
+            _ => return None,
+        };
+        let value_type = match inner_type.remove_nullable() {
+            DataType::Tuple(fields)
+                if fields.len() == 2 && fields[0].remove_nullable() == DataType::String =>
+            {
+                fields[1].wrap_nullable()
+            }
+            _ => return None,
+        };
+        let tuple_type = DataType::Tuple(vec![value_type; keys.len()]);
+        let f = Function {
+            signature: FunctionSignature {
+                name: "array_pivot".to_string(),
+                args_type: vec![DataType::Array(Box::new(inner_type))],
+                return_type: tuple_type.clone(),
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::Full),
+                eval: Box::new(move |args, _| array_pivot_fn(&args[0], &keys, &tuple_type)),
+            },
+        };
+
+        if args_type[0].is_nullable() {
+            Some(Arc::new(f.passthrough_nullable()))
+        } else {
+            Some(Arc::new(f))
+        }
+    });
+
+    // The values are summed as Int64, UInt64 or Float64 (for floats and decimals),
+    // and the optional mode argument switches the aggregation to `min` or `max`.
+    registry.register_function_factory("array_grouped_sum", |_, args_type| {
+        if args_type.len() != 2 && args_type.len() != 3 {
+            return None;
+        }
+        let key_type = match args_type[0].remove_nullable() {
+            DataType::Array(box key_type) => key_type,
+            _ => return None,
+        };
+        let value_type = match args_type[1].remove_nullable() {
+            DataType::Array(box value_type) => value_type,
+            _ => return None,
+        };
+        let sum_type = match value_type.remove_nullable() {
+            DataType::Number(ty) if ty.is_float() => NumberDataType::Float64,
+            DataType::Number(ty) if ty.is_signed() => NumberDataType::Int64,
+            DataType::Number(_) => NumberDataType::UInt64,
+            DataType::Decimal(_) => NumberDataType::Float64,
+            _ => return None,
+        };
+        let value_type = if value_type.is_nullable() {
+            DataType::Number(sum_type).wrap_nullable()
+        } else {
+            DataType::Number(sum_type)
+        };
+        let mut sig_args_type = vec![
+            DataType::Array(Box::new(key_type.clone())),
+            DataType::Array(Box::new(value_type)),
+        ];
+        if args_type.len() == 3 {
+            if args_type[2].remove_nullable() != DataType::String {
+                return None;
+            }
+            sig_args_type.push(DataType::String);
+        }
+        let return_type = DataType::Tuple(vec![
+            DataType::Array(Box::new(key_type.clone())),
+            DataType::Array(Box::new(DataType::Number(sum_type))),
+        ]);
+        let f = Function {
+            signature: FunctionSignature {
+                name: "array_grouped_sum".to_string(),
+                args_type: sig_args_type,
+                return_type: return_type.clone(),
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::MayThrow),
+                eval: Box::new(move |args, ctx| {
+                    array_grouped_sum_fn(args, &key_type, sum_type, &return_type, ctx)
+                }),
+            },
+        };
+
+        if args_type.iter().any(|ty| ty.is_nullable()) {
+            Some(Arc::new(f.passthrough_nullable()))
+        } else {
+            Some(Arc::new(f))
+        }
+    });
+}
+
+/// Convert the 1-based start position of `slice` to the offset in an array of `len` elements,
+/// 0 is taken as the head as the unsigned overloads do. A negative position counts from the
+/// tail, the positions out of range are clamped.
+fn slice_start(start: i64, len: usize) -> usize {
+    match start {
+        0 => 0,
+        1.. => (start as usize - 1).min(len),
+        _ => len.saturating_sub(start.unsigned_abs() as usize),
+    }
+}
+
+/// Convert the 1-based inclusive end position of `slice` to the exclusive end offset in an
+/// array of `len` elements. A negative position counts from the tail, the positions out of
+/// range are clamped.
+fn slice_end(end: i64, len: usize) -> usize {
+    if end >= 0 {
+        (end as usize).min(len)
+    } else {
+        (len + 1).saturating_sub(end.unsigned_abs() as usize)
+    }
+}
+
+#[derive(Clone, Copy)]
+enum GroupedAggregate {
+    Sum,
+    Min,
+    Max,
+}
+
+impl GroupedAggregate {
+    fn name(&self) -> &'static str {
+        match self {
+            GroupedAggregate::Sum => "sum",
+            GroupedAggregate::Min => "min",
+            GroupedAggregate::Max => "max",
+        }
+    }
+}
+
+/// Group the values by the keys at the same positions, and aggregate the values of
+/// each group with the mode. The keys are returned in ascending order along with the
+/// aggregated values, NULL values are skipped, so are the keys with only NULL values.
+fn array_grouped_sum_fn(
+    args: &[ValueRef<AnyType>],
+    key_type: &DataType,
+    sum_type: NumberDataType,
+    return_type: &DataType,
+    ctx: &mut EvalContext,
+) -> Value<AnyType> {
+    fn aggregate(
+        mode: GroupedAggregate,
+        key: &ScalarRef,
+        acc: NumberScalar,
+        val: NumberScalar,
+    ) -> Result<NumberScalar, String> {
+        let overflow = |ty: &str| {
+            format!(
+                "the {} of the values of key {key} is out of range of {ty}",
+                mode.name()
+            )
+        };
+        match (acc, val) {
+            (NumberScalar::Int64(acc), NumberScalar::Int64(val)) => match mode {
+                GroupedAggregate::Min => Ok(NumberScalar::Int64(acc.min(val))),
+                GroupedAggregate::Max => Ok(NumberScalar::Int64(acc.max(val))),
+                GroupedAggregate::Sum => acc
+                    .checked_add(val)
+                    .map(NumberScalar::Int64)
+                    .ok_or_else(|| overflow("Int64")),
+            },
+            (NumberScalar::UInt64(acc), NumberScalar::UInt64(val)) => match mode {
+                GroupedAggregate::Min => Ok(NumberScalar::UInt64(acc.min(val))),
+                GroupedAggregate::Max => Ok(NumberScalar::UInt64(acc.max(val))),
+                GroupedAggregate::Sum => acc
+                    .checked_add(val)
+                    .map(NumberScalar::UInt64)
+                    .ok_or_else(|| overflow("UInt64")),
+            },
+            (NumberScalar::Float64(acc), NumberScalar::Float64(val)) => match mode {
+                GroupedAggregate::Min => Ok(NumberScalar::Float64(acc.min(val))),
+                GroupedAggregate::Max => Ok(NumberScalar::Float64(acc.max(val))),
+                GroupedAggregate::Sum => Ok(NumberScalar::Float64(acc + val)),
+            },
+            (acc, val) => Err(format!(
+                "the values of key {key} can't be aggregated by {}, got {} and {}",
+                mode.name(),
+                ScalarRef::Number(acc),
+                ScalarRef::Number(val)
+            )),
+        }
+    }
+
+    // The mode is parsed once, so it must be a constant.
+    let mode = match args.get(2) {
+        None => Ok(GroupedAggregate::Sum),
+        Some(ValueRef::Scalar(ScalarRef::String(mode))) => {
+            match String::from_utf8_lossy(mode).to_lowercase().as_str() {
+                "sum" => Ok(GroupedAggregate::Sum),
+                "min" => Ok(GroupedAggregate::Min),
+                "max" => Ok(GroupedAggregate::Max),
+                mode => Err(format!(
+                    "invalid mode '{mode}', expected one of 'sum', 'min' and 'max'"
+                )),
+            }
+        }
+        Some(_) => Err("the mode of array_grouped_sum must be a constant string".to_string()),
+    };
+
+    let len = args.iter().find_map(|arg| match arg {
+        ValueRef::Column(col) => Some(col.len()),
+        _ => None,
+    });
+    let mut builder = ColumnBuilder::with_capacity(return_type, len.unwrap_or(1));
+    for row in 0..len.unwrap_or(1) {
+        let (Some(ScalarRef::Array(keys)), Some(ScalarRef::Array(values))) =
+            (args[0].index(row), args[1].index(row))
+        else {
+            ctx.set_error(row, "the keys and the values of array_grouped_sum must be arrays");
+            builder.push_default();
+            continue;
+        };
+        let mut groups = BTreeMap::new();
+        match mode {
+            Err(ref err) => ctx.set_error(row, err.clone()),
+            Ok(_) if keys.len() != values.len() => ctx.set_error(
+                row,
+                format!(
+                    "the length of keys {} does not match the length of values {}",
+                    keys.len(),
+                    values.len()
+                ),
+            ),
+            Ok(mode) => {
+                for (key, value) in keys.iter().zip(values.iter()) {
+                    let ScalarRef::Number(value) = value else {
+                        continue;
+                    };
+                    let acc = match groups.remove(&key) {
+                        Some(acc) => match aggregate(mode, &key, acc, value) {
+                            Ok(acc) => acc,
+                            Err(err) => {
+                                ctx.set_error(row, err);
+                                groups.clear();
+                                break;
+                            }
+                        },
+                        None => value,
+                    };
+                    groups.insert(key, acc);
+                }
+            }
+        }
+
+        let mut group_keys = ColumnBuilder::with_capacity(key_type, groups.len());
+        let mut group_values =
+            ColumnBuilder::with_capacity(&DataType::Number(sum_type), groups.len());
+        for (key, value) in groups {
+            group_keys.push(key);
+            group_values.push(ScalarRef::Number(value));
+        }
+        builder.push(ScalarRef::Tuple(vec![
+            ScalarRef::Array(group_keys.build()),
+            ScalarRef::Array(group_values.build()),
+        ]));
+    }
+
+    match len {
+        Some(_) => Value::Column(builder.build()),
+        None => Value::Scalar(builder.build_scalar()),
+    }
+}
+
+/// Convert the arrays to tuples with one field per element, the length of
+/// the arrays must be equal to the number of field names.
+fn array_to_struct_fn(
+    arg: &ValueRef<AnyType>,
+    num_fields: usize,
+    tuple_type: &DataType,
+    ctx: &mut EvalContext,
+) -> Value<AnyType> {
+    fn push_tuple(
+        arr: &Column,
+        num_fields: usize,
+        row: usize,
+        builder: &mut ColumnBuilder,
+        ctx: &mut EvalContext,
+    ) {
+        if arr.len() != num_fields {
+            ctx.set_error(
+                row,
+                format!(
+                    "array length {} does not match the number of field names {}",
+                    arr.len(),
+                    num_fields
+                ),
+            );
+            builder.push_default();
+        } else {
+            builder.push(ScalarRef::Tuple(arr.iter().collect()));
+        }
+    }
+
+    match arg {
+        ValueRef::Scalar(ScalarRef::Array(arr)) => {
+            let mut builder = ColumnBuilder::with_capacity(tuple_type, 1);
+            push_tuple(arr, num_fields, 0, &mut builder, ctx);
+            Value::Scalar(builder.build_scalar())
+        }
+        ValueRef::Column(Column::Array(box array_col)) => {
+            let mut builder = ColumnBuilder::with_capacity(tuple_type, array_col.len());
+            for (row, arr) in array_col.iter().enumerate() {
+                push_tuple(&arr, num_fields, row, &mut builder, ctx);
+            }
+            Value::Column(builder.build())
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// Convert the arrays of `(key, value)` tuples to tuples with one field per key,
+/// the keys missing in the arrays are filled with NULL. If a key appears more than
+/// once, the last value wins. The NULL elements and the elements with NULL keys are ignored.
+fn array_pivot_fn(
+    arg: &ValueRef<AnyType>,
+    keys: &[Vec<u8>],
+    tuple_type: &DataType,
+) -> Value<AnyType> {
+    fn push_tuple(arr: &Column, keys: &[Vec<u8>], builder: &mut ColumnBuilder) {
+        let mut fields = vec![ScalarRef::Null; keys.len()];
+        for val in arr.iter() {
+            let ScalarRef::Tuple(pair) = val else {
+                continue;
+            };
+            let ScalarRef::String(key) = pair[0] else {
+                continue;
+            };
+            if let Some(pos) = keys.iter().position(|k| k.as_slice() == key) {
+                fields[pos] = pair[1].clone();
+            }
+        }
+        builder.push(ScalarRef::Tuple(fields));
+    }
+
+    match arg {
+        ValueRef::Scalar(ScalarRef::Array(arr)) => {
+            let mut builder = ColumnBuilder::with_capacity(tuple_type, 1);
+            push_tuple(arr, keys, &mut builder);
+            Value::Scalar(builder.build_scalar())
+        }
+        ValueRef::Column(Column::Array(box array_col)) => {
+            let mut builder = ColumnBuilder::with_capacity(tuple_type, array_col.len());
+            for arr in array_col.iter() {
+                push_tuple(&arr, keys, &mut builder);
+            }
+            Value::Column(builder.build())
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// Encode the consecutive equal elements of the arrays as `(value, run length)` pairs,
+/// NULL elements are treated as equal, so consecutive NULLs are encoded as one run.
+fn array_group_consecutive_fn(arg: &ValueRef<AnyType>, tuple_type: &DataType) -> Value<AnyType> {
+    fn push_runs(arr: &Column, builder: &mut ColumnBuilder) {
+        let mut iter = arr.iter();
+        let Some(mut value) = iter.next() else {
+            return;
+        };
+        let mut count = 1_u64;
+        for val in iter {
+            if val == value {
+                count += 1;
+            } else {
+                builder.push(ScalarRef::Tuple(vec![
+                    value,
+                    ScalarRef::Number(NumberScalar::UInt64(count)),
+                ]));
+                value = val;
+                count = 1;
+            }
+        }
+        builder.push(ScalarRef::Tuple(vec![
+            value,
+            ScalarRef::Number(NumberScalar::UInt64(count)),
+        ]));
+    }
+
+    match arg {
+        ValueRef::Scalar(ScalarRef::Array(arr)) => {
+            let mut builder = ColumnBuilder::with_capacity(tuple_type, arr.len());
+            push_runs(arr, &mut builder);
+            Value::Scalar(Scalar::Array(builder.build()))
+        }
+        ValueRef::Column(Column::Array(box array_col)) => {
+            let mut builder = ColumnBuilder::with_capacity(tuple_type, array_col.values.len());
+            let mut offsets = Vec::with_capacity(array_col.len() + 1);
+            offsets.push(0);
+            for arr in array_col.iter() {
+                push_runs(&arr, &mut builder);
+                offsets.push(builder.len() as u64);
+            }
+            Value::Column(Column::Array(Box::new(ArrayColumn {
+                values: builder.build(),
+                offsets: offsets.into(),
+            })))
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// Pair the elements at the same positions of the two arrays into tuples. The longer array
+/// is truncated to the shorter one, or an error is reported if `strict_array_zip` is enabled.
+fn array_zip_fn(
+    args: &[ValueRef<AnyType>],
+    tuple_type: &DataType,
+    ctx: &mut EvalContext,
+) -> Value<AnyType> {
+    let len = args.iter().find_map(|arg| match arg {
+        ValueRef::Column(col) => Some(col.len()),
+        _ => None,
+    });
+    let array_at = |arg: &ValueRef<AnyType>, row: usize| match arg {
+        ValueRef::Scalar(ScalarRef::Array(arr)) => arr.clone(),
+        ValueRef::Column(Column::Array(box array_col)) => unsafe { array_col.index_unchecked(row) },
+        _ => unreachable!(),
+    };
+
+    let mut builder = ColumnBuilder::with_capacity(tuple_type, 0);
+    let mut offsets = Vec::with_capacity(len.unwrap_or(1) + 1);
+    offsets.push(0);
+    for row in 0..len.unwrap_or(1) {
+        let lhs = array_at(&args[0], row);
+        let rhs = array_at(&args[1], row);
+        if ctx.func_ctx.strict_array_zip && lhs.len() != rhs.len() {
+            ctx.set_error(
+                row,
+                format!(
+                    "array_zip requires the arrays to have the same length, but got lengths {} and {}",
+                    lhs.len(),
+                    rhs.len()
+                ),
+            );
+        } else {
+            for (lhs_item, rhs_item) in lhs.iter().zip(rhs.iter()) {
+                builder.push(ScalarRef::Tuple(vec![lhs_item, rhs_item]));
+            }
+        }
+        offsets.push(builder.len() as u64);
+    }
+
+    match len {
+        Some(_) => Value::Column(Column::Array(Box::new(ArrayColumn {
+            values: builder.build(),
+            offsets: offsets.into(),
+        }))),
+        None => Value::Scalar(Scalar::Array(builder.build())),
+    }
+}
+
+/// Check if the arrays hold the same elements regardless of the order, NULL is taken as
+/// an element as well. The occurrences of each element are compared only if `multiset` is set.
+fn array_equals_unordered(lhs: &Column, rhs: &Column, multiset: bool) -> bool {
+    if multiset && lhs.len() != rhs.len() {
+        return false;
+    }
+    let count = |arr: &Column| {
+        let mut counts: HashMap<u128, usize> = HashMap::with_capacity(arr.len());
+        for val in arr.iter() {
+            let mut hasher = SipHasher24::new();
+            val.hash(&mut hasher);
+            *counts.entry(hasher.finish128().into()).or_default() += 1;
+        }
+        counts
+    };
+    let (lhs, rhs) = (count(lhs), count(rhs));
+    if multiset {
+        lhs == rhs
+    } else {
+        lhs.len() == rhs.len() && lhs.keys().all(|key| rhs.contains_key(key))
+    }
+}
+
+fn array_set_fn(
+    arr: NullableColumn<GenericType<0>>,
+    index: i64,
+    val: Option<ScalarRef<'_>>,
+    output: &mut ArrayColumnBuilder<NullableType<GenericType<0>>>,
+    ctx: &mut EvalContext,
+) {
+    // The index is 1-based. If it's beyond the end of the array, the array is
+    // extended with NULLs, or an error is reported if `strict_array_set` is enabled.
+    if index <= 0 {
+        ctx.set_error(
+            output.len(),
+            format!("the index of array_set must be positive, but got {index}"),
+        );
+        output.push_default();
+        return;
+    }
+    if ctx.func_ctx.strict_array_set && index as usize > arr.len() {
+        ctx.set_error(
+            output.len(),
+            format!(
+                "the index of array_set must be in the range [1, {}], but got {index}",
+                arr.len()
+            ),
+        );
+        output.push_default();
+        return;
+    }
+    let pos = index as usize - 1;
+    for (i, item) in arr.iter().enumerate() {
+        if i == pos {
+            output.put_item(val.clone());
+        } else {
+            output.put_item(item);
+        }
+    }
+    if pos >= arr.len() {
+        for _ in arr.len()..pos {
+            output.put_item(None);
+        }
+        output.put_item(val);
+    }
+    output.commit_row()
+}
+
+#[derive(Clone, Copy)]
+enum SetOperation {
+    Intersect,
+    Union,
+    Except,
+}
+
+// The result keeps the first occurrence of each distinct element, in the order of `lhs`
+// followed by `rhs`. NULLs are compared as equal to each other.
+fn array_set_operation(operation: SetOperation, lhs: &Column, rhs: &Column) -> Column {
+    let hash = |val: &ScalarRef| -> u128 {
+        let mut hasher = SipHasher24::new();
+        val.hash(&mut hasher);
+        hasher.finish128().into()
+    };
+    let rhs_keys: HashSet<u128> = rhs.iter().map(|val| hash(&val)).collect();
+
+    let data_type = lhs.data_type();
+    let mut builder = ColumnBuilder::with_capacity(&data_type, lhs.len());
+    let mut seen: HashSet<u128> = HashSet::with_capacity(lhs.len());
+    for val in lhs.iter() {
+        let key = hash(&val);
+        let keep = match operation {
+            SetOperation::Intersect => rhs_keys.contains(&key),
+            SetOperation::Except => !rhs_keys.contains(&key),
+            SetOperation::Union => true,
+        };
+        if keep && seen.insert(key) {
+            builder.push(val);
+        }
+    }
+    if let SetOperation::Union = operation {
+        for val in rhs.iter() {
+            if seen.insert(hash(&val)) {
+                builder.push(val);
+            }
+        }
+    }
+    builder.build()
 }
 
 fn register_array_aggr(registry: &mut FunctionRegistry) {
@@ -22,6 +22,7 @@ use databend_common_expression::types::GenericType;
 use databend_common_expression::types::MapType;
 use databend_common_expression::types::NullType;
 use databend_common_expression::types::NullableType;
+use databend_common_expression::vectorize_with_builder_1_arg;
 use databend_common_expression::vectorize_with_builder_2_arg;
 use databend_common_expression::FunctionDomain;
 use databend_common_expression::FunctionRegistry;
@@ -84,6 +85,38 @@ pub fn register(registry: &mut FunctionRegistry) {
         ),
     );
 
+    registry.register_passthrough_nullable_1_arg::<EmptyMapType, EmptyMapType, _, _>(
+        "array_compact_map",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<EmptyMapType, EmptyMapType>(|_, output, _| {
+            *output += 1;
+        }),
+    );
+
+    // All the values are NULL, so all the entries are removed.
+    registry.register_passthrough_nullable_1_arg::<MapType<GenericType<0>, NullType>, EmptyMapType, _, _>(
+        "array_compact_map",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<MapType<GenericType<0>, NullType>, EmptyMapType>(|_, output, _| {
+            *output += 1;
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<MapType<GenericType<0>, NullableType<GenericType<1>>>, MapType<GenericType<0>, GenericType<1>>, _, _>(
+        "array_compact_map",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<MapType<GenericType<0>, NullableType<GenericType<1>>>, MapType<GenericType<0>, GenericType<1>>>(
+            |map, output, _| {
+                for (key, val) in map.iter() {
+                    if let Some(val) = val {
+                        output.put_item((key, val));
+                    }
+                }
+                output.commit_row();
+            }
+        ),
+    );
+
     registry.register_2_arg_core::<NullableType<EmptyMapType>, NullableType<GenericType<0>>, NullType, _, _>(
         "get",
         |_, _, _| FunctionDomain::Full,
@@ -35,6 +35,7 @@ use databend_common_expression::types::ArrayType;
 use databend_common_expression::types::BooleanType;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::DateType;
+use databend_common_expression::types::EmptyArrayType;
 use databend_common_expression::types::GenericType;
 use databend_common_expression::types::NullableType;
 use databend_common_expression::types::NumberDataType;
@@ -563,6 +564,46 @@ pub fn register(registry: &mut FunctionRegistry) {
         }))
     });
 
+    registry.register_passthrough_nullable_2_arg::<EmptyArrayType, StringType, EmptyArrayType, _, _>(
+        "array_json_path_exists",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<EmptyArrayType, StringType, EmptyArrayType>(
+            |_, _, output, _| {
+                *output += 1;
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<ArrayType<NullableType<VariantType>>, StringType, ArrayType<BooleanType>, _, _>(
+        "array_json_path_exists",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<ArrayType<NullableType<VariantType>>, StringType, ArrayType<BooleanType>>(
+            |arr, path, output, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(output.len()) {
+                        output.commit_row();
+                        return;
+                    }
+                }
+                match parse_key_paths(path) {
+                    Ok(path) => {
+                        // The elements that are not objects don't contain the path.
+                        for val in arr.iter() {
+                            let exists = val.is_some_and(|val| {
+                                is_object(val) && get_by_keypath(val, path.paths.iter()).is_some()
+                            });
+                            output.put_item(exists);
+                        }
+                    }
+                    Err(err) => {
+                        ctx.set_error(output.len(), err.to_string());
+                    }
+                }
+                output.commit_row();
+            },
+        ),
+    );
+
     registry.register_combine_nullable_2_arg::<VariantType, StringType, VariantType, _, _>(
         "get_path",
         |_, _, _| FunctionDomain::MayThrow,
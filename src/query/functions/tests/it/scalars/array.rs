@@ -33,12 +33,22 @@ fn test_array() {
     test_contains(file);
     test_array_remove_first(file);
     test_array_remove_last(file);
+    test_array_reverse(file);
     test_array_concat(file);
+    test_array_set_operations(file);
+    test_array_flatten(file);
     test_array_prepend(file);
     test_array_append(file);
+    test_array_set(file);
+    test_array_resize(file);
+    test_array_zip(file);
     test_array_indexof(file);
+    test_array_positions(file);
     test_array_unique(file);
     test_array_distinct(file);
+    test_array_compact(file);
+    test_array_to_string(file);
+    test_string_to_array(file);
     test_array_sum(file);
     test_array_avg(file);
     test_array_count(file);
@@ -92,11 +102,18 @@ fn test_get(file: &mut impl Write) {
     run_ast(file, "[1, null, 3][1]", &[]);
     run_ast(file, "[1, null, 3][2]", &[]);
     run_ast(file, "[1, 2, 3][4]", &[]);
+    run_ast(file, "[1, 2, 3][-1]", &[]);
+    run_ast(file, "[1, 2, 3][-4]", &[]);
     run_ast(file, "[a, b][idx]", &[
         ("a", Int16Type::from_data(vec![0i16, 1, 2])),
         ("b", Int16Type::from_data(vec![3i16, 4, 5])),
         ("idx", UInt16Type::from_data(vec![1u16, 2, 3])),
     ]);
+    run_ast(file, "[a, b][idx]", &[
+        ("a", Int16Type::from_data(vec![0i16, 1, 2])),
+        ("b", Int16Type::from_data(vec![3i16, 4, 5])),
+        ("idx", Int16Type::from_data(vec![1i16, -1, -3])),
+    ]);
 }
 
 fn test_slice(file: &mut impl Write) {
@@ -122,6 +139,17 @@ fn test_slice(file: &mut impl Write) {
         ("b", Int16Type::from_data(vec![3i16, 4, 5])),
         ("c", Int16Type::from_data(vec![7i16, 8, 9])),
     ]);
+
+    run_ast(file, "slice([0, 1, 2, 3], -2)", &[]);
+    run_ast(file, "slice([0, 1, 2, 3], -2, 2)", &[]);
+    run_ast(file, "slice([0, 1, 2, 3], -2, -1)", &[]);
+    run_ast(file, "slice(['a', 'b', 'c', 'd'], -10, 2)", &[]);
+    run_ast(file, "slice([a, b, c], i)", &[
+        ("a", Int16Type::from_data(vec![0i16, 1, 2])),
+        ("b", Int16Type::from_data(vec![3i16, 4, 5])),
+        ("c", Int16Type::from_data(vec![7i16, 8, 9])),
+        ("i", Int64Type::from_data(vec![-1i64, -2, -10])),
+    ]);
 }
 
 fn test_array_remove_first(file: &mut impl Write) {
@@ -178,6 +206,16 @@ fn test_contains(file: &mut impl Write) {
     );
 }
 
+fn test_array_reverse(file: &mut impl Write) {
+    run_ast(file, "array_reverse([])", &[]);
+    run_ast(file, "array_reverse([1, NULL, 3])", &[]);
+    run_ast(file, "array_reverse(['a', 'b', 'c'])", &[]);
+    run_ast(file, "array_reverse([a, b])", &[
+        ("a", Int16Type::from_data(vec![0i16, 1, 2])),
+        ("b", Int16Type::from_data(vec![3i16, 4, 5])),
+    ]);
+}
+
 fn test_array_concat(file: &mut impl Write) {
     run_ast(file, "array_concat([], [])", &[]);
     run_ast(file, "array_concat([], [1,2])", &[]);
@@ -204,6 +242,47 @@ fn test_array_concat(file: &mut impl Write) {
     run_ast(file, "array_concat([1,2,null], [int8_col])", &columns);
 }
 
+fn test_array_set_operations(file: &mut impl Write) {
+    run_ast(file, "array_intersect([], [])", &[]);
+    run_ast(file, "array_union([], [1, 2])", &[]);
+    run_ast(file, "array_intersect([false, true], [1, 2])", &[]);
+    run_ast(file, "array_intersect([1, 2, 2, 3], [2, 3, 4])", &[]);
+    run_ast(file, "array_union([1, 2, 2, 3], [2, 3, 4])", &[]);
+    run_ast(file, "array_except([1, 2, 2, 3], [2, 3, 4])", &[]);
+    run_ast(file, "array_intersect(['a', 'b'], ['c'])", &[]);
+    run_ast(file, "array_intersect([1, NULL, 2, NULL], [NULL, 2, 3])", &[]);
+    run_ast(file, "array_union([1, NULL], [NULL, 2])", &[]);
+    run_ast(file, "array_except([1, NULL, 2], [NULL, 3])", &[]);
+
+    let columns = [
+        ("int8_col", Int8Type::from_data(vec![1i8, 2, 7, 8])),
+        (
+            "nullable_col",
+            Int64Type::from_data_with_validity(vec![9i64, 10, 11, 12], vec![
+                true, true, false, false,
+            ]),
+        ),
+    ];
+
+    run_ast(file, "array_intersect([1, 2, null], [int8_col])", &columns);
+    run_ast(file, "array_union([1, 2, null], [int8_col])", &columns);
+    run_ast(
+        file,
+        "array_except([1, 2, 3, 4, 5, null], [nullable_col])",
+        &columns,
+    );
+}
+
+fn test_array_flatten(file: &mut impl Write) {
+    run_ast(file, "array_flatten([[1, 2], [], [3, NULL]])", &[]);
+    run_ast(file, "array_flatten([[1, 2], NULL, [3]])", &[]);
+    run_ast(file, "array_flatten([[a, b], [c]])", &[
+        ("a", Int16Type::from_data(vec![0i16, 1, 2])),
+        ("b", Int16Type::from_data(vec![3i16, 4, 5])),
+        ("c", Int16Type::from_data(vec![6i16, 7, 8])),
+    ]);
+}
+
 fn test_array_prepend(file: &mut impl Write) {
     run_ast(file, "array_prepend(1, [])", &[]);
     run_ast(file, "array_prepend(1, [2, 3, NULL, 4])", &[]);
@@ -226,6 +305,48 @@ fn test_array_append(file: &mut impl Write) {
     ]);
 }
 
+fn test_array_set(file: &mut impl Write) {
+    run_ast(file, "array_set([1, 2, 3], 2, 99)", &[]);
+    run_ast(file, "array_set([1, 2, 3], 5, 9)", &[]);
+    run_ast(file, "array_set(['a', 'b'], 1, 'z')", &[]);
+    run_ast(file, "array_set([1, 2, 3], 0, 9)", &[]);
+
+    run_ast(file, "array_set([a, b], n, c)", &[
+        ("a", Int16Type::from_data(vec![0i16, 1, 2, 3])),
+        ("b", Int16Type::from_data(vec![4i16, 5, 6, 7])),
+        ("n", UInt8Type::from_data(vec![1u8, 2, 3, 4])),
+        ("c", Int16Type::from_data(vec![10i16, 11, 12, 13])),
+    ]);
+}
+
+fn test_array_resize(file: &mut impl Write) {
+    run_ast(file, "array_resize([], 2)", &[]);
+    run_ast(file, "array_resize([1, 2, 3], 5)", &[]);
+    run_ast(file, "array_resize([1, 2, 3], 2)", &[]);
+    run_ast(file, "array_resize([1, 2, 3], 0)", &[]);
+    run_ast(file, "array_resize([1, 2, 3], 3, 0)", &[]);
+    run_ast(file, "array_resize(['a', 'b'], 4, 'z')", &[]);
+    run_ast(file, "array_resize([1, 2, 3], -1)", &[]);
+
+    run_ast(file, "array_resize([a, b], n)", &[
+        ("a", Int16Type::from_data(vec![0i16, 1, 2, 3])),
+        ("b", Int16Type::from_data(vec![4i16, 5, 6, 7])),
+        ("n", UInt8Type::from_data(vec![0u8, 1, 2, 3])),
+    ]);
+}
+
+fn test_array_zip(file: &mut impl Write) {
+    run_ast(file, "array_zip([], [])", &[]);
+    run_ast(file, "array_zip([1, 2, 3], ['a', 'b', 'c'])", &[]);
+    run_ast(file, "array_zip([1, 2, 3], ['a', 'b'])", &[]);
+    run_ast(file, "array_zip([1, NULL], ['a', 'b'])", &[]);
+    run_ast(file, "array_zip([a, b], [c])", &[
+        ("a", Int16Type::from_data(vec![0i16, 1, 2])),
+        ("b", Int16Type::from_data(vec![3i16, 4, 5])),
+        ("c", Int16Type::from_data(vec![6i16, 7, 8])),
+    ]);
+}
+
 fn test_array_indexof(file: &mut impl Write) {
     run_ast(file, "array_indexof([], NULL)", &[]);
     run_ast(file, "array_indexof(NULL, NULL)", &[]);
@@ -258,6 +379,29 @@ fn test_array_indexof(file: &mut impl Write) {
     run_ast(file, "array_indexof([9,10,null], int8_col)", &columns);
 }
 
+fn test_array_positions(file: &mut impl Write) {
+    run_ast(file, "array_positions([], NULL)", &[]);
+    run_ast(file, "array_positions(NULL, NULL)", &[]);
+    run_ast(file, "array_positions([1, 2, 1, 3, 1], 1)", &[]);
+    run_ast(file, "array_positions([1, 2, 3], 4)", &[]);
+    run_ast(
+        file,
+        "array_positions([1::VARIANT,'x'::VARIANT,null::VARIANT,'x'::VARIANT], 'x'::VARIANT)",
+        &[],
+    );
+
+    let columns = [(
+        "nullable_col",
+        Int64Type::from_data_with_validity(vec![9i64, 10, 11, 12], vec![true, true, false, false]),
+    )];
+
+    run_ast(
+        file,
+        "array_positions([1, NULL, 9, NULL, 9], nullable_col)",
+        &columns,
+    );
+}
+
 fn test_array_unique(file: &mut impl Write) {
     run_ast(file, "array_unique([])", &[]);
     run_ast(file, "array_unique([1, 1, 2, 2, 3, NULL])", &[]);
@@ -292,6 +436,36 @@ fn test_array_distinct(file: &mut impl Write) {
     ]);
 }
 
+fn test_array_compact(file: &mut impl Write) {
+    run_ast(file, "array_compact([])", &[]);
+    run_ast(file, "array_compact([NULL, NULL])", &[]);
+    run_ast(file, "array_compact([1, 2, 3])", &[]);
+    run_ast(file, "array_compact([1, NULL, 2, NULL, 2])", &[]);
+
+    run_ast(file, "array_compact([nullable_col])", &[(
+        "nullable_col",
+        Int64Type::from_data_with_validity(vec![9i64, 10, 11, 12], vec![
+            true, true, false, false,
+        ]),
+    )]);
+}
+
+fn test_array_to_string(file: &mut impl Write) {
+    run_ast(file, "array_to_string([], ',')", &[]);
+    run_ast(file, "array_to_string(['a', 'b', 'c'], ', ')", &[]);
+    run_ast(file, "array_to_string(['a', NULL, 'c'], '--')", &[]);
+    run_ast(file, "array_to_string([], ',', '*')", &[]);
+    run_ast(file, "array_to_string(['a', NULL, 'c'], ',', '*')", &[]);
+}
+
+fn test_string_to_array(file: &mut impl Write) {
+    run_ast(file, "string_to_array('a,b,c', ',')", &[]);
+    run_ast(file, "string_to_array('a--b--c', '--')", &[]);
+    run_ast(file, "string_to_array('a,,b,', ',')", &[]);
+    run_ast(file, "string_to_array('', ',')", &[]);
+    run_ast(file, "string_to_array('abc', '')", &[]);
+}
+
 fn test_array_sum(file: &mut impl Write) {
     run_ast(file, "array_sum([])", &[]);
     run_ast(file, "array_sum([1, 2, 3, 4, 5, 6, 7])", &[]);
@@ -403,6 +577,7 @@ fn test_array_max(file: &mut impl Write) {
     run_ast(file, "array_max([1.2, 3.4, 5.6, 7.8])", &[]);
     run_ast(file, "array_max([1.2, NULL, 3.4, 5.6, NULL])", &[]);
     run_ast(file, "array_max(['a', 'b', 'c', 'd', 'e'])", &[]);
+    run_ast(file, "array_max(['a', 'z', 'm'])", &[]);
     run_ast(file, "array_max(['a', 'b', NULL, 'c', 'd', NULL])", &[]);
 
     run_ast(file, "array_max([a, b, c, d])", &[
@@ -690,4 +865,22 @@ fn test_array_sort(file: &mut impl Write) {
         "array_sort_desc_null_last([1.2, NULL, 3.4, 5.6, '2.2', NULL])",
         &[],
     );
+    run_ast(file, "array_sort_asc_null_first(['c', 'a', NULL, 'b'])", &[]);
+    run_ast(file, "array_sort_desc_null_last(['c', 'a', NULL, 'b'])", &[]);
+    run_ast(file, "array_sort_desc_null_first(['b', 'a', 'c'])", &[]);
+
+    run_ast(file, "array_sort_asc_null_last([a, b, c])", &[
+        (
+            "a",
+            UInt64Type::from_data_with_validity(vec![3u64, 1, 0, 4], vec![true, true, false, true]),
+        ),
+        (
+            "b",
+            UInt64Type::from_data_with_validity(vec![1u64, 0, 5, 2], vec![true, false, true, true]),
+        ),
+        (
+            "c",
+            UInt64Type::from_data_with_validity(vec![2u64, 7, 4, 0], vec![true, true, true, false]),
+        ),
+    ]);
 }
@@ -0,0 +1,85 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use std::sync::Arc;
+
+use bumpalo::Bump;
+use criterion::Criterion;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::FromData;
+use databend_common_expression::HashMethod;
+use databend_common_expression::HashMethodKeysU64;
+use databend_common_hashtable::HashtableEntryRefLike;
+use databend_common_hashtable::HashtableLike;
+use databend_query::pipelines::processors::transforms::group_by::PartitionedHashMethod;
+use databend_query::pipelines::processors::transforms::group_by::PolymorphicKeysHelper;
+
+const NUM_KEYS: usize = 1_000_000;
+const CONVERT_THRESHOLD: usize = 20000;
+
+/// Benchmark the partial group by of high cardinality keys, either converting the hash table
+/// to the partitioned one after `CONVERT_THRESHOLD` keys or starting with the partitioned one.
+fn bench_group_by_partitioned(c: &mut Criterion) {
+    let mut group = c.benchmark_group("group_by_partitioned");
+    group.sample_size(10);
+
+    let method = HashMethodKeysU64::default();
+    let partitioned_method = PartitionedHashMethod::create(method.clone());
+    let column = UInt64Type::from_data((0..NUM_KEYS as u64).collect::<Vec<_>>());
+    let group_columns = vec![(column, DataType::Number(NumberDataType::UInt64))];
+    let state = method.build_keys_state(&group_columns, NUM_KEYS).unwrap();
+
+    group.bench_function(format!("convert/{NUM_KEYS}"), |b| {
+        b.iter(|| unsafe {
+            let mut keys = method.build_keys_iter(&state).unwrap();
+            let mut hashtable = method
+                .create_hash_table::<()>(Arc::new(Bump::new()))
+                .unwrap();
+            for key in keys.by_ref().take(CONVERT_THRESHOLD) {
+                let _ = hashtable.insert_and_entry(key);
+            }
+
+            let mut partitioned_hashtable = partitioned_method
+                .create_hash_table::<()>(Arc::new(Bump::new()))
+                .unwrap();
+            for item in hashtable.iter() {
+                let _ = partitioned_hashtable.insert_and_entry(item.key());
+            }
+            for key in keys {
+                let _ = partitioned_hashtable.insert_and_entry(key);
+            }
+            partitioned_hashtable.len()
+        })
+    });
+
+    group.bench_function(format!("start_partitioned/{NUM_KEYS}"), |b| {
+        b.iter(|| unsafe {
+            let mut partitioned_hashtable = partitioned_method
+                .create_hash_table::<()>(Arc::new(Bump::new()))
+                .unwrap();
+            for key in method.build_keys_iter(&state).unwrap() {
+                let _ = partitioned_hashtable.insert_and_entry(key);
+            }
+            partitioned_hashtable.len()
+        })
+    });
+}
+
+criterion_group!(benches, bench_group_by_partitioned);
+criterion_main!(benches);
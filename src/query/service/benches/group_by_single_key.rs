@@ -0,0 +1,76 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use std::sync::Arc;
+
+use bumpalo::Bump;
+use criterion::Criterion;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::FromData;
+use databend_common_expression::HashMethod;
+use databend_common_expression::HashMethodKeysU64;
+use databend_common_expression::HashMethodSerializer;
+use databend_common_hashtable::HashtableLike;
+use databend_query::pipelines::processors::transforms::group_by::PolymorphicKeysHelper;
+
+const NUM_ROWS: usize = 1_000_000;
+const NUM_KEYS: u64 = 10_000;
+
+/// Benchmark the partial group by of a single non-nullable integer column, either with the
+/// fixed keys method chosen for it, which reads the column buffer as the keys, or with the
+/// generic serializer method, which serializes the column into the keys first.
+fn bench_group_by_single_key(c: &mut Criterion) {
+    let mut group = c.benchmark_group("group_by_single_key");
+    group.sample_size(10);
+
+    let keys = (0..NUM_ROWS as u64).map(|v| v % NUM_KEYS).collect::<Vec<_>>();
+    let column = UInt64Type::from_data(keys);
+    let group_columns = vec![(column, DataType::Number(NumberDataType::UInt64))];
+
+    group.bench_function(format!("fixed_keys/{NUM_ROWS}"), |b| {
+        let method = HashMethodKeysU64::default();
+        b.iter(|| unsafe {
+            let state = method.build_keys_state(&group_columns, NUM_ROWS).unwrap();
+            let mut hashtable = method
+                .create_hash_table::<()>(Arc::new(Bump::new()))
+                .unwrap();
+            for key in method.build_keys_iter(&state).unwrap() {
+                let _ = hashtable.insert_and_entry(key);
+            }
+            hashtable.len()
+        })
+    });
+
+    group.bench_function(format!("serializer/{NUM_ROWS}"), |b| {
+        let method = HashMethodSerializer::default();
+        b.iter(|| unsafe {
+            let state = method.build_keys_state(&group_columns, NUM_ROWS).unwrap();
+            let mut hashtable = method
+                .create_hash_table::<()>(Arc::new(Bump::new()))
+                .unwrap();
+            for key in method.build_keys_iter(&state).unwrap() {
+                let _ = hashtable.insert_and_entry(key);
+            }
+            hashtable.len()
+        })
+    });
+}
+
+criterion_group!(benches, bench_group_by_single_key);
+criterion_main!(benches);
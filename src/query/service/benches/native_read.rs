@@ -0,0 +1,87 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use criterion::Criterion;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_sql::Planner;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::test_kits::TestFixture;
+use futures::TryStreamExt;
+
+const NUM_COLUMNS: usize = 200;
+
+async fn select_all(fixture: &TestFixture, decode_parallelism: usize) -> Result<Vec<DataBlock>> {
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings().set_setting(
+        "storage_native_decode_parallelism".to_string(),
+        decode_parallelism.to_string(),
+    )?;
+    let qry = format!("select * from {}.t where c0 >= 0", fixture.default_db_name());
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(&qry).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let stream = interpreter.execute(ctx).await?;
+    stream.try_collect::<Vec<_>>().await
+}
+
+/// Benchmark scanning a wide native table, decoding the remain columns of each page
+/// one by one or concurrently.
+fn bench_native_read(c: &mut Criterion) {
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    let fixture = runtime.block_on(async {
+        let fixture = TestFixture::setup().await?;
+        fixture.create_default_database().await?;
+        let db = fixture.default_db_name();
+
+        let columns = (0..NUM_COLUMNS)
+            .map(|i| format!("c{i} int"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let qry = format!("create table {db}.t({columns}) storage_format = 'native'");
+        fixture.execute_command(&qry).await?;
+        let values = (0..NUM_COLUMNS)
+            .map(|i| format!("number + {i}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let qry = format!("insert into {db}.t select {values} from numbers(100000)");
+        fixture.execute_command(&qry).await?;
+        Result::Ok(fixture)
+    });
+    let fixture = fixture.unwrap();
+
+    let mut group = c.benchmark_group("native_read");
+    group.sample_size(10);
+    for decode_parallelism in [0, 4, 8] {
+        group.bench_function(
+            format!("{NUM_COLUMNS}_columns/parallelism_{decode_parallelism}"),
+            |b| {
+                b.iter(|| {
+                    runtime
+                        .block_on(select_all(&fixture, decode_parallelism))
+                        .unwrap()
+                })
+            },
+        );
+    }
+}
+
+criterion_group!(benches, bench_native_read);
+criterion_main!(benches);
@@ -12,13 +12,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::future::Future;
 use std::sync::Arc;
+use std::time::Duration;
 
+use backoff::backoff::Backoff;
+use databend_common_base::base::tokio::sync::Semaphore;
+use databend_common_base::base::tokio::time::sleep;
 use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrackedFuture;
 use databend_common_catalog::catalog::CatalogManager;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_license::license::Feature;
+use databend_common_license::license_manager::get_license_manager;
 use databend_common_meta_app::schema::IndexMeta;
 use databend_common_meta_app::schema::ListIndexesByIdReq;
 use databend_common_meta_types::MetaId;
@@ -30,6 +38,7 @@ use databend_common_sql::BindContext;
 use databend_common_sql::Binder;
 use databend_common_sql::Metadata;
 use databend_common_sql::NameResolutionContext;
+use databend_common_storages_fuse::operations::set_backoff;
 use databend_storages_common_table_meta::meta::Location;
 use log::error;
 use log::info;
@@ -41,15 +50,19 @@ use crate::interpreters::RefreshVirtualColumnInterpreter;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelineCompleteExecutor;
 use crate::sessions::QueryContext;
+use crate::sessions::RefreshHookStats;
 
 pub struct RefreshDesc {
     pub catalog: String,
     pub database: String,
     pub table: String,
+    /// The id of the table if it's already resolved by the caller,
+    /// the table is looked up by name otherwise.
+    pub table_id: Option<MetaId>,
 }
 
 /// Hook refresh action with a on-finished callback.
-/// errors (if any) are ignored.
+/// errors (if any) don't fail the query, they are recorded in the `RefreshHookStats` of the query.
 pub async fn hook_refresh(
     ctx: Arc<QueryContext>,
     pipeline: &mut Pipeline,
@@ -66,17 +79,32 @@ pub async fn hook_refresh(
     pipeline.set_on_finished(move |may_error| match may_error {
         Ok(_) => {
             info!("execute pipeline finished successfully, starting run refresh job.");
-            GlobalIORuntime::instance().block_on(async move {
-                let result = do_hook_refresh(ctx, desc, refresh_virtual_column).await;
+            // The memory of the refresh is recorded to the query, so it's limited with the query.
+            let mem_stat = ctx.get_query_mem_stat().ok();
+            let refresh = async move {
+                let result = do_hook_refresh(ctx.clone(), desc, refresh_virtual_column).await;
+                let mut stats = RefreshHookStats::default();
                 match result {
-                    Ok(_) => Ok(()),
+                    Ok(refreshed) => {
+                        stats.succeeded = refreshed.iter().filter(|r| **r).count();
+                        stats.skipped = refreshed.len() - stats.succeeded;
+                    }
                     Err(e) if e.code() == ErrorCode::LICENSE_KEY_INVALID => {
                         error!("license key invalid: {}", e.message());
-                        Ok(())
+                        stats.skipped += 1;
+                        stats.error_codes.push(e.code());
+                    }
+                    Err(e) => {
+                        error!("refresh after write failed: {}", e);
+                        stats.failed += 1;
+                        stats.error_codes.push(e.code());
                     }
-                    Err(e) => Err(e),
                 }
-            })
+                ctx.add_refresh_hook_stats(stats);
+                Ok(())
+            };
+            GlobalIORuntime::instance()
+                .block_on(TrackedFuture::create_with_mem_stat(mem_stat, refresh))
         }
         Err(e) => Err(e.clone()),
     });
@@ -84,16 +112,44 @@ pub async fn hook_refresh(
     Ok(())
 }
 
-/// Hook refresh action with a on-finished callback.
+/// Run the refreshes of the table, return whether each of them is executed
+/// or skipped for having nothing to do.
 async fn do_hook_refresh(
     ctx: Arc<QueryContext>,
     desc: RefreshDesc,
     refresh_virtual_column: bool,
-) -> Result<()> {
-    let table_id = ctx
-        .get_table(&desc.catalog, &desc.database, &desc.table)
-        .await?
-        .get_id();
+) -> Result<Vec<bool>> {
+    let plans = generate_refresh_plans(ctx.clone(), &desc, refresh_virtual_column).await?;
+    if ctx.get_settings().get_refresh_hook_dry_run()? {
+        return dry_run_refresh_plans(ctx.as_ref(), &desc, &plans);
+    }
+
+    let max_threads = ctx.get_settings().get_max_threads()? as usize;
+    let max_attempts = ctx.get_settings().get_refresh_after_write_max_attempts()? as usize;
+    let mut tasks = Vec::with_capacity(plans.len());
+
+    for plan in plans {
+        let ctx = ctx.clone();
+        tasks.push(async move {
+            retry_transient(max_attempts, || refresh_plan(ctx.clone(), plan.clone())).await
+        });
+    }
+
+    try_join_all_bounded(tasks, max_threads).await
+}
+
+async fn generate_refresh_plans(
+    ctx: Arc<QueryContext>,
+    desc: &RefreshDesc,
+    refresh_virtual_column: bool,
+) -> Result<Vec<Plan>> {
+    let table_id = match desc.table_id {
+        Some(table_id) => table_id,
+        None => ctx
+            .get_table(&desc.catalog, &desc.database, &desc.table)
+            .await?
+            .get_id(),
+    };
 
     let mut plans = Vec::new();
 
@@ -101,65 +157,189 @@ async fn do_hook_refresh(
     plans.extend_from_slice(&agg_index_plans);
 
     if refresh_virtual_column {
-        let virtual_column_plan = generate_refresh_virtual_column_plan(ctx.clone(), &desc).await?;
+        let virtual_column_plan = generate_refresh_virtual_column_plan(ctx.clone(), desc).await?;
         plans.push(virtual_column_plan);
     }
 
-    let mut tasks = Vec::with_capacity(std::cmp::min(
-        ctx.get_settings().get_max_threads()? as usize,
-        plans.len(),
-    ));
+    Ok(plans)
+}
 
+/// Log the refresh plans instead of executing them, they are all reported as skipped.
+fn dry_run_refresh_plans(
+    ctx: &QueryContext,
+    desc: &RefreshDesc,
+    plans: &[Plan],
+) -> Result<Vec<bool>> {
+    let license_manager = get_license_manager();
     for plan in plans {
-        let ctx_cloned = ctx.clone();
-        tasks.push(async move {
-            match plan {
-                Plan::RefreshIndex(agg_index_plan) => {
-                    let refresh_agg_index_interpreter =
-                        RefreshIndexInterpreter::try_create(ctx_cloned.clone(), *agg_index_plan)?;
-                    let mut build_res = refresh_agg_index_interpreter.execute2().await?;
-                    if build_res.main_pipeline.is_empty() {
-                        return Ok(());
-                    }
+        match plan {
+            Plan::RefreshIndex(agg_index_plan) => {
+                license_manager
+                    .manager
+                    .check_enterprise_enabled(ctx.get_license_key(), Feature::AggregateIndex)?;
+                info!(
+                    "refresh hook dry run, aggregating index {} of {}.{}.{} on {} segments",
+                    agg_index_plan.index_name,
+                    desc.catalog,
+                    desc.database,
+                    desc.table,
+                    agg_index_plan.segment_locs.as_ref().map_or(0, |locs| locs.len()),
+                );
+            }
+            Plan::RefreshVirtualColumn(virtual_column_plan) => {
+                license_manager
+                    .manager
+                    .check_enterprise_enabled(ctx.get_license_key(), Feature::VirtualColumn)?;
+                info!(
+                    "refresh hook dry run, virtual columns of {}.{}.{} on {} segments",
+                    desc.catalog,
+                    desc.database,
+                    desc.table,
+                    virtual_column_plan.segment_locs.as_ref().map_or(0, |locs| locs.len()),
+                );
+            }
+            _ => unreachable!(),
+        }
+    }
+    Ok(vec![false; plans.len()])
+}
 
-                    let settings = ctx_cloned.get_settings();
-                    let query_id = ctx_cloned.get_id();
-                    build_res.set_max_threads(settings.get_max_threads()? as usize);
-                    let settings = ExecutorSettings::try_create(&settings, query_id)?;
-
-                    if build_res.main_pipeline.is_complete_pipeline()? {
-                        let mut pipelines = build_res.sources_pipelines;
-                        pipelines.push(build_res.main_pipeline);
-
-                        let complete_executor =
-                            PipelineCompleteExecutor::from_pipelines(pipelines, settings)?;
-                        ctx_cloned.set_executor(complete_executor.get_inner())?;
-                        complete_executor.execute()
-                    } else {
-                        Ok(())
-                    }
-                }
-                Plan::RefreshVirtualColumn(virtual_column_plan) => {
-                    let refresh_virtual_column_interpreter =
-                        RefreshVirtualColumnInterpreter::try_create(
-                            ctx_cloned.clone(),
-                            *virtual_column_plan,
-                        )?;
-                    let build_res = refresh_virtual_column_interpreter.execute2().await?;
-                    if !build_res.main_pipeline.is_empty() {
-                        return Err(ErrorCode::Internal(
-                            "Logical error, refresh virtual column is an empty pipeline.",
-                        ));
-                    }
-                    Ok(())
-                }
-                _ => unreachable!(),
+/// Execute a refresh plan, return whether it's executed or skipped for having nothing to do.
+async fn refresh_plan(ctx: Arc<QueryContext>, plan: Plan) -> Result<bool> {
+    // The limits are checked before any refresh, the virtual columns are refreshed in place
+    // without a pipeline, they are not limited while running.
+    let executor_settings = refresh_executor_settings(&ctx)?;
+    match plan {
+        Plan::RefreshIndex(agg_index_plan) => {
+            let refresh_agg_index_interpreter =
+                RefreshIndexInterpreter::try_create(ctx.clone(), *agg_index_plan)?;
+            let mut build_res = refresh_agg_index_interpreter.execute2().await?;
+            if build_res.main_pipeline.is_empty() {
+                return Ok(false);
             }
-        });
+
+            build_res.set_max_threads(ctx.get_settings().get_max_threads()? as usize);
+
+            if build_res.main_pipeline.is_complete_pipeline()? {
+                let mut pipelines = build_res.sources_pipelines;
+                pipelines.push(build_res.main_pipeline);
+
+                let complete_executor =
+                    PipelineCompleteExecutor::from_pipelines(pipelines, executor_settings)?;
+                ctx.set_executor(complete_executor.get_inner())?;
+                complete_executor.execute()?;
+                Ok(true)
+            } else {
+                Ok(false)
+            }
+        }
+        Plan::RefreshVirtualColumn(virtual_column_plan) => {
+            let refresh_virtual_column_interpreter =
+                RefreshVirtualColumnInterpreter::try_create(ctx.clone(), *virtual_column_plan)?;
+            let build_res = refresh_virtual_column_interpreter.execute2().await?;
+            if !build_res.main_pipeline.is_empty() {
+                return Err(ErrorCode::Internal(
+                    "Logical error, refresh virtual column is an empty pipeline.",
+                ));
+            }
+            Ok(true)
+        }
+        _ => unreachable!(),
     }
+}
 
-    let _ = futures::future::try_join_all(tasks).await?;
-    Ok(())
+/// The refresh runs within the limits of the query that triggered it, it's aborted if the memory
+/// usage of the query is already over `max_memory_usage` or the query has used up its execution
+/// time, otherwise the executor is given the execution time left to the query, and aborts the
+/// refresh once the memory usage of the query exceeds `max_memory_usage` while running.
+fn refresh_executor_settings(ctx: &QueryContext) -> Result<ExecutorSettings> {
+    let settings = ctx.get_settings();
+    let max_memory_usage = settings.get_max_memory_usage()?;
+    let mem_stat = ctx.get_query_mem_stat()?;
+    let used_memory = mem_stat.get_memory_usage();
+    if used_memory.max(0) as u64 >= max_memory_usage {
+        return Err(ErrorCode::AbortedQuery(format!(
+            "Aborted refresh after write, because the memory usage exceeds the maximum memory usage limit: max_memory_usage: {}, used_memory: {}",
+            max_memory_usage, used_memory
+        )));
+    }
+
+    let mut executor_settings = ExecutorSettings::try_create(&settings, ctx.get_id())?;
+    let max_execute_time = executor_settings.max_execute_time_in_seconds;
+    if !max_execute_time.is_zero() {
+        let elapsed = ctx.get_created_time().elapsed().unwrap_or_default();
+        if elapsed >= max_execute_time {
+            return Err(ErrorCode::AbortedQuery(
+                "Aborted refresh after write, because the execution time exceeds the maximum execution time limit",
+            ));
+        }
+        executor_settings.max_execute_time_in_seconds = max_execute_time - elapsed;
+    }
+    executor_settings.memory_limit = Some((mem_stat, max_memory_usage));
+    Ok(executor_settings)
+}
+
+/// Retry `f` with exponential backoff on the transient errors, up to `max_attempts` attempts.
+async fn retry_transient<F, Fut, T>(max_attempts: usize, mut f: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut backoff = set_backoff(
+        Some(Duration::from_millis(50)),
+        Some(Duration::from_secs(5)),
+        None,
+    );
+    let mut attempt = 1;
+    loop {
+        match f().await {
+            Err(e) if attempt < max_attempts && is_transient_error(&e) => {
+                let Some(duration) = backoff.next_backoff() else {
+                    return Err(e);
+                };
+                info!(
+                    "refresh after write failed at attempt {}, will be retried {} ms later: {}",
+                    attempt,
+                    duration.as_millis(),
+                    e
+                );
+                sleep(duration).await;
+                attempt += 1;
+            }
+            res => return res,
+        }
+    }
+}
+
+/// The errors that are likely to go away shortly, such as the hiccups of the meta service
+/// and the storage.
+fn is_transient_error(e: &ErrorCode) -> bool {
+    matches!(
+        e.code(),
+        ErrorCode::META_SERVICE_ERROR
+            | ErrorCode::META_STORAGE_ERROR
+            | ErrorCode::STORAGE_UNAVAILABLE
+    )
+}
+
+/// Run the tasks with at most `max_concurrency` of them in flight,
+/// the first error aborts the rest like `try_join_all`.
+async fn try_join_all_bounded<Fut, T>(
+    tasks: impl IntoIterator<Item = Fut>,
+    max_concurrency: usize,
+) -> Result<Vec<T>>
+where Fut: Future<Output = Result<T>> {
+    let semaphore = Semaphore::new(max_concurrency.max(1));
+    let tasks = tasks.into_iter().map(|task| {
+        let semaphore = &semaphore;
+        async move {
+            let _permit = semaphore.acquire().await.map_err(|e| {
+                ErrorCode::Internal(format!("acquire refresh task permit failure, {}", e))
+            })?;
+            task.await
+        }
+    });
+    futures::future::try_join_all(tasks).await
 }
 
 async fn generate_refresh_index_plan(
@@ -233,13 +413,146 @@ async fn generate_refresh_virtual_column_plan(
     desc: &RefreshDesc,
 ) -> Result<Plan> {
     let segment_locs = ctx.get_segment_locations()?;
+    // Only the newly written blocks need to be refreshed, fall back to the whole
+    // segments if the blocks are unknown.
+    let block_locs = ctx.get_block_locations()?;
+    let block_locs = if block_locs.is_empty() {
+        None
+    } else {
+        Some(block_locs)
+    };
 
     let plan = RefreshVirtualColumnPlan {
         catalog: desc.catalog.clone(),
         database: desc.database.clone(),
         table: desc.table.clone(),
         segment_locs: Some(segment_locs),
+        block_locs,
     };
 
     Ok(Plan::RefreshVirtualColumn(Box::new(plan)))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::time::Duration;
+
+    use databend_common_base::base::tokio;
+    use databend_common_exception::ErrorCode;
+    use databend_common_exception::Result;
+
+    use super::generate_refresh_plans;
+    use super::retry_transient;
+    use super::try_join_all_bounded;
+    use super::RefreshDesc;
+    use crate::sessions::TableContext;
+    use crate::test_kits::TestFixture;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_try_join_all_bounded() -> Result<()> {
+        let running = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        let tasks = (0..16).map(|i| {
+            let (running, peak) = (&running, &peak);
+            async move {
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                running.fetch_sub(1, Ordering::SeqCst);
+                Ok(i)
+            }
+        });
+
+        let results = try_join_all_bounded(tasks, 3).await?;
+        assert_eq!(results, (0..16).collect::<Vec<_>>());
+        assert_eq!(peak.load(Ordering::SeqCst), 3);
+
+        // The first error aborts the tasks that are not started yet.
+        let started = AtomicUsize::new(0);
+        let tasks = (0..16).map(|i| {
+            let started = &started;
+            async move {
+                started.fetch_add(1, Ordering::SeqCst);
+                if i == 0 {
+                    return Err(ErrorCode::Internal("refresh failed"));
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                Ok(i)
+            }
+        });
+
+        let result = try_join_all_bounded(tasks, 2).await;
+        assert!(result.is_err());
+        assert!(started.load(Ordering::SeqCst) < 16);
+
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_retry_transient() -> Result<()> {
+        // The transient error is retried and succeeds on the second attempt.
+        let attempts = AtomicUsize::new(0);
+        let result = retry_transient(3, || async {
+            match attempts.fetch_add(1, Ordering::SeqCst) {
+                0 => Err(ErrorCode::MetaServiceError("meta service hiccup")),
+                n => Ok(n),
+            }
+        })
+        .await?;
+        assert_eq!(result, 1);
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+
+        // The attempts are bounded.
+        let attempts = AtomicUsize::new(0);
+        let result: Result<()> = retry_transient(3, || async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err(ErrorCode::StorageUnavailable("storage is throttled"))
+        })
+        .await;
+        assert_eq!(result.unwrap_err().code(), ErrorCode::STORAGE_UNAVAILABLE);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+        // The errors other than the transient ones are not retried.
+        let attempts = AtomicUsize::new(0);
+        let result: Result<()> = retry_transient(3, || async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err(ErrorCode::LicenseKeyInvalid("Need Commercial License"))
+        })
+        .await;
+        assert_eq!(result.unwrap_err().code(), ErrorCode::LICENSE_KEY_INVALID);
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_generate_refresh_plans_with_table_id() -> Result<()> {
+        let fixture = TestFixture::setup().await?;
+        fixture.create_default_database().await?;
+        fixture.create_default_table().await?;
+
+        let ctx = fixture.new_query_ctx().await?;
+        let table = ctx
+            .get_table(
+                &fixture.default_catalog_name(),
+                &fixture.default_db_name(),
+                &fixture.default_table_name(),
+            )
+            .await?;
+        let desc = |table_id| RefreshDesc {
+            catalog: fixture.default_catalog_name(),
+            database: fixture.default_db_name(),
+            table: fixture.default_table_name(),
+            table_id,
+        };
+
+        let looked_up = generate_refresh_plans(ctx.clone(), &desc(None), true).await?;
+        let resolved = generate_refresh_plans(ctx, &desc(Some(table.get_id())), true).await?;
+        assert_eq!(looked_up.len(), 1);
+        assert_eq!(format!("{:?}", looked_up), format!("{:?}", resolved));
+
+        Ok(())
+    }
+}
@@ -353,6 +353,7 @@ impl Interpreter for CopyIntoTableInterpreter {
                 catalog: self.plan.catalog_info.name_ident.catalog_name.clone(),
                 database: self.plan.database_name.clone(),
                 table: self.plan.table_name.clone(),
+                table_id: None,
             };
 
             hook_refresh(self.ctx.clone(), &mut build_res.main_pipeline, refresh_desc).await?;
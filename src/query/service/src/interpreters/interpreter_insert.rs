@@ -279,6 +279,7 @@ impl Interpreter for InsertInterpreter {
                     catalog: self.plan.catalog.clone(),
                     database: self.plan.database.clone(),
                     table: self.plan.table.clone(),
+                    table_id: Some(table.get_id()),
                 };
 
                 hook_refresh(self.ctx.clone(), &mut build_res.main_pipeline, refresh_desc).await?;
@@ -331,6 +332,7 @@ impl Interpreter for InsertInterpreter {
             catalog: self.plan.catalog.clone(),
             database: self.plan.database.clone(),
             table: self.plan.table.clone(),
+            table_id: Some(table.get_id()),
         };
 
         hook_refresh(self.ctx.clone(), &mut build_res.main_pipeline, refresh_desc).await?;
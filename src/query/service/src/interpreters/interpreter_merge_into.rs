@@ -91,7 +91,7 @@ impl Interpreter for MergeIntoInterpreter {
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let start = Instant::now();
-        let (physical_plan, _) = self.build_physical_plan().await?;
+        let (physical_plan, table_info) = self.build_physical_plan().await?;
         let mut build_res =
             build_query_pipeline_without_render_result_set(&self.ctx, &physical_plan, false)
                 .await?;
@@ -131,6 +131,7 @@ impl Interpreter for MergeIntoInterpreter {
                 catalog: self.plan.catalog.clone(),
                 database: self.plan.database.clone(),
                 table: self.plan.table.clone(),
+                table_id: Some(table_info.ident.table_id),
             };
 
             hook_refresh(self.ctx.clone(), &mut build_res.main_pipeline, refresh_desc).await?;
@@ -135,6 +135,7 @@ impl Interpreter for ReplaceInterpreter {
                 catalog: self.plan.catalog.clone(),
                 database: self.plan.database.clone(),
                 table: self.plan.table.clone(),
+                table_id: None,
             };
 
             hook_refresh(self.ctx.clone(), &mut pipeline.main_pipeline, refresh_desc).await?;
@@ -245,6 +245,7 @@ impl Interpreter for UpdateInterpreter {
                     catalog: catalog_name.to_string(),
                     database: db_name.to_string(),
                     table: tbl_name.to_string(),
+                    table_id: Some(tbl.get_id()),
                 };
 
                 hook_refresh(self.ctx.clone(), &mut build_res.main_pipeline, refresh_desc).await?;
@@ -82,9 +82,16 @@ impl Interpreter for RefreshVirtualColumnInterpreter {
         let virtual_columns = res[0].virtual_columns.clone();
         let fuse_table = FuseTable::try_from_table(table.as_ref())?;
         let segment_locs = self.plan.segment_locs.clone();
+        let block_locs = self.plan.block_locs.clone();
 
         let _ = handler
-            .do_refresh_virtual_column(fuse_table, self.ctx.clone(), virtual_columns, segment_locs)
+            .do_refresh_virtual_column(
+                fuse_table,
+                self.ctx.clone(),
+                virtual_columns,
+                segment_locs,
+                block_locs,
+            )
             .await?;
 
         Ok(PipelineBuildResult::create())
@@ -106,6 +106,10 @@ impl PipelineBuilder {
             &aggregate.group_by,
             &aggregate.agg_funcs,
             None,
+            aggregate
+                .stat_info
+                .as_ref()
+                .map(|stat_info| stat_info.estimated_rows as usize),
         )?;
 
         if params.group_columns.is_empty() {
@@ -131,6 +135,15 @@ impl PipelineBuilder {
         let sample_block = DataBlock::empty_with_schema(schema_before_group_by);
         let method = DataBlock::choose_hash_method(&sample_block, group_cols, efficiently_memory)?;
 
+        // Start with the partitioned hash table if the groups are estimated to be more than
+        // the two level threshold, to avoid converting the hash table in the middle.
+        let convert_threshold = self.settings.get_group_by_two_level_threshold()? as usize;
+        let start_partitioned = params
+            .cardinality
+            .is_some_and(|cardinality| cardinality >= convert_threshold);
+        // The partial group by spills early once the memory usage of the query reaches the limit.
+        let mem_stat = self.ctx.get_query_mem_stat()?;
+
         self.main_pipeline.add_transform(|input, output| {
             let transform = match params.aggregate_functions.is_empty() {
                 true => with_mappedhash_method!(|T| match method.clone() {
@@ -139,7 +152,9 @@ impl PipelineBuilder {
                         method,
                         input,
                         output,
-                        params.clone()
+                        params.clone(),
+                        start_partitioned,
+                        mem_stat.clone()
                     ),
                 }),
                 false => with_mappedhash_method!(|T| match method.clone() {
@@ -225,6 +240,7 @@ impl PipelineBuilder {
             &aggregate.group_by,
             &aggregate.agg_funcs,
             aggregate.limit,
+            None,
         )?;
 
         if params.group_columns.is_empty() {
@@ -323,6 +339,7 @@ impl PipelineBuilder {
         group_by: &[IndexType],
         agg_funcs: &[AggregateFunctionDesc],
         limit: Option<usize>,
+        cardinality: Option<usize>,
     ) -> Result<Arc<AggregatorParams>> {
         let mut agg_args = Vec::with_capacity(agg_funcs.len());
         let (group_by, group_data_types) = group_by
@@ -362,6 +379,7 @@ impl PipelineBuilder {
             &aggs,
             &agg_args,
             limit,
+            cardinality,
         )?;
 
         Ok(params)
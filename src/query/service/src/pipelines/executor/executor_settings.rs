@@ -15,6 +15,7 @@
 use std::sync::Arc;
 use std::time::Duration;
 
+use databend_common_base::runtime::MemStat;
 use databend_common_exception::Result;
 use databend_common_settings::Settings;
 
@@ -23,6 +24,10 @@ pub struct ExecutorSettings {
     pub query_id: Arc<String>,
     pub enable_profiling: bool,
     pub max_execute_time_in_seconds: Duration,
+    /// The executor is aborted once the memory usage of the tracker exceeds the limit, None means
+    /// no limit. It's left unlimited for the queries, only set for the jobs running after the
+    /// query, such as the refreshes after write, with the tracker of the query triggering them.
+    pub memory_limit: Option<(Arc<MemStat>, u64)>,
 }
 
 impl ExecutorSettings {
@@ -33,6 +38,7 @@ impl ExecutorSettings {
             enable_profiling,
             query_id: Arc::new(query_id),
             max_execute_time_in_seconds: Duration::from_secs(max_execute_time_in_seconds),
+            memory_limit: None,
         })
     }
 }
@@ -15,6 +15,7 @@
 use std::intrinsics::assume;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::Duration;
 use std::time::Instant;
 
 use databend_common_base::base::tokio;
@@ -48,6 +49,9 @@ use crate::pipelines::executor::RunningGraph;
 use crate::pipelines::executor::WatchNotify;
 use crate::pipelines::executor::WorkersCondvar;
 
+// The interval to check the memory usage against `max_memory_usage` of the settings.
+const MEMORY_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
 pub type InitCallback = Box<dyn FnOnce() -> Result<()> + Send + Sync + 'static>;
 
 pub type FinishedCallback =
@@ -331,6 +335,34 @@ impl PipelineExecutor {
             });
         }
 
+        if let Some((mem_stat, max_memory_usage)) = self.settings.memory_limit.clone() {
+            let this = Arc::downgrade(self);
+            let finished_notify = self.finished_notify.clone();
+            self.async_runtime.spawn(GLOBAL_TASK, async move {
+                let mut finished_future = Box::pin(finished_notify.notified());
+                loop {
+                    let check_future = Box::pin(tokio::time::sleep(MEMORY_CHECK_INTERVAL));
+                    match select(check_future, finished_future).await {
+                        Either::Left((_, finished)) => {
+                            let Some(executor) = this.upgrade() else {
+                                break;
+                            };
+                            let used_memory = mem_stat.get_memory_usage();
+                            if used_memory.max(0) as u64 >= max_memory_usage {
+                                executor.finish(Some(ErrorCode::AbortedQuery(format!(
+                                    "Aborted query, because the memory usage exceeds the maximum memory usage limit: max_memory_usage: {}, used_memory: {}",
+                                    max_memory_usage, used_memory
+                                ))));
+                                break;
+                            }
+                            finished_future = finished;
+                        }
+                        Either::Right(_) => break,
+                    }
+                }
+            });
+        }
+
         Ok(())
     }
 
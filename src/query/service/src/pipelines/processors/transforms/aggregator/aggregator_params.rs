@@ -40,6 +40,9 @@ pub struct AggregatorParams {
 
     // Limit is push down to AggregatorTransform
     pub limit: Option<usize>,
+
+    // The number of groups estimated by the optimizer, if any
+    pub cardinality: Option<usize>,
 }
 
 impl AggregatorParams {
@@ -50,6 +53,7 @@ impl AggregatorParams {
         agg_funcs: &[AggregateFunctionRef],
         agg_args: &[Vec<usize>],
         limit: Option<usize>,
+        cardinality: Option<usize>,
     ) -> Result<Arc<AggregatorParams>> {
         let mut states_offsets: Vec<usize> = Vec::with_capacity(agg_funcs.len());
         let mut states_layout = None;
@@ -67,6 +71,7 @@ impl AggregatorParams {
             layout: states_layout,
             offsets_aggregate_states: states_offsets,
             limit,
+            cardinality,
         }))
     }
 
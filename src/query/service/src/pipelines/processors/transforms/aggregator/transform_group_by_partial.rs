@@ -12,18 +12,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::vec;
 
 use bumpalo::Bump;
 use databend_common_base::base::convert_byte_size;
 use databend_common_base::base::convert_number_size;
-use databend_common_base::runtime::GLOBAL_MEM_STAT;
+use databend_common_base::runtime::MemStat;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
 use databend_common_hashtable::HashtableLike;
+use databend_common_metrics::transform::*;
 use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
@@ -55,6 +58,12 @@ impl<Method: HashMethodBounds> Default for HashTable<Method> {
     }
 }
 
+// Report the size of the hash table into the metrics once every so many blocks.
+const REPORT_HASHTABLE_SIZE_INTERVAL: usize = 32;
+
+// Identifies the partial group by processors in the metrics.
+static NEXT_PROCESSOR_ID: AtomicUsize = AtomicUsize::new(0);
+
 struct GroupBySettings {
     convert_threshold: usize,
     max_memory_usage: usize,
@@ -101,6 +110,13 @@ pub struct TransformPartialGroupBy<Method: HashMethodBounds> {
     hash_table: HashTable<Method>,
     group_columns: Vec<IndexType>,
     settings: GroupBySettings,
+
+    // The memory tracker of the query to check the memory pressure.
+    mem_stat: Arc<MemStat>,
+
+    query_id: String,
+    processor_id: usize,
+    processed_blocks: usize,
 }
 
 impl<Method: HashMethodBounds> TransformPartialGroupBy<Method> {
@@ -110,23 +126,65 @@ impl<Method: HashMethodBounds> TransformPartialGroupBy<Method> {
         input: Arc<InputPort>,
         output: Arc<OutputPort>,
         params: Arc<AggregatorParams>,
+        start_partitioned: bool,
+        mem_stat: Arc<MemStat>,
     ) -> Result<Box<dyn Processor>> {
-        let arena = Arc::new(Bump::new());
-        let hashtable = method.create_hash_table(arena)?;
-        let _dropper = GroupByHashTableDropper::<Method>::create();
-        let hash_table = HashTable::HashTable(HashTableCell::create(hashtable, _dropper));
-
         Ok(AccumulatingTransformer::create(
             input,
             output,
-            TransformPartialGroupBy::<Method> {
-                method,
-                hash_table,
-                group_columns: params.group_columns.clone(),
-                settings: GroupBySettings::try_from(ctx)?,
-            },
+            Self::create(ctx, method, &params, start_partitioned, mem_stat)?,
         ))
     }
+
+    fn create(
+        ctx: Arc<QueryContext>,
+        method: Method,
+        params: &AggregatorParams,
+        start_partitioned: bool,
+        mem_stat: Arc<MemStat>,
+    ) -> Result<Self> {
+        let arena = Arc::new(Bump::new());
+        let hash_table = if start_partitioned && Method::SUPPORT_PARTITIONED {
+            let method = PartitionedHashMethod::<Method>::create(method.clone());
+            let hashtable = method.create_hash_table(arena)?;
+            let _dropper = PartitionedHashTableDropper::<Method, ()>::create(
+                GroupByHashTableDropper::<Method>::create(),
+            );
+            HashTable::PartitionedHashTable(HashTableCell::create(hashtable, _dropper))
+        } else {
+            let hashtable = method.create_hash_table(arena)?;
+            let _dropper = GroupByHashTableDropper::<Method>::create();
+            HashTable::HashTable(HashTableCell::create(hashtable, _dropper))
+        };
+
+        Ok(TransformPartialGroupBy::<Method> {
+            method,
+            hash_table,
+            group_columns: params.group_columns.clone(),
+            query_id: ctx.get_id(),
+            settings: GroupBySettings::try_from(ctx)?,
+            mem_stat,
+            processor_id: NEXT_PROCESSOR_ID.fetch_add(1, Ordering::Relaxed),
+            processed_blocks: 0,
+        })
+    }
+
+    fn report_hashtable_size(&self) {
+        let (keys, bytes) = match &self.hash_table {
+            HashTable::MovedOut => return,
+            HashTable::HashTable(cell) => (cell.hashtable.len(), cell.hashtable.bytes_len(false)),
+            HashTable::PartitionedHashTable(cell) => {
+                (cell.hashtable.len(), cell.hashtable.bytes_len(false))
+            }
+        };
+        metrics_set_group_by_partial_hashtable_size(&self.query_id, self.processor_id, keys, bytes);
+    }
+}
+
+impl<Method: HashMethodBounds> Drop for TransformPartialGroupBy<Method> {
+    fn drop(&mut self) {
+        metrics_remove_group_by_partial_hashtable_size(&self.query_id, self.processor_id);
+    }
 }
 
 impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialGroupBy<Method> {
@@ -163,12 +221,21 @@ impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialGroupBy
                 }
             };
 
+            self.processed_blocks += 1;
+            if self.processed_blocks % REPORT_HASHTABLE_SIZE_INTERVAL == 0 {
+                self.report_hashtable_size();
+            }
+
             #[allow(clippy::collapsible_if)]
             if Method::SUPPORT_PARTITIONED {
+                // Under memory pressure, convert and spill the hash table regardless of its size.
+                let memory_pressure =
+                    self.mem_stat.get_memory_usage() as usize >= self.settings.max_memory_usage;
+
                 if matches!(&self.hash_table, HashTable::HashTable(cell)
                     if cell.len() >= self.settings.convert_threshold ||
                         cell.allocated_bytes() >= self.settings.spilling_bytes_threshold_per_proc ||
-                        GLOBAL_MEM_STAT.get_memory_usage() as usize >= self.settings.max_memory_usage
+                        memory_pressure
                 ) {
                     if let HashTable::HashTable(cell) = std::mem::take(&mut self.hash_table) {
                         self.hash_table = HashTable::PartitionedHashTable(
@@ -178,7 +245,7 @@ impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialGroupBy
                 }
 
                 if matches!(&self.hash_table, HashTable::PartitionedHashTable(cell) if cell.allocated_bytes() > self.settings.spilling_bytes_threshold_per_proc)
-                    || GLOBAL_MEM_STAT.get_memory_usage() as usize >= self.settings.max_memory_usage
+                    || memory_pressure
                 {
                     if let HashTable::PartitionedHashTable(v) = std::mem::take(&mut self.hash_table)
                     {
@@ -236,3 +303,99 @@ impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialGroupBy
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use databend_common_base::base::tokio;
+    use databend_common_base::runtime::MemStat;
+    use databend_common_catalog::table_context::TableContext;
+    use databend_common_exception::Result;
+    use databend_common_expression::types::DataType;
+    use databend_common_expression::types::NumberDataType;
+    use databend_common_expression::types::UInt64Type;
+    use databend_common_expression::BlockMetaInfoDowncast;
+    use databend_common_expression::DataBlock;
+    use databend_common_expression::DataField;
+    use databend_common_expression::DataSchemaRefExt;
+    use databend_common_expression::FromData;
+    use databend_common_expression::HashMethodKeysU64;
+    use databend_common_pipeline_transforms::processors::AccumulatingTransform;
+    use databend_common_sql::Planner;
+
+    use super::GroupBySettings;
+    use super::HashTable;
+    use super::TransformPartialGroupBy;
+    use crate::pipelines::processors::transforms::aggregator::AggregateMeta;
+    use crate::pipelines::processors::transforms::aggregator::AggregatorParams;
+    use crate::test_kits::TestFixture;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_convert_under_memory_pressure() -> Result<()> {
+        let fixture = TestFixture::setup().await?;
+        let ctx = fixture.new_query_ctx().await?;
+        let max_memory_usage: i64 = 64 * 1024 * 1024;
+        let settings = ctx.get_settings();
+        settings.set_setting("max_memory_usage".to_string(), max_memory_usage.to_string())?;
+        settings.set_setting("aggregate_spilling_memory_ratio".to_string(), "100".to_string())?;
+        settings.set_setting(
+            "aggregate_spilling_bytes_threshold_per_proc".to_string(),
+            (1024 * 1024 * 1024).to_string(),
+        )?;
+
+        let data_type = DataType::Number(NumberDataType::UInt64);
+        let schema = DataSchemaRefExt::create(vec![DataField::new("a", data_type.clone())]);
+        let params =
+            AggregatorParams::try_create(schema, vec![data_type], &[0], &[], &[], None, None)?;
+        let mem_stat =
+            MemStat::create_child("test_convert_under_memory_pressure".to_string(), None);
+        let mut transform = TransformPartialGroupBy::create(
+            ctx,
+            HashMethodKeysU64::default(),
+            &params,
+            false,
+            mem_stat.clone(),
+        )?;
+        let block = DataBlock::new_from_columns(vec![UInt64Type::from_data(vec![1, 2, 3])]);
+
+        // The hash table is kept as it is below the convert threshold.
+        assert!(transform.transform(block.clone())?.is_empty());
+        assert!(matches!(transform.hash_table, HashTable::HashTable(_)));
+
+        // Once the memory usage of the query reaches the limit, the hash table is converted
+        // and spilled on the next block.
+        assert!(MemStat::record_memory::<false>(&Some(mem_stat.clone()), max_memory_usage).is_ok());
+        let blocks = transform.transform(block.clone())?;
+        assert!(MemStat::record_memory::<false>(&Some(mem_stat), -max_memory_usage).is_ok());
+
+        assert_eq!(blocks.len(), 1);
+        let meta = blocks[0]
+            .get_meta()
+            .and_then(AggregateMeta::<HashMethodKeysU64, ()>::downcast_ref_from);
+        assert!(matches!(meta, Some(AggregateMeta::Spilling(_))));
+        assert!(matches!(transform.hash_table, HashTable::PartitionedHashTable(_)));
+
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_group_by_settings_from_hints() -> Result<()> {
+        let fixture = TestFixture::setup().await?;
+        let ctx = fixture.new_query_ctx().await?;
+        ctx.get_shared_settings().set_setting(
+            "group_by_two_level_threshold".to_string(),
+            "1000".to_string(),
+        )?;
+
+        // The hints of the statement are applied to the settings of the query only.
+        let sql = "select /*+ SET_VAR(group_by_two_level_threshold=10) */ number \
+            from numbers(100) group by number";
+        let mut planner = Planner::new(ctx.clone());
+        planner.plan_sql(sql).await?;
+
+        let settings = GroupBySettings::try_from(ctx.clone())?;
+        assert_eq!(settings.convert_threshold, 10);
+        assert_eq!(ctx.get_shared_settings().get_group_by_two_level_threshold()?, 1000);
+
+        Ok(())
+    }
+}
@@ -40,6 +40,8 @@ pub struct HashJoinDesc {
     /// Whether the Join are derived from correlated subquery.
     pub(crate) from_correlated_subquery: bool,
     pub(crate) probe_keys_rt: Vec<Expr<String>>,
+    /// The (probe key, build key) pairs of each disjunctive non-equi condition.
+    pub(crate) disjunctive_keys_rt: Vec<Vec<(Expr<String>, Expr)>>,
     // Under cluster, mark if the join is broadcast join.
     pub broadcast: bool,
 }
@@ -65,6 +67,21 @@ impl HashJoinDesc {
             .map(|k| k.as_expr(&BUILTIN_FUNCTIONS))
             .collect();
 
+        let disjunctive_keys_rt = join
+            .disjunctive_keys_rt
+            .iter()
+            .map(|keys| {
+                keys.iter()
+                    .map(|(probe_key, build_key)| {
+                        (
+                            probe_key.as_expr(&BUILTIN_FUNCTIONS),
+                            build_key.as_expr(&BUILTIN_FUNCTIONS),
+                        )
+                    })
+                    .collect()
+            })
+            .collect();
+
         Ok(HashJoinDesc {
             join_type: join.join_type.clone(),
             build_keys,
@@ -76,6 +93,7 @@ impl HashJoinDesc {
             },
             from_correlated_subquery: join.from_correlated_subquery,
             probe_keys_rt,
+            disjunctive_keys_rt,
             broadcast: join.broadcast,
         })
     }
@@ -272,6 +272,11 @@ impl HashJoinBuildState {
             };
 
             let mut runtime_filter = RuntimeFilterInfo::default();
+            if build_num_rows == 0
+                && (self.enable_inlist_runtime_filter || self.enable_bloom_runtime_filter)
+            {
+                runtime_filter.set_build_empty();
+            }
             if self.enable_inlist_runtime_filter && build_num_rows < INLIST_RUNTIME_FILTER_THRESHOLD
             {
                 self.inlist_runtime_filter(&mut runtime_filter, &build_chunks)?;
@@ -279,6 +284,11 @@ impl HashJoinBuildState {
             // If enable bloom runtime filter, collect hashes for build keys
             if self.enable_bloom_runtime_filter {
                 self.bloom_runtime_filter(&self.func_ctx, &build_chunks, &mut runtime_filter)?;
+                self.disjunctive_bloom_runtime_filter(
+                    &self.func_ctx,
+                    &build_chunks,
+                    &mut runtime_filter,
+                )?;
             }
 
             if self.enable_min_max_runtime_filter {
@@ -803,43 +813,82 @@ impl HashJoinBuildState {
                 return Ok(());
             }
             if let Expr::ColumnRef { id, .. } = probe_key {
-                let mut columns = Vec::with_capacity(data_blocks.len());
-                for block in data_blocks.iter() {
-                    if block.num_columns() == 0 {
-                        continue;
-                    }
-                    let evaluator = Evaluator::new(block, func_ctx, &BUILTIN_FUNCTIONS);
-                    let column = evaluator
-                        .run(build_key)?
-                        .convert_to_full_column(build_key.data_type(), block.num_rows());
-                    columns.push(column);
-                }
-                if columns.is_empty() {
+                let Some(filter) = self.build_bloom_filter(func_ctx, data_blocks, build_key)? else {
                     return Ok(());
-                }
-                let build_key_column = Column::concat_columns(columns.into_iter())?;
-                // Generate bloom filter using build column
-                let data_type = build_key.data_type().clone();
-                let num_rows = build_key_column.len();
-                let method = DataBlock::choose_hash_method_with_types(&[data_type.clone()], false)?;
-                let mut hashes = HashSet::with_capacity(num_rows);
-                hash_by_method(
-                    &method,
-                    &[(build_key_column, data_type)],
-                    num_rows,
-                    &mut hashes,
-                )?;
-                let mut hashes_vec = Vec::with_capacity(num_rows);
-                hashes.into_iter().for_each(|hash| {
-                    hashes_vec.push(hash);
-                });
-                let filter = BinaryFuse8::try_from(&hashes_vec)?;
+                };
                 runtime_filter.add_bloom((id.to_string(), filter));
             }
         }
         Ok(())
     }
 
+    fn disjunctive_bloom_runtime_filter(
+        &self,
+        func_ctx: &FunctionContext,
+        data_blocks: &[DataBlock],
+        runtime_filter: &mut RuntimeFilterInfo,
+    ) -> Result<()> {
+        'conditions: for keys in self.hash_join_state.hash_join_desc.disjunctive_keys_rt.iter() {
+            // The rows only passing a missing filter would be rejected by the others of the
+            // group, so the group is built only if all its filters are.
+            let mut filters = Vec::with_capacity(keys.len());
+            for (probe_key, build_key) in keys.iter() {
+                let Expr::ColumnRef { id, .. } = probe_key else {
+                    continue 'conditions;
+                };
+                if !build_key.data_type().remove_nullable().is_numeric() {
+                    continue 'conditions;
+                }
+                let Some(filter) = self.build_bloom_filter(func_ctx, data_blocks, build_key)? else {
+                    continue 'conditions;
+                };
+                filters.push((id.to_string(), filter));
+            }
+            runtime_filter.add_disjunctive_bloom(filters);
+        }
+        Ok(())
+    }
+
+    /// Build the bloom filter of the build key, return None if the build side has no columns.
+    fn build_bloom_filter(
+        &self,
+        func_ctx: &FunctionContext,
+        data_blocks: &[DataBlock],
+        build_key: &Expr,
+    ) -> Result<Option<BinaryFuse8>> {
+        let mut columns = Vec::with_capacity(data_blocks.len());
+        for block in data_blocks.iter() {
+            if block.num_columns() == 0 {
+                continue;
+            }
+            let evaluator = Evaluator::new(block, func_ctx, &BUILTIN_FUNCTIONS);
+            let column = evaluator
+                .run(build_key)?
+                .convert_to_full_column(build_key.data_type(), block.num_rows());
+            columns.push(column);
+        }
+        if columns.is_empty() {
+            return Ok(None);
+        }
+        let build_key_column = Column::concat_columns(columns.into_iter())?;
+        // Generate bloom filter using build column
+        let data_type = build_key.data_type().clone();
+        let num_rows = build_key_column.len();
+        let method = DataBlock::choose_hash_method_with_types(&[data_type.clone()], false)?;
+        let mut hashes = HashSet::with_capacity(num_rows);
+        hash_by_method(
+            &method,
+            &[(build_key_column, data_type)],
+            num_rows,
+            &mut hashes,
+        )?;
+        let mut hashes_vec = Vec::with_capacity(num_rows);
+        hashes.into_iter().for_each(|hash| {
+            hashes_vec.push(hash);
+        });
+        Ok(Some(BinaryFuse8::try_from(&hashes_vec)?))
+    }
+
     fn inlist_runtime_filter(
         &self,
         runtime_filter: &mut RuntimeFilterInfo,
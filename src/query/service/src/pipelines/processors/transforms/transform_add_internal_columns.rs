@@ -17,6 +17,7 @@ use std::sync::Arc;
 
 use databend_common_catalog::plan::InternalColumn;
 use databend_common_catalog::plan::InternalColumnMeta;
+use databend_common_catalog::plan::LineageMeta;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::BlockMetaInfoDowncast;
@@ -54,8 +55,11 @@ impl Transform for TransformAddInternalColumns {
 
     fn transform(&mut self, mut block: DataBlock) -> Result<DataBlock> {
         if let Some(meta) = block.take_meta() {
-            let internal_column_meta =
-                InternalColumnMeta::downcast_from(meta).ok_or(ErrorCode::Internal("It's a bug"))?;
+            // The lineage of the block is kept, only its inner meta is consumed.
+            let (lineage, meta) = LineageMeta::split(meta);
+            let internal_column_meta = meta
+                .and_then(InternalColumnMeta::downcast_from)
+                .ok_or(ErrorCode::Internal("It's a bug"))?;
             let num_rows = block.num_rows();
             for internal_column in self.internal_columns.values() {
                 if internal_column.column_id() == CHANGE_ROW_ID_COLUMN_ID {
@@ -65,6 +69,7 @@ impl Transform for TransformAddInternalColumns {
                     internal_column.generate_column_values(&internal_column_meta, num_rows);
                 block.add_column(column);
             }
+            block = block.add_meta(LineageMeta::wrap(lineage, None))?;
         }
         Ok(block)
     }
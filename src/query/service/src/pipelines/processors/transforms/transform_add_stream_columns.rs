@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use databend_common_catalog::plan::LineageMeta;
 use databend_common_catalog::plan::StreamColumn;
 use databend_common_catalog::plan::StreamColumnMeta;
 use databend_common_exception::ErrorCode;
@@ -59,7 +60,10 @@ impl Transform for TransformAddStreamColumns {
         let num_rows = block.num_rows();
         if num_rows != 0 {
             if let Some(meta) = block.take_meta() {
-                let meta = StreamColumnMeta::downcast_from(meta)
+                // The lineage of the block is kept, only its inner meta is consumed.
+                let (lineage, meta) = LineageMeta::split(meta);
+                let meta = meta
+                    .and_then(StreamColumnMeta::downcast_from)
                     .ok_or_else(|| ErrorCode::Internal("It's a bug"))?;
 
                 for stream_column in self.stream_columns.iter() {
@@ -70,7 +74,7 @@ impl Transform for TransformAddStreamColumns {
                 block = self
                     .expression_transform
                     .transform(block)?
-                    .add_meta(meta.inner)?;
+                    .add_meta(LineageMeta::wrap(lineage, meta.inner))?;
             }
         }
 
@@ -35,6 +35,7 @@ use dashmap::DashMap;
 use databend_common_base::base::tokio::task::JoinHandle;
 use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
+use databend_common_base::runtime::MemStat;
 use databend_common_base::runtime::TrySpawn;
 use databend_common_catalog::plan::DataSourceInfo;
 use databend_common_catalog::plan::DataSourcePlan;
@@ -44,7 +45,10 @@ use databend_common_catalog::plan::StageTableInfo;
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use databend_common_catalog::table_args::TableArgs;
+use databend_common_catalog::table_context::ColumnMask;
 use databend_common_catalog::table_context::MaterializedCtesBlocks;
+use databend_common_catalog::table_context::ScanDecodeError;
+use databend_common_catalog::table_context::ScanPartProfile;
 use databend_common_catalog::table_context::StageAttachment;
 use databend_common_config::GlobalConfig;
 use databend_common_config::DATABEND_COMMIT_VERSION;
@@ -100,6 +104,7 @@ use crate::pipelines::executor::PipelineExecutor;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::ProcessInfo;
 use crate::sessions::QueryContextShared;
+use crate::sessions::RefreshHookStats;
 use crate::sessions::Session;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
@@ -121,6 +126,7 @@ pub struct QueryContext {
     fragment_id: Arc<AtomicUsize>,
     // Used by synchronized generate aggregating indexes when new data written.
     inserted_segment_locs: Arc<RwLock<HashSet<Location>>>,
+    inserted_block_locs: Arc<RwLock<HashSet<String>>>,
 }
 
 impl QueryContext {
@@ -142,6 +148,7 @@ impl QueryContext {
             query_settings,
             fragment_id: Arc::new(AtomicUsize::new(0)),
             inserted_segment_locs: Arc::new(RwLock::new(HashSet::new())),
+            inserted_block_locs: Arc::new(RwLock::new(HashSet::new())),
         })
     }
 
@@ -290,17 +297,27 @@ impl QueryContext {
         (finish_time - query_start_time) / 1_000
     }
 
-    pub fn get_created_time(&self) -> SystemTime {
-        self.shared.created_time
-    }
-
     pub fn set_finish_time(&self, time: SystemTime) {
         *self.shared.finish_time.write() = Some(time)
     }
 
+    /// The memory tracker of the query runtime, the threads spawned from it record
+    /// their memory usage to it as well.
+    pub fn get_query_mem_stat(&self) -> Result<Arc<MemStat>> {
+        Ok(self.shared.try_get_runtime()?.get_tracker())
+    }
+
     pub fn evict_table_from_cache(&self, catalog: &str, database: &str, table: &str) -> Result<()> {
         self.shared.evict_table_from_cache(catalog, database, table)
     }
+
+    pub fn add_refresh_hook_stats(&self, stats: RefreshHookStats) {
+        self.shared.refresh_hook_stats.write().merge(stats)
+    }
+
+    pub fn get_refresh_hook_stats(&self) -> RefreshHookStats {
+        self.shared.refresh_hook_stats.read().clone()
+    }
 }
 
 #[async_trait::async_trait]
@@ -399,6 +416,10 @@ impl TableContext for QueryContext {
         *status = info.to_string();
     }
 
+    fn get_created_time(&self) -> SystemTime {
+        self.shared.created_time
+    }
+
     fn get_partition(&self) -> Option<PartInfoPtr> {
         self.partition_queue.write().pop_front()
     }
@@ -583,12 +604,18 @@ impl TableContext for QueryContext {
         let tz = TzFactory::instance().get_by_name(&tz)?;
         let numeric_cast_option = self.get_settings().get_numeric_cast_option()?;
         let rounding_mode = numeric_cast_option.as_str() == "rounding";
+        let strict_array_zip_with = self.get_settings().get_enable_strict_array_zip_with()?;
+        let strict_array_zip = self.get_settings().get_enable_strict_array_zip()?;
+        let strict_array_set = self.get_settings().get_enable_strict_array_set()?;
 
         let query_config = &GlobalConfig::instance().query;
 
         Ok(FunctionContext {
             tz,
             rounding_mode,
+            strict_array_zip_with,
+            strict_array_zip,
+            strict_array_set,
 
             openai_api_key: query_config.openai_api_key.clone(),
             openai_api_version: query_config.openai_api_version.clone(),
@@ -828,6 +855,8 @@ impl TableContext for QueryContext {
     fn clear_segment_locations(&self) -> Result<()> {
         let mut segment_locations = self.inserted_segment_locs.write();
         segment_locations.clear();
+        // The blocks are in the cleared segments.
+        self.inserted_block_locs.write().clear();
         Ok(())
     }
 
@@ -840,6 +869,21 @@ impl TableContext for QueryContext {
             .collect::<Vec<_>>())
     }
 
+    fn add_block_location(&self, block_loc: String) -> Result<()> {
+        let mut block_locations = self.inserted_block_locs.write();
+        block_locations.insert(block_loc);
+        Ok(())
+    }
+
+    fn get_block_locations(&self) -> Result<Vec<String>> {
+        Ok(self
+            .inserted_block_locs
+            .read()
+            .iter()
+            .cloned()
+            .collect::<Vec<_>>())
+    }
+
     fn add_file_status(&self, file_path: &str, file_status: FileStatus) -> Result<()> {
         if matches!(self.get_query_kind(), QueryKind::CopyIntoTable) {
             self.shared.copy_status.add_chunk(file_path, file_status);
@@ -917,12 +961,19 @@ impl TableContext for QueryContext {
                 v.insert(filters.1);
             }
             Entry::Occupied(mut v) => {
+                if filters.1.is_build_empty() {
+                    v.get_mut().set_build_empty();
+                }
                 for filter in filters.1.get_inlist() {
                     v.get_mut().add_inlist(filter.clone());
                 }
                 for filter in filters.1.get_min_max() {
                     v.get_mut().add_min_max(filter.clone());
                 }
+                // The disjunctive groups are kept apart, they are ANDed with the other filters.
+                for blooms in filters.1.get_disjunctive_bloom() {
+                    v.get_mut().add_disjunctive_bloom(blooms.clone());
+                }
                 for filter in filters.1.blooms() {
                     v.get_mut().add_bloom(filter);
                 }
@@ -938,6 +989,17 @@ impl TableContext for QueryContext {
         }
     }
 
+    fn get_disjunctive_bloom_runtime_filter_with_id(
+        &self,
+        id: IndexType,
+    ) -> Vec<Vec<(String, BinaryFuse8)>> {
+        let runtime_filters = self.shared.runtime_filters.read();
+        match runtime_filters.get(&id) {
+            Some(v) => (v.get_disjunctive_bloom()).clone(),
+            None => vec![],
+        }
+    }
+
     fn get_inlist_runtime_filter_with_id(&self, id: IndexType) -> Vec<Expr<String>> {
         let runtime_filters = self.shared.runtime_filters.read();
         match runtime_filters.get(&id) {
@@ -956,10 +1018,83 @@ impl TableContext for QueryContext {
 
     fn has_bloom_runtime_filters(&self, id: usize) -> bool {
         if let Some(runtime_filter) = self.shared.runtime_filters.read().get(&id) {
-            return !runtime_filter.get_bloom().is_empty();
+            return !runtime_filter.get_bloom().is_empty()
+                || !runtime_filter.get_disjunctive_bloom().is_empty();
         }
         false
     }
+
+    fn is_runtime_filter_build_empty(&self, id: usize) -> bool {
+        if let Some(runtime_filter) = self.shared.runtime_filters.read().get(&id) {
+            return runtime_filter.is_build_empty();
+        }
+        false
+    }
+
+    fn add_limited_scan_rows(&self, table_index: usize, rows: usize) -> usize {
+        let mut limited_scan_rows = self.shared.limited_scan_rows.write();
+        let total_rows = limited_scan_rows.entry(table_index).or_default();
+        *total_rows += rows;
+        *total_rows
+    }
+
+    fn get_limited_scan_rows(&self, table_index: usize) -> usize {
+        self.shared
+            .limited_scan_rows
+            .read()
+            .get(&table_index)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn set_column_masks(&self, table_index: usize, masks: HashMap<String, ColumnMask>) {
+        self.shared.column_masks.write().insert(table_index, masks);
+    }
+
+    fn get_column_masks(&self, table_index: usize) -> HashMap<String, ColumnMask> {
+        self.shared
+            .column_masks
+            .read()
+            .get(&table_index)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn add_scan_decode_error(&self, table_index: usize, error: ScanDecodeError) {
+        self.shared
+            .scan_decode_errors
+            .write()
+            .entry(table_index)
+            .or_default()
+            .push(error);
+    }
+
+    fn get_scan_decode_errors(&self, table_index: usize) -> Vec<ScanDecodeError> {
+        self.shared
+            .scan_decode_errors
+            .read()
+            .get(&table_index)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn add_scan_part_profile(&self, table_index: usize, profile: ScanPartProfile) {
+        self.shared
+            .scan_part_profiles
+            .write()
+            .entry(table_index)
+            .or_default()
+            .push(profile);
+    }
+
+    fn get_scan_part_profiles(&self, table_index: usize) -> Vec<ScanPartProfile> {
+        self.shared
+            .scan_part_profiles
+            .read()
+            .get(&table_index)
+            .cloned()
+            .unwrap_or_default()
+    }
 }
 
 impl TrySpawn for QueryContext {
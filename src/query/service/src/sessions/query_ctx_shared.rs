@@ -26,7 +26,10 @@ use databend_common_base::runtime::Runtime;
 use databend_common_catalog::catalog::CatalogManager;
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::runtime_filter_info::RuntimeFilterInfo;
+use databend_common_catalog::table_context::ColumnMask;
 use databend_common_catalog::table_context::MaterializedCtesBlocks;
+use databend_common_catalog::table_context::ScanDecodeError;
+use databend_common_catalog::table_context::ScanPartProfile;
 use databend_common_catalog::table_context::StageAttachment;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -50,6 +53,7 @@ use uuid::Uuid;
 use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
 use crate::sessions::query_affect::QueryAffect;
+use crate::sessions::RefreshHookStats;
 use crate::sessions::Session;
 use crate::storages::Table;
 
@@ -113,6 +117,24 @@ pub struct QueryContextShared {
     pub(in crate::sessions) query_profiles: Arc<RwLock<HashMap<Option<u32>, PlanProfile>>>,
 
     pub(in crate::sessions) runtime_filters: Arc<RwLock<HashMap<IndexType, RuntimeFilterInfo>>>,
+
+    /// The rows emitted by the scans under a pushed down limit, keyed by the table index.
+    pub(in crate::sessions) limited_scan_rows: Arc<RwLock<HashMap<IndexType, usize>>>,
+
+    /// The masks of the columns read by the scans, keyed by the table index.
+    pub(in crate::sessions) column_masks:
+        Arc<RwLock<HashMap<IndexType, HashMap<String, ColumnMask>>>>,
+
+    /// The pages failed to be decoded by the scans, keyed by the table index.
+    pub(in crate::sessions) scan_decode_errors:
+        Arc<RwLock<HashMap<IndexType, Vec<ScanDecodeError>>>>,
+
+    /// The profiles of the parts decoded by the scans, keyed by the table index.
+    pub(in crate::sessions) scan_part_profiles:
+        Arc<RwLock<HashMap<IndexType, Vec<ScanPartProfile>>>>,
+
+    /// The outcome of the refreshes run by the hook after the write.
+    pub(in crate::sessions) refresh_hook_stats: Arc<RwLock<RefreshHookStats>>,
 }
 
 impl QueryContextShared {
@@ -158,6 +180,11 @@ impl QueryContextShared {
             group_by_spill_progress: Arc::new(Progress::create()),
             query_profiles: Arc::new(RwLock::new(HashMap::new())),
             runtime_filters: Default::default(),
+            limited_scan_rows: Default::default(),
+            column_masks: Default::default(),
+            scan_decode_errors: Default::default(),
+            scan_part_profiles: Default::default(),
+            refresh_hook_stats: Default::default(),
         }))
     }
 
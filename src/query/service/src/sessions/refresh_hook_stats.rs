@@ -0,0 +1,35 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The outcome of the refreshes run by the hook after the write of a query.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RefreshHookStats {
+    /// The refreshes executed successfully.
+    pub succeeded: usize,
+    /// The refreshes that failed.
+    pub failed: usize,
+    /// The refreshes with nothing to do, or not allowed by the license.
+    pub skipped: usize,
+    /// The codes of the errors met by the failed and skipped refreshes.
+    pub error_codes: Vec<u16>,
+}
+
+impl RefreshHookStats {
+    pub fn merge(&mut self, other: RefreshHookStats) {
+        self.succeeded += other.succeeded;
+        self.failed += other.failed;
+        self.skipped += other.skipped;
+        self.error_codes.extend(other.error_codes);
+    }
+}
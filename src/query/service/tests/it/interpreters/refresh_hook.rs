@@ -0,0 +1,118 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_base::runtime::MemStat;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_sql::Planner;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::RefreshHookStats;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::TestFixture;
+use futures::TryStreamExt;
+
+async fn insert_with_refresh(
+    fixture: &TestFixture,
+    refresh_virtual_column: bool,
+    dry_run: bool,
+) -> Result<Arc<QueryContext>> {
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings().set_setting(
+        "enable_refresh_virtual_column_after_write".to_string(),
+        (refresh_virtual_column as u8).to_string(),
+    )?;
+    ctx.get_settings()
+        .set_setting("refresh_hook_dry_run".to_string(), (dry_run as u8).to_string())?;
+    let qry = format!("insert into {}.t values (1), (2)", fixture.default_db_name());
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(&qry).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let stream = interpreter.execute(ctx.clone()).await?;
+    stream.try_collect::<Vec<DataBlock>>().await?;
+    Ok(ctx)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_refresh_hook_stats() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("create table {db}.t(a int)"))
+        .await?;
+
+    // No refresh to run.
+    let ctx = insert_with_refresh(&fixture, false, false).await?;
+    assert_eq!(ctx.get_refresh_hook_stats(), RefreshHookStats::default());
+
+    // The refresh of virtual columns is not allowed without the enterprise license,
+    // it's skipped and the insert still succeeds.
+    let ctx = insert_with_refresh(&fixture, true, false).await?;
+    assert_eq!(ctx.get_refresh_hook_stats(), RefreshHookStats {
+        succeeded: 0,
+        failed: 0,
+        skipped: 1,
+        error_codes: vec![ErrorCode::LICENSE_KEY_INVALID],
+    });
+
+    // The dry run checks the license the same way.
+    let ctx = insert_with_refresh(&fixture, true, true).await?;
+    assert_eq!(ctx.get_refresh_hook_stats(), RefreshHookStats {
+        succeeded: 0,
+        failed: 0,
+        skipped: 1,
+        error_codes: vec![ErrorCode::LICENSE_KEY_INVALID],
+    });
+
+    // The refresh is aborted for exceeding the memory limit of the query before running,
+    // the insert still succeeds.
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings().set_setting("max_memory_usage".to_string(), "1".to_string())?;
+    ctx.get_settings().set_setting(
+        "enable_refresh_virtual_column_after_write".to_string(),
+        "1".to_string(),
+    )?;
+    let qry = format!("insert into {db}.t values (1), (2)");
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(&qry).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    // The memory usage is recorded to the query, whatever the allocator of the test is.
+    let mem_stat = Some(ctx.get_query_mem_stat()?);
+    assert!(MemStat::record_memory::<false>(&mem_stat, 1024).is_ok());
+    let stream = interpreter.execute(ctx.clone()).await?;
+    stream.try_collect::<Vec<DataBlock>>().await?;
+    assert_eq!(ctx.get_refresh_hook_stats(), RefreshHookStats {
+        succeeded: 0,
+        failed: 1,
+        skipped: 0,
+        error_codes: vec![ErrorCode::ABORTED_QUERY],
+    });
+
+    let rows = fixture
+        .execute_query(&format!("select * from {db}.t"))
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?
+        .iter()
+        .map(|block| block.num_rows())
+        .sum::<usize>();
+    assert_eq!(rows, 8);
+
+    Ok(())
+}
@@ -20,17 +20,23 @@ use databend_common_base::base::tokio;
 use databend_common_base::base::tokio::sync::mpsc::channel;
 use databend_common_base::base::tokio::sync::mpsc::Receiver;
 use databend_common_base::base::tokio::sync::mpsc::Sender;
+use databend_common_base::runtime::MemStat;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::Int32Type;
 use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
 use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipe;
 use databend_common_pipeline_core::PipeItem;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sinks::EmptySink;
 use databend_common_pipeline_sinks::SyncSenderSink;
 use databend_common_pipeline_sources::SyncReceiverSource;
+use databend_common_pipeline_sources::SyncSource;
+use databend_common_pipeline_sources::SyncSourcer;
 use databend_query::pipelines::executor::ExecutorSettings;
 use databend_query::pipelines::executor::PipelineExecutor;
 use databend_query::sessions::QueryContext;
@@ -44,6 +50,7 @@ async fn test_always_call_on_finished() -> Result<()> {
         enable_profiling: false,
         query_id: Arc::new("".to_string()),
         max_execute_time_in_seconds: Default::default(),
+        memory_limit: None,
     };
 
     {
@@ -91,6 +98,65 @@ async fn test_always_call_on_finished() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_abort_on_exceeding_max_memory_usage() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+    let mem_stat = MemStat::create("test_abort_on_exceeding_max_memory_usage".to_string());
+
+    let settings = ExecutorSettings {
+        enable_profiling: false,
+        query_id: Arc::new("".to_string()),
+        max_execute_time_in_seconds: Default::default(),
+        memory_limit: Some((mem_stat.clone(), 1)),
+    };
+
+    // The source never ends, the executor is aborted by the memory usage checked while running.
+    let mut pipeline = Pipeline::create();
+    let output = OutputPort::create();
+    pipeline.add_pipe(Pipe::create(0, 1, vec![PipeItem::create(
+        SyncSourcer::create(ctx, output.clone(), EndlessSource)?,
+        vec![],
+        vec![output],
+    )]));
+    let input = InputPort::create();
+    pipeline.add_pipe(Pipe::create(1, 0, vec![PipeItem::create(
+        ProcessorPtr::create(EmptySink::create(input.clone())),
+        vec![input],
+        vec![],
+    )]));
+    pipeline.set_max_threads(1);
+
+    // The memory usage is recorded to the tracker, whatever the allocator of the test is.
+    assert!(MemStat::record_memory::<false>(&Some(mem_stat), 1024).is_ok());
+    let executor = PipelineExecutor::create(pipeline, settings)?;
+    match executor.execute() {
+        Ok(_) => unreachable!(),
+        Err(error) => {
+            assert_eq!(error.code(), ErrorCode::ABORTED_QUERY);
+            assert!(
+                error
+                    .message()
+                    .starts_with("Aborted query, because the memory usage exceeds")
+            );
+        }
+    }
+
+    Ok(())
+}
+
+struct EndlessSource;
+
+impl SyncSource for EndlessSource {
+    const NAME: &'static str = "EndlessSource";
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        Ok(Some(DataBlock::new_from_columns(vec![Int32Type::from_data(
+            vec![1],
+        )])))
+    }
+}
+
 fn create_pipeline() -> (Arc<AtomicBool>, Pipeline) {
     let called_finished = Arc::new(AtomicBool::new(false));
     let mut pipeline = Pipeline::create();
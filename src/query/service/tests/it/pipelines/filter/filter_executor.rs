@@ -71,14 +71,21 @@ pub fn test_filter_executor() -> databend_common_exception::Result<()> {
             );
             let block_2 = filter_executor.filter(block.clone())?;
 
-            // 4. Check if the result block generated by `Evaluator` is the same as the result block generated by `FilterExecutor`.
-            assert_eq!(block_1.num_columns(), block_2.num_columns());
-            assert_eq!(block_1.num_rows(), block_2.num_rows());
-            let columns_1 = block_1.columns();
-            let columns_2 = block_2.columns();
-            for idx in 0..columns_1.len() {
-                assert_eq!(columns_1[idx].data_type, columns_2[idx].data_type);
-                assert_eq!(columns_1[idx].value, columns_2[idx].value);
+            // 3.3 Execute the filter expr by `FilterExecutor` on random sized sub-blocks.
+            let batch_rows = rng.gen_range(1..=num_rows.max(1));
+            let count = filter_executor.select_in_batches(&block, batch_rows)?;
+            let block_3 = filter_executor.take(block.clone(), num_rows, count)?;
+
+            // 4. Check if the result block generated by `Evaluator` is the same as the result blocks generated by `FilterExecutor`.
+            for block_2 in [block_2, block_3] {
+                assert_eq!(block_1.num_columns(), block_2.num_columns());
+                assert_eq!(block_1.num_rows(), block_2.num_rows());
+                let columns_1 = block_1.columns();
+                let columns_2 = block_2.columns();
+                for idx in 0..columns_1.len() {
+                    assert_eq!(columns_1[idx].data_type, columns_2[idx].data_type);
+                    assert_eq!(columns_1[idx].value, columns_2[idx].value);
+                }
             }
         }
     }
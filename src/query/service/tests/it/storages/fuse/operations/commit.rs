@@ -16,6 +16,7 @@ use std::any::Any;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
+use std::time::SystemTime;
 
 use dashmap::DashMap;
 use databend_common_base::base::tokio;
@@ -30,8 +31,11 @@ use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::ColumnMask;
 use databend_common_catalog::table_context::MaterializedCtesBlocks;
 use databend_common_catalog::table_context::ProcessInfo;
+use databend_common_catalog::table_context::ScanDecodeError;
+use databend_common_catalog::table_context::ScanPartProfile;
 use databend_common_catalog::table_context::StageAttachment;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
@@ -431,6 +435,10 @@ impl TableContext for CtxDelegation {
 
     fn set_status_info(&self, _info: &str) {}
 
+    fn get_created_time(&self) -> SystemTime {
+        todo!()
+    }
+
     fn get_partition(&self) -> Option<PartInfoPtr> {
         todo!()
     }
@@ -660,6 +668,14 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn add_block_location(&self, _block_loc: String) -> Result<()> {
+        todo!()
+    }
+
+    fn get_block_locations(&self) -> Result<Vec<String>> {
+        todo!()
+    }
+
     fn set_need_compact_after_write(&self, _enable: bool) {
         todo!()
     }
@@ -707,6 +723,13 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn get_disjunctive_bloom_runtime_filter_with_id(
+        &self,
+        _id: usize,
+    ) -> Vec<Vec<(String, BinaryFuse8)>> {
+        todo!()
+    }
+
     fn get_inlist_runtime_filter_with_id(&self, _id: usize) -> Vec<Expr<String>> {
         todo!()
     }
@@ -718,6 +741,42 @@ impl TableContext for CtxDelegation {
     fn has_bloom_runtime_filters(&self, _id: usize) -> bool {
         todo!()
     }
+
+    fn is_runtime_filter_build_empty(&self, _id: usize) -> bool {
+        todo!()
+    }
+
+    fn add_limited_scan_rows(&self, _table_index: usize, _rows: usize) -> usize {
+        todo!()
+    }
+
+    fn get_limited_scan_rows(&self, _table_index: usize) -> usize {
+        todo!()
+    }
+
+    fn set_column_masks(&self, _table_index: usize, _masks: HashMap<String, ColumnMask>) {
+        todo!()
+    }
+
+    fn get_column_masks(&self, _table_index: usize) -> HashMap<String, ColumnMask> {
+        todo!()
+    }
+
+    fn add_scan_decode_error(&self, _table_index: usize, _error: ScanDecodeError) {
+        todo!()
+    }
+
+    fn get_scan_decode_errors(&self, _table_index: usize) -> Vec<ScanDecodeError> {
+        todo!()
+    }
+
+    fn add_scan_part_profile(&self, _table_index: usize, _profile: ScanPartProfile) {
+        todo!()
+    }
+
+    fn get_scan_part_profiles(&self, _table_index: usize) -> Vec<ScanPartProfile> {
+        todo!()
+    }
 }
 
 #[derive(Clone, Debug)]
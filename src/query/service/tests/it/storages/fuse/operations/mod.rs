@@ -20,6 +20,7 @@ mod commit;
 mod gc;
 mod internal_column;
 mod mutation;
+mod native_read;
 mod navigate;
 mod optimize;
 mod purge_drop;
@@ -0,0 +1,1897 @@
+// Copyright 2024 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_arrow::native::ColumnMeta as NativeColumnMeta;
+use databend_common_arrow::native::PageMeta;
+use databend_common_base::base::tokio;
+use databend_common_catalog::plan::InternalColumnMeta;
+use databend_common_catalog::plan::LineageMeta;
+use databend_common_catalog::plan::PrewhereInfo;
+use databend_common_catalog::plan::Projection;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::plan::VirtualColumnInfo;
+use databend_common_catalog::runtime_filter_info::RuntimeFilterInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::ColumnMask;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::block_debug::assert_blocks_sorted_eq;
+use databend_common_expression::type_check::check_function;
+use databend_common_expression::types::nullable::NullableColumn;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::types::StringType;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Expr;
+use databend_common_expression::FromData;
+use databend_common_expression::RemoteExpr;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_hashtable::FastHash;
+use databend_common_pipeline_core::processors::connect;
+use databend_common_pipeline_core::processors::Event;
+use databend_common_pipeline_core::processors::EventCause;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_sql::executor::table_read_plan::ToReadDataSourcePlan;
+use databend_common_sql::parse_to_filters;
+use databend_common_storages_fuse::io::NativeSourceData;
+use databend_common_storages_fuse::operations::order_bloom_runtime_filters;
+use databend_common_storages_fuse::operations::DataSourceWithMeta;
+use databend_common_storages_fuse::operations::NativeDataSource;
+use databend_common_storages_fuse::operations::NativeDeserializeDataTransform;
+use databend_common_storages_fuse::FusePartInfo;
+use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_fuse::TableContext;
+use databend_common_storages_fuse::DEFAULT_ROW_PER_PAGE;
+use databend_query::pipelines::executor::ExecutorSettings;
+use databend_query::pipelines::executor::PipelinePullingExecutor;
+use databend_query::sessions::QueryContext;
+use databend_query::stream::ReadDataBlockStream;
+use databend_query::test_kits::*;
+use databend_storages_common_table_meta::meta::ColumnMeta;
+use databend_storages_common_table_meta::meta::Compression;
+use futures::TryStreamExt;
+use xorf::BinaryFuse8;
+
+/// Set up the native table `t` of the definition in the default database, e.g. `(a int)`,
+/// each of the values is inserted as a block.
+async fn setup_native_table(definition: &str, blocks: &[&str]) -> Result<TestFixture> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+
+    let qry = format!("create table {db}.t{definition} storage_format = 'native'");
+    fixture.execute_command(&qry).await?;
+    for values in blocks {
+        let qry = format!("insert into {db}.t values{values}");
+        fixture.execute_command(&qry).await?;
+    }
+    Ok(fixture)
+}
+
+/// Create a new query context with the settings.
+async fn new_query_ctx(
+    fixture: &TestFixture,
+    settings: &[(&str, &str)],
+) -> Result<Arc<QueryContext>> {
+    let ctx = fixture.new_query_ctx().await?;
+    for (name, value) in settings {
+        ctx.get_settings()
+            .set_setting(name.to_string(), value.to_string())?;
+    }
+    Ok(ctx)
+}
+
+/// Get the table of the default database, it's always fresh from the catalog.
+async fn get_table(
+    fixture: &TestFixture,
+    ctx: &Arc<QueryContext>,
+    name: &str,
+) -> Result<Arc<dyn Table>> {
+    let catalog = ctx
+        .get_catalog(fixture.default_catalog_name().as_str())
+        .await?;
+    catalog
+        .get_table(ctx.get_tenant().as_str(), &fixture.default_db_name(), name)
+        .await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_parquet_parts() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+
+    let qry = format!("create table {db}.t_parquet(a int, b int) storage_format = 'parquet'");
+    fixture.execute_command(&qry).await?;
+    let qry = format!("create table {db}.t_native(a int, b int) storage_format = 'native'");
+    fixture.execute_command(&qry).await?;
+    let qry = format!("insert into {db}.t_parquet values(1, 10), (2, 20)");
+    fixture.execute_command(&qry).await?;
+    let qry = format!("insert into {db}.t_native values(3, 30), (4, 40)");
+    fixture.execute_command(&qry).await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let parquet_table = get_table(&fixture, &ctx, "t_parquet").await?;
+    let native_table = get_table(&fixture, &ctx, "t_native").await?;
+
+    // Scan the native table with one native part and one parquet part,
+    // as if the table is migrated from parquet to native in place.
+    let parquet_plan = parquet_table.read_plan(ctx.clone(), None, true).await?;
+    let mut plan = native_table.read_plan(ctx.clone(), None, true).await?;
+    assert_eq!(plan.parts.partitions.len(), 1);
+    assert_eq!(parquet_plan.parts.partitions.len(), 1);
+    plan.parts
+        .partitions
+        .extend(parquet_plan.parts.partitions.into_iter());
+
+    let stream = native_table
+        .read_data_block_stream(ctx.clone(), &plan)
+        .await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    let rows: usize = blocks.iter().map(|block| block.num_rows()).sum();
+    assert_eq!(rows, 4);
+
+    let expected = vec![
+        "+----------+----------+",
+        "| Column 0 | Column 1 |",
+        "+----------+----------+",
+        "| 1        | 10       |",
+        "| 2        | 20       |",
+        "| 3        | 30       |",
+        "| 4        | 40       |",
+        "+----------+----------+",
+    ];
+    assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_parquet_parts_and_filters() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+
+    let qry = format!(
+        "create table {db}.t_parquet(a int not null, v variant) storage_format = 'parquet'"
+    );
+    fixture.execute_command(&qry).await?;
+    let qry =
+        format!("create table {db}.t_native(a int not null, v variant) storage_format = 'native'");
+    fixture.execute_command(&qry).await?;
+    let qry = format!(
+        r#"insert into {db}.t_parquet values(1, '{{"k":1}}'), (2, '{{"k":2}}'), (3, '{{"k":3}}')"#
+    );
+    fixture.execute_command(&qry).await?;
+    let qry = format!(r#"insert into {db}.t_native values(4, '{{"k":4}}')"#);
+    fixture.execute_command(&qry).await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let parquet_table = get_table(&fixture, &ctx, "t_parquet").await?;
+    let native_table = get_table(&fixture, &ctx, "t_native").await?;
+
+    let parquet_plan = parquet_table.read_plan(ctx.clone(), None, true).await?;
+    let mut plan = native_table.read_plan(ctx.clone(), None, true).await?;
+    plan.parts
+        .partitions
+        .extend(parquet_plan.parts.partitions.into_iter());
+
+    let virtual_column = VirtualColumnInfo {
+        source_name: "v".to_string(),
+        name: "v['k']".to_string(),
+        key_paths: Scalar::String("{k}".as_bytes().to_vec()),
+        data_type: Box::new(TableDataType::Nullable(Box::new(TableDataType::Variant))),
+    };
+    let mut fields = plan.output_schema.fields().clone();
+    fields.push(TableField::new(&virtual_column.name, *virtual_column.data_type.clone()));
+    plan.output_schema = Arc::new(TableSchema::new(fields));
+    plan.push_downs = Some(PushDownInfo {
+        virtual_columns: Some(vec![virtual_column]),
+        ..Default::default()
+    });
+
+    // The virtual columns are generated from the masked source columns, their own masks
+    // don't apply, and must not be mapped to the columns deserialized from the parquet part.
+    let mask: ColumnMask =
+        Arc::new(|_: &Column| -> Result<Column> { Err(ErrorCode::Internal("unexpected mask")) });
+    ctx.set_column_masks(plan.table_index, HashMap::from([("v['k']".to_string(), mask)]));
+
+    // The rows of the parquet part are filtered by the bloom runtime filter as well.
+    let hashes = [1u32, 4]
+        .iter()
+        .map(|key| key.fast_hash())
+        .collect::<Vec<_>>();
+    let mut runtime_filter = RuntimeFilterInfo::default();
+    runtime_filter.add_bloom(("a".to_string(), BinaryFuse8::try_from(&hashes)?));
+    ctx.set_runtime_filter((plan.table_index, runtime_filter));
+
+    let stream = native_table
+        .read_data_block_stream(ctx.clone(), &plan)
+        .await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    let expected = vec![
+        "+----------+----------+----------+",
+        "| Column 0 | Column 1 | Column 2 |",
+        "+----------+----------+----------+",
+        "| 1        | {\"k\":1}  | 1        |",
+        "| 4        | {\"k\":4}  | 4        |",
+        "+----------+----------+----------+",
+    ];
+    assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_output_block_rows() -> Result<()> {
+    let fixture = setup_native_table("(a int)", &[
+        "(1), (2), (3), (4), (5)",
+        "(6), (7)",
+        "(8), (9), (10), (11)",
+    ])
+    .await?;
+
+    let ctx = new_query_ctx(&fixture, &[
+        ("max_threads", "1"),
+        ("storage_native_output_block_rows", "3"),
+    ])
+    .await?;
+    let table = get_table(&fixture, &ctx, "t").await?;
+    let plan = table.read_plan(ctx.clone(), None, true).await?;
+    assert_eq!(plan.parts.partitions.len(), 3);
+
+    let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    // The blocks of 5, 2 and 4 rows are output as blocks of 3 rows, except the last one.
+    let rows = blocks
+        .iter()
+        .map(|block| block.num_rows())
+        .collect::<Vec<_>>();
+    assert_eq!(rows, vec![3, 3, 3, 2]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_max_output_block_rows() -> Result<()> {
+    let fixture = setup_native_table("(a int)", &[
+        "(1), (2), (3), (4), (5), (6), (7), (8), (9), (10)",
+    ])
+    .await?;
+    let db = fixture.default_db_name();
+
+    let ctx = new_query_ctx(&fixture, &[("storage_native_max_output_block_rows", "4")]).await?;
+    let table = get_table(&fixture, &ctx, "t").await?;
+    let plan = table.read_plan(ctx.clone(), None, true).await?;
+    let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    let rows = blocks
+        .iter()
+        .map(|block| block.num_rows())
+        .collect::<Vec<_>>();
+    assert_eq!(rows, vec![4, 4, 2]);
+
+    // The row ids are generated from the offsets of the internal column meta,
+    // they must be the same whether the blocks are split or not.
+    let mut row_ids = vec![];
+    for max_output_block_rows in [0, 3] {
+        let ctx = new_query_ctx(&fixture, &[
+            ("storage_native_max_output_block_rows", &max_output_block_rows.to_string()),
+        ])
+        .await?;
+        let qry = format!("select a, _row_id from {db}.t where a > 2");
+        let stream = execute_query(ctx.clone(), &qry).await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+        let block = DataBlock::concat(&blocks)?;
+        let mut values = (0..block.num_rows())
+            .map(|row| {
+                let a = block.get_by_offset(0).value.index(row).unwrap();
+                let row_id = block.get_by_offset(1).value.index(row).unwrap();
+                (a.to_string(), row_id.to_string())
+            })
+            .collect::<Vec<_>>();
+        values.sort();
+        assert_eq!(values.len(), 8);
+        row_ids.push(values);
+    }
+    assert_eq!(row_ids[0], row_ids[1]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_filter_batch_rows() -> Result<()> {
+    let fixture = setup_native_table("(a int)", &[
+        "(1), (2), (3), (4), (5), (6), (7), (8), (9), (10)",
+    ])
+    .await?;
+    let db = fixture.default_db_name();
+
+    // The batches of 4 rows don't divide the page, and the second batch selects nothing.
+    for filter_batch_rows in [0, 4, 100] {
+        let ctx = new_query_ctx(&fixture, &[
+            ("storage_native_filter_batch_rows", &filter_batch_rows.to_string()),
+        ])
+        .await?;
+        let qry = format!("select a from {db}.t where a < 4 or a > 8");
+        let stream = execute_query(ctx.clone(), &qry).await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+
+        let expected = vec![
+            "+----------+",
+            "| Column 0 |",
+            "+----------+",
+            "| 1        |",
+            "| 10       |",
+            "| 2        |",
+            "| 3        |",
+            "| 9        |",
+            "+----------+",
+        ];
+        assert_blocks_sorted_eq(expected, blocks.as_slice());
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_filter_row_per_page() -> Result<()> {
+    // Four rows per page, more than the selection vectors are allocated for.
+    let fixture = setup_native_table("(a int not null, b int not null) row_per_page = 4", &[
+        "(1, 10), (2, 20), (3, 30), (4, 40), (5, 50), (6, 60)",
+    ])
+    .await?;
+    let db = fixture.default_db_name();
+
+    // The prewhere filter executor, with or without the split stages.
+    for split_prewhere in [0, 1] {
+        let ctx = new_query_ctx(&fixture, &[
+            ("storage_native_filter_row_per_page", "1"),
+            ("storage_native_split_prewhere", &split_prewhere.to_string()),
+        ])
+        .await?;
+        let qry = format!("select a, b from {db}.t where a > 1 and b < 60 or a = 6");
+        let stream = execute_query(ctx.clone(), &qry).await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+
+        let expected = vec![
+            "+----------+----------+",
+            "| Column 0 | Column 1 |",
+            "+----------+----------+",
+            "| 2        | 20       |",
+            "| 3        | 30       |",
+            "| 4        | 40       |",
+            "| 5        | 50       |",
+            "| 6        | 60       |",
+            "+----------+----------+",
+        ];
+        assert_blocks_sorted_eq(expected, blocks.as_slice());
+    }
+
+    // The dummy filter executor built for the bitmaps without prewhere filter.
+    let ctx = new_query_ctx(&fixture, &[("storage_native_filter_row_per_page", "1")]).await?;
+    let table = get_table(&fixture, &ctx, "t").await?;
+    let plan = table.read_plan(ctx.clone(), None, true).await?;
+    let hashes = [1u32, 3, 4, 6]
+        .iter()
+        .map(|key| key.fast_hash())
+        .collect::<Vec<_>>();
+    let mut runtime_filter = RuntimeFilterInfo::default();
+    runtime_filter.add_bloom(("a".to_string(), BinaryFuse8::try_from(&hashes)?));
+    ctx.set_runtime_filter((plan.table_index, runtime_filter));
+
+    let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    let expected = vec![
+        "+----------+----------+",
+        "| Column 0 | Column 1 |",
+        "+----------+----------+",
+        "| 1        | 10       |",
+        "| 3        | 30       |",
+        "| 4        | 40       |",
+        "| 6        | 60       |",
+        "+----------+----------+",
+    ];
+    assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_part_rows_mismatch() -> Result<()> {
+    let fixture = setup_native_table("(a int)", &["(1), (2)"]).await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let table = get_table(&fixture, &ctx, "t").await?;
+    let mut plan = table.read_plan(ctx.clone(), None, true).await?;
+    assert_eq!(plan.parts.partitions.len(), 1);
+
+    // The part declares more rows than its pages contain, as if it's corrupted.
+    let mut part = FusePartInfo::from_part(&plan.parts.partitions[0])?.clone();
+    part.nums_rows += 1;
+    plan.parts.partitions[0] = Arc::new(Box::new(part));
+
+    let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await;
+    assert!(result.is_err());
+    assert_eq!(
+        result.unwrap_err().code(),
+        ErrorCode::StorageOther("").code()
+    );
+
+    // Only warn on mismatch, the decoded rows are returned.
+    let ctx = new_query_ctx(&fixture, &[("storage_native_part_rows_mismatch", "warn")]).await?;
+    let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    let rows: usize = blocks.iter().map(|block| block.num_rows()).sum();
+    assert_eq!(rows, 2);
+
+    // The part declares fewer rows than its pages contain.
+    let mut part = FusePartInfo::from_part(&plan.parts.partitions[0])?.clone();
+    part.nums_rows -= 2;
+    plan.parts.partitions[0] = Arc::new(Box::new(part));
+
+    let ctx = fixture.new_query_ctx().await?;
+    let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await;
+    assert_eq!(
+        result.unwrap_err().code(),
+        ErrorCode::StorageOther("").code()
+    );
+
+    // Warn on mismatch and drop the rows past the rows count of the part.
+    let ctx = new_query_ctx(&fixture, &[("storage_native_part_rows_mismatch", "warn")]).await?;
+    let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    let expected = vec![
+        "+----------+",
+        "| Column 0 |",
+        "+----------+",
+        "| 1        |",
+        "+----------+",
+    ];
+    assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_strict_page_count() -> Result<()> {
+    let fixture = setup_native_table("(a int not null, b int not null) row_per_page = 2", &[
+        "(1, 1), (2, 2), (3, 3), (4, 4)",
+    ])
+    .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let table = get_table(&fixture, &ctx, "t").await?;
+    let mut plan = table.read_plan(ctx.clone(), None, true).await?;
+    assert_eq!(plan.parts.partitions.len(), 1);
+
+    // Drop the last page of column `b`, as if the file is truncated.
+    let mut part = FusePartInfo::from_part(&plan.parts.partitions[0])?.clone();
+    let mut native_meta = part.columns_meta[&1].as_native().unwrap().clone();
+    assert_eq!(native_meta.pages.len(), 2);
+    native_meta.pages.pop();
+    part.columns_meta.insert(1, ColumnMeta::Native(native_meta));
+    let location = part.location.clone();
+    plan.parts.partitions[0] = Arc::new(Box::new(part));
+
+    // The part is finished once the pages of `b` are exhausted by default.
+    let ctx = new_query_ctx(&fixture, &[("storage_native_part_rows_mismatch", "warn")]).await?;
+    let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    let rows: usize = blocks.iter().map(|block| block.num_rows()).sum();
+    assert_eq!(rows, 2);
+
+    // The page left in column `a` is reported in strict mode.
+    ctx.get_settings().set_setting(
+        "storage_native_strict_page_count".to_string(),
+        "1".to_string(),
+    )?;
+    let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+    let err = stream.try_collect::<Vec<_>>().await.unwrap_err();
+    assert_eq!(err.code(), ErrorCode::StorageOther("").code());
+    assert!(err.message().contains(&location));
+    assert!(err.message().contains("columns [a]"));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_force_full_columns() -> Result<()> {
+    let fixture = setup_native_table("(a int)", &["(1), (2)"]).await?;
+    let db = fixture.default_db_name();
+
+    // The new column is filled with the default value as a constant column.
+    let qry = format!("alter table {db}.t add column b int default 7");
+    fixture.execute_command(&qry).await?;
+
+    for force_full_columns in [false, true] {
+        let ctx = new_query_ctx(&fixture, &[
+            ("storage_native_force_full_columns", &(force_full_columns as u64).to_string()),
+        ])
+        .await?;
+        let table = get_table(&fixture, &ctx, "t").await?;
+        let plan = table.read_plan(ctx.clone(), None, true).await?;
+
+        let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+        let all_full_columns = blocks.iter().all(|block| {
+            block
+                .columns()
+                .iter()
+                .all(|entry| matches!(entry.value, Value::Column(_)))
+        });
+        assert_eq!(all_full_columns, force_full_columns);
+
+        let expected = vec![
+            "+----------+----------+",
+            "| Column 0 | Column 1 |",
+            "+----------+----------+",
+            "| 1        | 7        |",
+            "| 2        | 7        |",
+            "+----------+----------+",
+        ];
+        assert_blocks_sorted_eq(expected, blocks.as_slice());
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_exceeds_scan_deadline() -> Result<()> {
+    let fixture = setup_native_table("(a int)", &[]).await?;
+    let db = fixture.default_db_name();
+
+    let qry = format!("insert into {db}.t select number from numbers(100000)");
+    fixture.execute_command(&qry).await?;
+
+    let ctx = new_query_ctx(&fixture, &[("max_storage_scan_time_in_milliseconds", "1")]).await?;
+    let table = get_table(&fixture, &ctx, "t").await?;
+    let plan = table.read_plan(ctx.clone(), None, true).await?;
+
+    let mut pipeline = Pipeline::create();
+    ctx.set_partitions(plan.parts.clone())?;
+    table.read_data(ctx.clone(), &plan, &mut pipeline, true)?;
+    pipeline.set_max_threads(ctx.get_settings().get_max_threads()? as usize);
+
+    // The deadline is counted from the creation of the query, make sure it has passed
+    // before any page is decoded.
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let executor_settings = ExecutorSettings::try_create(&settings, ctx.get_id())?;
+    let mut executor = PipelinePullingExecutor::try_create(pipeline, executor_settings)?;
+    ctx.set_executor(executor.get_inner())?;
+    executor.start();
+
+    let result = loop {
+        match executor.pull_data() {
+            Ok(Some(_)) => continue,
+            other => break other,
+        }
+    };
+    assert!(result.is_err());
+    assert_eq!(
+        result.unwrap_err().code(),
+        ErrorCode::AbortedQuery("").code()
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_empty_build_runtime_filter() -> Result<()> {
+    let fixture = setup_native_table("(a int)", &["(1), (2)", "(3), (4)"]).await?;
+
+    for skip_on_empty_build in [true, false] {
+        let ctx = new_query_ctx(&fixture, &[
+            ("enable_runtime_filter_empty_build_skip", &(skip_on_empty_build as u64).to_string()),
+        ])
+        .await?;
+        let table = get_table(&fixture, &ctx, "t").await?;
+        let plan = table.read_plan(ctx.clone(), None, true).await?;
+
+        let mut runtime_filter = RuntimeFilterInfo::default();
+        runtime_filter.set_build_empty();
+        ctx.set_runtime_filter((plan.table_index, runtime_filter));
+
+        let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+        let num_rows = blocks.iter().map(|b| b.num_rows()).sum::<usize>();
+        let expected_rows = if skip_on_empty_build { 0 } else { 4 };
+        assert_eq!(num_rows, expected_rows);
+        // No pages are decoded if the scan is skipped.
+        assert_eq!(ctx.get_scan_progress_value().rows, expected_rows);
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_reuse_array_iters() -> Result<()> {
+    let fixture = setup_native_table("(a int, b string, c array(int))", &[
+        "(1, 'a', [1]), (2, 'b', [])",
+        "(3, 'c', [3, 3])",
+        "(4, NULL, NULL)",
+    ])
+    .await?;
+    let db = fixture.default_db_name();
+
+    // The column added later has no readers in the parts inserted before.
+    let qry = format!("alter table {db}.t add column d int default 7");
+    fixture.execute_command(&qry).await?;
+    let qry = format!("insert into {db}.t values(5, 'e', [5], 5)");
+    fixture.execute_command(&qry).await?;
+
+    // The ArrayIters reset for each part must read the same rows as the rebuilt ones.
+    for reuse_array_iters in [0, 1] {
+        let ctx = new_query_ctx(&fixture, &[
+            ("max_threads", "1"),
+            ("storage_native_reuse_array_iters", &reuse_array_iters.to_string()),
+        ])
+        .await?;
+        let table = get_table(&fixture, &ctx, "t").await?;
+        let plan = table.read_plan(ctx.clone(), None, true).await?;
+        let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+----------+----------+----------+----------+",
+            "| Column 0 | Column 1 | Column 2 | Column 3 |",
+            "+----------+----------+----------+----------+",
+            "| 1        | 'a'      | [1]      | 7        |",
+            "| 2        | 'b'      | []       | 7        |",
+            "| 3        | 'c'      | [3, 3]   | 7        |",
+            "| 4        | NULL     | NULL     | 7        |",
+            "| 5        | 'e'      | [5]      | 5        |",
+            "+----------+----------+----------+----------+",
+        ];
+        assert_blocks_sorted_eq(expected, blocks.as_slice());
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_decode_parallelism() -> Result<()> {
+    let fixture = setup_native_table("(a int, b string, c array(int), d int)", &[
+        "(1, 'a', [1], 10), (2, 'b', [], 20)",
+        "(3, 'c', [3, 3], 30)",
+    ])
+    .await?;
+    let db = fixture.default_db_name();
+
+    // The remain columns decoded concurrently must line up with the prewhere column.
+    for decode_parallelism in [0, 2, 8] {
+        let ctx = new_query_ctx(&fixture, &[
+            ("storage_native_decode_parallelism", &decode_parallelism.to_string()),
+        ])
+        .await?;
+        let qry = format!("select * from {db}.t where a > 1");
+        let stream = execute_query(ctx.clone(), &qry).await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+----------+----------+----------+----------+",
+            "| Column 0 | Column 1 | Column 2 | Column 3 |",
+            "+----------+----------+----------+----------+",
+            "| 2        | 'b'      | []       | 20       |",
+            "| 3        | 'c'      | [3, 3]   | 30       |",
+            "+----------+----------+----------+----------+",
+        ];
+        assert_blocks_sorted_eq(expected, blocks.as_slice());
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_combined_bloom_runtime_filters() -> Result<()> {
+    let fixture = setup_native_table("(a int not null, b int not null)", &[
+        "(1, 10), (2, 20), (3, 30)",
+    ])
+    .await?;
+
+    // The keys of the non-nullable int columns are hashed as u32, the same as the hash join build.
+    let build_filter = |keys: &[u32]| -> Result<BinaryFuse8> {
+        let hashes = keys.iter().map(|key| key.fast_hash()).collect::<Vec<_>>();
+        Ok(BinaryFuse8::try_from(&hashes)?)
+    };
+
+    // Neither filter rejects the page alone, but the page is skipped if they are combined with AND.
+    // The disjunctive group set by another join is ANDed with the conjunctive filters.
+    for (disjunctive, conjunctive, expected_rows) in [
+        (false, None, 0),
+        (true, None, 2),
+        (true, Some([2, 3]), 1),
+    ] {
+        let ctx = fixture.new_query_ctx().await?;
+        let table = get_table(&fixture, &ctx, "t").await?;
+        let plan = table.read_plan(ctx.clone(), None, true).await?;
+
+        let filters = vec![
+            ("a".to_string(), build_filter(&[1])?),
+            ("b".to_string(), build_filter(&[20])?),
+        ];
+        let mut runtime_filter = RuntimeFilterInfo::default();
+        if disjunctive {
+            runtime_filter.add_disjunctive_bloom(filters);
+        } else {
+            filters
+                .into_iter()
+                .for_each(|filter| runtime_filter.add_bloom(filter));
+        }
+        ctx.set_runtime_filter((plan.table_index, runtime_filter));
+        if let Some(keys) = conjunctive {
+            let mut runtime_filter = RuntimeFilterInfo::default();
+            runtime_filter.add_bloom(("a".to_string(), build_filter(&keys)?));
+            ctx.set_runtime_filter((plan.table_index, runtime_filter));
+        }
+
+        let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+        let num_rows = blocks.iter().map(|b| b.num_rows()).sum::<usize>();
+        assert_eq!(
+            num_rows, expected_rows,
+            "disjunctive: {disjunctive}, conjunctive: {conjunctive:?}"
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_bloom_runtime_filter_after_prewhere() -> Result<()> {
+    let fixture = setup_native_table("(a int not null, b int not null)", &[
+        "(1, 10), (2, 20), (3, 30)",
+    ])
+    .await?;
+
+    // The prewhere filter keeps the rows the runtime filter rejects, and vice versa.
+    for (with_runtime_filter, expected_rows) in [(false, 2), (true, 0)] {
+        let ctx = fixture.new_query_ctx().await?;
+        let table = get_table(&fixture, &ctx, "t").await?;
+        let filters = parse_to_filters(ctx.clone(), table.clone(), "a > 1")?;
+        let push_downs = PushDownInfo {
+            prewhere: Some(PrewhereInfo {
+                output_columns: Projection::Columns(vec![0, 1]),
+                prewhere_columns: Projection::Columns(vec![0]),
+                remain_columns: Projection::Columns(vec![1]),
+                filter: filters.filter,
+                virtual_columns: None,
+            }),
+            ..Default::default()
+        };
+        let plan = table.read_plan(ctx.clone(), Some(push_downs), true).await?;
+
+        if with_runtime_filter {
+            let hashes = vec![1u32.fast_hash()];
+            let mut runtime_filter = RuntimeFilterInfo::default();
+            runtime_filter.add_bloom(("a".to_string(), BinaryFuse8::try_from(&hashes)?));
+            ctx.set_runtime_filter((plan.table_index, runtime_filter));
+        }
+
+        let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+        let num_rows = blocks.iter().map(|b| b.num_rows()).sum::<usize>();
+        assert_eq!(num_rows, expected_rows);
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_column_mask_and_bloom_runtime_filter() -> Result<()> {
+    let fixture = setup_native_table("(a int not null, b int not null)", &[
+        "(1, 10), (2, 20), (3, 30)",
+    ])
+    .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let table = get_table(&fixture, &ctx, "t").await?;
+    // Column `b` is not a prewhere column, its page is read by the bloom runtime filter.
+    let filters = parse_to_filters(ctx.clone(), table.clone(), "a > 0")?;
+    let push_downs = PushDownInfo {
+        prewhere: Some(PrewhereInfo {
+            output_columns: Projection::Columns(vec![0, 1]),
+            prewhere_columns: Projection::Columns(vec![0]),
+            remain_columns: Projection::Columns(vec![1]),
+            filter: filters.filter,
+            virtual_columns: None,
+        }),
+        ..Default::default()
+    };
+    let plan = table.read_plan(ctx.clone(), Some(push_downs), true).await?;
+
+    // All the values of `b` are masked to 20, which is the only key of the runtime filter.
+    let mask: ColumnMask =
+        Arc::new(|column: &Column| Ok(Int32Type::from_data(vec![20i32; column.len()])));
+    ctx.set_column_masks(plan.table_index, HashMap::from([("b".to_string(), mask)]));
+    let hashes = vec![20u32.fast_hash()];
+    let mut runtime_filter = RuntimeFilterInfo::default();
+    runtime_filter.add_bloom(("b".to_string(), BinaryFuse8::try_from(&hashes)?));
+    ctx.set_runtime_filter((plan.table_index, runtime_filter));
+
+    // The filter probes the masked values, and the masked values are output.
+    let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    let expected = vec![
+        "+----------+----------+",
+        "| Column 0 | Column 1 |",
+        "+----------+----------+",
+        "| 1        | 20       |",
+        "| 2        | 20       |",
+        "| 3        | 20       |",
+        "+----------+----------+",
+    ];
+    assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_split_prewhere() -> Result<()> {
+    let fixture = setup_native_table("(a int not null, b int not null, c int not null)", &[
+        "(1, 10, 100), (2, 20, 200), (3, 30, 300)",
+    ])
+    .await?;
+
+    // The conjunct of `a` is evaluated before reading `b`, the conjunct of `b` only sees
+    // the rows kept by it, and `b` is not read at all if no rows are kept.
+    for (filter, expected) in [
+        ("a > 1 and b < 30", vec![
+            "+----------+----------+----------+",
+            "| Column 0 | Column 1 | Column 2 |",
+            "+----------+----------+----------+",
+            "| 2        | 20       | 200      |",
+            "+----------+----------+----------+",
+        ]),
+        ("a > 1 and b < 30 and a + b > 30", vec![]),
+        ("a > 3 and b < 30", vec![]),
+    ] {
+        for split_prewhere in [0, 1] {
+            let ctx = new_query_ctx(&fixture, &[
+                ("storage_native_split_prewhere", &split_prewhere.to_string()),
+            ])
+            .await?;
+            let table = get_table(&fixture, &ctx, "t").await?;
+            let filters = parse_to_filters(ctx.clone(), table.clone(), filter)?;
+            let push_downs = PushDownInfo {
+                prewhere: Some(PrewhereInfo {
+                    output_columns: Projection::Columns(vec![0, 1, 2]),
+                    prewhere_columns: Projection::Columns(vec![0, 1]),
+                    remain_columns: Projection::Columns(vec![2]),
+                    filter: filters.filter,
+                    virtual_columns: None,
+                }),
+                ..Default::default()
+            };
+            let plan = table.read_plan(ctx.clone(), Some(push_downs), true).await?;
+
+            let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+            let blocks = stream.try_collect::<Vec<_>>().await?;
+            if expected.is_empty() {
+                let num_rows = blocks.iter().map(|b| b.num_rows()).sum::<usize>();
+                assert_eq!(num_rows, 0);
+            } else {
+                assert_blocks_sorted_eq(expected.clone(), blocks.as_slice());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_topk_approx_order() -> Result<()> {
+    let fixture = setup_native_table("(a int not null, b int not null)", &[
+        "(3, 30), (1, 10), (4, 40), (2, 20)",
+    ])
+    .await?;
+
+    // All the rows make the heap of the top-k sorter, they are only reordered.
+    for (approx_order, asc, expected) in [
+        (0, true, vec![3, 1, 4, 2]),
+        (1, true, vec![1, 2, 3, 4]),
+        (1, false, vec![4, 3, 2, 1]),
+    ] {
+        let ctx = new_query_ctx(&fixture, &[
+            ("max_threads", "1"),
+            ("storage_native_topk_approx_order", &approx_order.to_string()),
+        ])
+        .await?;
+        let table = get_table(&fixture, &ctx, "t").await?;
+        let filters = parse_to_filters(ctx.clone(), table.clone(), "b > 0")?;
+        let push_downs = PushDownInfo {
+            prewhere: Some(PrewhereInfo {
+                output_columns: Projection::Columns(vec![0, 1]),
+                prewhere_columns: Projection::Columns(vec![1]),
+                remain_columns: Projection::Columns(vec![0]),
+                filter: filters.filter,
+                virtual_columns: None,
+            }),
+            order_by: vec![(
+                RemoteExpr::ColumnRef {
+                    span: None,
+                    id: "a".to_string(),
+                    data_type: DataType::Number(NumberDataType::Int32),
+                    display_name: "a".to_string(),
+                },
+                asc,
+                false,
+            )],
+            limit: Some(4),
+            ..Default::default()
+        };
+        let plan = table.read_plan(ctx.clone(), Some(push_downs), true).await?;
+
+        let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+        let values = blocks
+            .iter()
+            .flat_map(|block| {
+                let entry = block.get_by_offset(0);
+                (0..block.num_rows()).map(move |i| entry.value.index(i).unwrap().to_owned())
+            })
+            .collect::<Vec<_>>();
+        let expected = expected
+            .into_iter()
+            .map(|v| Scalar::Number(NumberScalar::Int32(v)))
+            .collect::<Vec<_>>();
+        assert_eq!(values, expected);
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_topk_approx_order_nulls() -> Result<()> {
+    let fixture = setup_native_table("(a int null, b int not null)", &[
+        "(3, 30), (null, 0), (1, 10), (2, 20)",
+    ])
+    .await?;
+
+    // The NULLs are placed as the order by of the query, whatever the direction is.
+    for (asc, nulls_first, expected) in [
+        (true, true, vec![None, Some(1), Some(2), Some(3)]),
+        (true, false, vec![Some(1), Some(2), Some(3), None]),
+        (false, true, vec![None, Some(3), Some(2), Some(1)]),
+        (false, false, vec![Some(3), Some(2), Some(1), None]),
+    ] {
+        let ctx = new_query_ctx(&fixture, &[
+            ("max_threads", "1"),
+            ("storage_native_topk_approx_order", "1"),
+        ])
+        .await?;
+        let table = get_table(&fixture, &ctx, "t").await?;
+        let filters = parse_to_filters(ctx.clone(), table.clone(), "b >= 0")?;
+        let push_downs = PushDownInfo {
+            prewhere: Some(PrewhereInfo {
+                output_columns: Projection::Columns(vec![0, 1]),
+                prewhere_columns: Projection::Columns(vec![1]),
+                remain_columns: Projection::Columns(vec![0]),
+                filter: filters.filter,
+                virtual_columns: None,
+            }),
+            order_by: vec![(
+                RemoteExpr::ColumnRef {
+                    span: None,
+                    id: "a".to_string(),
+                    data_type: DataType::Nullable(Box::new(DataType::Number(
+                        NumberDataType::Int32,
+                    ))),
+                    display_name: "a".to_string(),
+                },
+                asc,
+                nulls_first,
+            )],
+            limit: Some(4),
+            ..Default::default()
+        };
+        let plan = table.read_plan(ctx.clone(), Some(push_downs), true).await?;
+
+        let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+        let values = blocks
+            .iter()
+            .flat_map(|block| {
+                let entry = block.get_by_offset(0);
+                (0..block.num_rows()).map(move |i| entry.value.index(i).unwrap().to_owned())
+            })
+            .collect::<Vec<_>>();
+        let expected = expected
+            .into_iter()
+            .map(|v| v.map_or(Scalar::Null, |v| Scalar::Number(NumberScalar::Int32(v))))
+            .collect::<Vec<_>>();
+        assert_eq!(values, expected, "asc: {asc}, nulls_first: {nulls_first}");
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_expression_runtime_filter_keys() -> Result<()> {
+    let fixture = setup_native_table("(a int not null)", &["(1), (2)", "(3), (4)"]).await?;
+
+    let build_filter = |keys: &[u32]| -> Result<BinaryFuse8> {
+        let hashes = keys.iter().map(|key| key.fast_hash()).collect::<Vec<_>>();
+        Ok(BinaryFuse8::try_from(&hashes)?)
+    };
+    // The filter of the expression-derived probe key is ignored by every part.
+    for (with_column_key, expected_rows) in [(false, 4), (true, 1)] {
+        let ctx = new_query_ctx(&fixture, &[("max_threads", "1")]).await?;
+        let table = get_table(&fixture, &ctx, "t").await?;
+        let plan = table.read_plan(ctx.clone(), None, true).await?;
+
+        let mut runtime_filter = RuntimeFilterInfo::default();
+        runtime_filter.add_bloom(("a + 1".to_string(), build_filter(&[100])?));
+        if with_column_key {
+            runtime_filter.add_bloom(("a".to_string(), build_filter(&[3])?));
+        }
+        ctx.set_runtime_filter((plan.table_index, runtime_filter));
+
+        let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+        let num_rows = blocks.iter().map(|b| b.num_rows()).sum::<usize>();
+        assert_eq!(num_rows, expected_rows);
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_bloom_index_probe() -> Result<()> {
+    // The value 2 is in the range of both parts, they are not pruned by the min/max
+    // statistics, but the bloom filter index of the first part has no match.
+    let fixture = setup_native_table("(a int not null)", &["(1), (3)", "(2), (4)"]).await?;
+
+    for (bloom_index_probe, expected_rows) in [(true, 2), (false, 4)] {
+        let ctx = new_query_ctx(&fixture, &[
+            ("enable_runtime_filter_bloom_index_probe", &(bloom_index_probe as u64).to_string()),
+        ])
+        .await?;
+        let table = get_table(&fixture, &ctx, "t").await?;
+        let plan = table.read_plan(ctx.clone(), None, true).await?;
+
+        let inlist = check_function(
+            None,
+            "contains",
+            &[],
+            &[
+                Expr::Constant {
+                    span: None,
+                    scalar: Scalar::Array(Int32Type::from_data(vec![2])),
+                    data_type: DataType::Array(Box::new(DataType::Number(NumberDataType::Int32))),
+                },
+                Expr::ColumnRef {
+                    span: None,
+                    id: "a".to_string(),
+                    data_type: DataType::Number(NumberDataType::Int32),
+                    display_name: "a".to_string(),
+                },
+            ],
+            &BUILTIN_FUNCTIONS,
+        )?;
+        let mut runtime_filter = RuntimeFilterInfo::default();
+        runtime_filter.add_inlist(inlist);
+        ctx.set_runtime_filter((plan.table_index, runtime_filter));
+
+        let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+        let num_rows = blocks.iter().map(|b| b.num_rows()).sum::<usize>();
+        assert_eq!(num_rows, expected_rows);
+        // No pages of the skipped part are decoded.
+        assert_eq!(ctx.get_scan_progress_value().rows, expected_rows);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_order_bloom_runtime_filters() -> Result<()> {
+    let build_filter = |num_keys: u64| -> Result<BinaryFuse8> {
+        let hashes = (0..num_keys).collect::<Vec<_>>();
+        Ok(BinaryFuse8::try_from(&hashes)?)
+    };
+
+    // The filter of fewer keys is more selective, it's applied first.
+    let mut filters = vec![(0, build_filter(10000)?), (1, build_filter(10)?)];
+    order_bloom_runtime_filters(&mut filters, &[]);
+    assert_eq!(filters.iter().map(|f| f.0).collect::<Vec<_>>(), vec![1, 0]);
+
+    // The filter on the column already read is applied first, as it needs no more reads.
+    let mut filters = vec![(0, build_filter(10000)?), (1, build_filter(10)?)];
+    order_bloom_runtime_filters(&mut filters, &[0]);
+    assert_eq!(filters.iter().map(|f| f.0).collect::<Vec<_>>(), vec![0, 1]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_limit() -> Result<()> {
+    let fixture = setup_native_table("(a int)", &["(1), (2)", "(3), (4)", "(5), (6)"]).await?;
+
+    for (limit, expected_rows) in [(None, 6), (Some(2), 2), (Some(3), 4)] {
+        let ctx = new_query_ctx(&fixture, &[("max_threads", "1")]).await?;
+        let table = get_table(&fixture, &ctx, "t").await?;
+        // Push down the limit after the parts are pruned, so that all the parts are
+        // scheduled and the scan itself has to stop reading.
+        let mut plan = table.read_plan(ctx.clone(), None, true).await?;
+        assert_eq!(plan.parts.len(), 3);
+        plan.push_downs = Some(PushDownInfo {
+            limit,
+            ..Default::default()
+        });
+
+        let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+        let num_rows = blocks.iter().map(|b| b.num_rows()).sum::<usize>();
+        assert_eq!(num_rows, expected_rows);
+        // The remaining parts are not decoded once the limit is reached.
+        assert_eq!(ctx.get_scan_progress_value().rows, expected_rows);
+        if limit.is_some() {
+            assert_eq!(ctx.get_limited_scan_rows(plan.table_index), expected_rows);
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_limit_within_part() -> Result<()> {
+    // Two rows per page, so the only block has three pages.
+    let fixture = setup_native_table("(a int) row_per_page = 2", &[
+        "(1), (2), (3), (4), (5), (6)",
+    ])
+    .await?;
+
+    for (limit, expected_rows, expected_pages) in [(None, 6, 3), (Some(1), 2, 1), (Some(3), 4, 2)] {
+        let ctx = new_query_ctx(&fixture, &[
+            ("max_threads", "1"),
+            ("storage_native_part_profile", "1"),
+        ])
+        .await?;
+        let table = get_table(&fixture, &ctx, "t").await?;
+        let mut plan = table.read_plan(ctx.clone(), None, true).await?;
+        assert_eq!(plan.parts.len(), 1);
+        plan.push_downs = Some(PushDownInfo {
+            limit,
+            ..Default::default()
+        });
+
+        let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+        let num_rows = blocks.iter().map(|b| b.num_rows()).sum::<usize>();
+        assert_eq!(num_rows, expected_rows);
+        assert_eq!(ctx.get_scan_progress_value().rows, expected_rows);
+        // The remaining pages of the part are not decoded once the limit is reached.
+        let profiles = ctx.get_scan_part_profiles(plan.table_index);
+        assert_eq!(profiles.len(), 1);
+        assert_eq!(profiles[0].pages_read, expected_pages);
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_lineage_meta() -> Result<()> {
+    let fixture = setup_native_table("(a int)", &["(1), (2)", "(3), (4), (5)"]).await?;
+
+    let ctx = new_query_ctx(&fixture, &[("storage_native_lineage_meta", "1")]).await?;
+    let table = get_table(&fixture, &ctx, "t").await?;
+    let plan = table.read_plan(ctx.clone(), None, true).await?;
+    let mut parts = plan
+        .parts
+        .partitions
+        .iter()
+        .map(|part| {
+            let part = FusePartInfo::from_part(part)?;
+            let block_meta_index = part.block_meta_index.as_ref();
+            Ok((
+                part.location.clone(),
+                block_meta_index.map(|index| index.segment_idx),
+                block_meta_index.map(|index| index.block_idx),
+                part.nums_rows,
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    parts.sort();
+
+    let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    let mut lineages = blocks
+        .iter()
+        .map(|block| {
+            let meta = block.get_meta().expect("lineage meta should be attached");
+            let lineage = LineageMeta::from_meta(meta)?;
+            assert!(lineage.inner.is_none());
+            Ok((
+                lineage.part_location.clone(),
+                lineage.segment_idx,
+                lineage.block_idx,
+                block.num_rows(),
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    lineages.sort();
+    assert_eq!(lineages, parts);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_column_masks() -> Result<()> {
+    let fixture = setup_native_table("(a int, b string)", &[
+        "(1, 'alice'), (2, 'bob'), (3, null)",
+    ])
+    .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let table = get_table(&fixture, &ctx, "t").await?;
+    let plan = table.read_plan(ctx.clone(), None, true).await?;
+
+    // Redact the non-null values of column `b`, keeping the nulls as they are.
+    let redact: ColumnMask = Arc::new(|column: &Column| {
+        let redacted = |len: usize| StringType::from_data(vec!["***"; len]);
+        Ok(match column {
+            Column::Nullable(nullable) => Column::Nullable(Box::new(NullableColumn {
+                column: redacted(nullable.len()),
+                validity: nullable.validity.clone(),
+            })),
+            column => redacted(column.len()),
+        })
+    });
+    ctx.set_column_masks(plan.table_index, HashMap::from([("b".to_string(), redact)]));
+
+    let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    let expected = vec![
+        "+----------+----------+",
+        "| Column 0 | Column 1 |",
+        "+----------+----------+",
+        "| 1        | '***'    |",
+        "| 2        | '***'    |",
+        "| 3        | NULL     |",
+        "+----------+----------+",
+    ];
+    assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    // A mask changing the number of rows is rejected.
+    let truncate: ColumnMask = Arc::new(|column: &Column| Ok(column.slice(0..0)));
+    ctx.set_column_masks(plan.table_index, HashMap::from([("a".to_string(), truncate)]));
+    let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+    let err = stream.try_collect::<Vec<_>>().await.unwrap_err();
+    assert_eq!(err.code(), ErrorCode::INTERNAL);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_virtual_column_error() -> Result<()> {
+    let fixture = setup_native_table("(a int, v variant)", &[
+        r#"(1, '{"k":1}'), (2, '{"k":2}')"#,
+    ])
+    .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let table = get_table(&fixture, &ctx, "t").await?;
+    let mut plan = table.read_plan(ctx.clone(), None, true).await?;
+
+    // The malformed key paths fail the extraction of every row.
+    let virtual_column = VirtualColumnInfo {
+        source_name: "v".to_string(),
+        name: "v['k']".to_string(),
+        key_paths: Scalar::String("{k".as_bytes().to_vec()),
+        data_type: Box::new(TableDataType::Nullable(Box::new(TableDataType::Variant))),
+    };
+    let mut fields = plan.output_schema.fields().clone();
+    fields.push(TableField::new(&virtual_column.name, *virtual_column.data_type.clone()));
+    plan.output_schema = Arc::new(TableSchema::new(fields));
+    plan.push_downs = Some(PushDownInfo {
+        virtual_columns: Some(vec![virtual_column]),
+        ..Default::default()
+    });
+
+    // Fail fast by default, the error tells the virtual column and its source column.
+    let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await;
+    let err = result.unwrap_err();
+    assert!(err.message().contains("virtual column v['k']"));
+    assert!(err.message().contains("source column v"));
+
+    // Fill the failed rows with NULL of the virtual column type.
+    let ctx = new_query_ctx(&fixture, &[("storage_native_virtual_column_error", "null")]).await?;
+    let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    for block in blocks.iter() {
+        let entry = block.get_by_offset(2);
+        assert_eq!(entry.data_type, DataType::Variant.wrap_nullable());
+    }
+    let expected = vec![
+        "+----------+----------+----------+",
+        "| Column 0 | Column 1 | Column 2 |",
+        "+----------+----------+----------+",
+        "| 1        | {\"k\":1}  | NULL     |",
+        "| 2        | {\"k\":2}  | NULL     |",
+        "+----------+----------+----------+",
+    ];
+    assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_default_virtual_column_source() -> Result<()> {
+    let fixture = setup_native_table("(a int)", &["(1), (2)"]).await?;
+    let db = fixture.default_db_name();
+
+    // The existing block has no data of `v`, it's read as the default value.
+    let qry = format!(r#"alter table {db}.t add column v variant default '{{"k":1}}'"#);
+    fixture.execute_command(&qry).await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let table = get_table(&fixture, &ctx, "t").await?;
+    let push_downs = PushDownInfo {
+        projection: Some(Projection::Columns(vec![1])),
+        ..Default::default()
+    };
+    let mut plan = table.read_plan(ctx.clone(), Some(push_downs), true).await?;
+
+    let virtual_column = VirtualColumnInfo {
+        source_name: "v".to_string(),
+        name: "v['k']".to_string(),
+        key_paths: Scalar::String("{k}".as_bytes().to_vec()),
+        data_type: Box::new(TableDataType::Nullable(Box::new(TableDataType::Variant))),
+    };
+    let mut fields = plan.output_schema.fields().clone();
+    fields.push(TableField::new(&virtual_column.name, *virtual_column.data_type.clone()));
+    plan.output_schema = Arc::new(TableSchema::new(fields));
+    plan.push_downs = Some(PushDownInfo {
+        projection: Some(Projection::Columns(vec![1])),
+        virtual_columns: Some(vec![virtual_column]),
+        ..Default::default()
+    });
+
+    // The virtual column is extracted from the default value instead of being NULL.
+    let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    let expected = vec![
+        "+----------+----------+",
+        "| Column 0 | Column 1 |",
+        "+----------+----------+",
+        "| {\"k\":1}  | 1        |",
+        "| {\"k\":1}  | 1        |",
+        "+----------+----------+",
+    ];
+    assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_virtual_columns_after_prewhere() -> Result<()> {
+    let fixture = setup_native_table("(a int, v variant)", &[
+        r#"(1, '{"k":1}'), (2, '{"k":2}'), (3, '{"k":3}')"#,
+    ])
+    .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let table = get_table(&fixture, &ctx, "t").await?;
+    let filters = parse_to_filters(ctx.clone(), table.clone(), "a = 2")?;
+    let mut plan = table.read_plan(ctx.clone(), None, true).await?;
+
+    let virtual_column = VirtualColumnInfo {
+        source_name: "v".to_string(),
+        name: "v['k']".to_string(),
+        key_paths: Scalar::String("{k}".as_bytes().to_vec()),
+        data_type: Box::new(TableDataType::Nullable(Box::new(TableDataType::Variant))),
+    };
+    let mut fields = plan.output_schema.fields().clone();
+    fields.push(TableField::new(&virtual_column.name, *virtual_column.data_type.clone()));
+    plan.output_schema = Arc::new(TableSchema::new(fields));
+
+    // The virtual column is generated on the rows kept by the prewhere filter only,
+    // it must line up with the other columns whether the block is filtered or not.
+    for (prewhere, expected) in [
+        (None, vec![
+            "+----------+----------+----------+",
+            "| Column 0 | Column 1 | Column 2 |",
+            "+----------+----------+----------+",
+            "| 1        | {\"k\":1}  | 1        |",
+            "| 2        | {\"k\":2}  | 2        |",
+            "| 3        | {\"k\":3}  | 3        |",
+            "+----------+----------+----------+",
+        ]),
+        (
+            Some(PrewhereInfo {
+                output_columns: Projection::Columns(vec![0, 1]),
+                prewhere_columns: Projection::Columns(vec![0]),
+                remain_columns: Projection::Columns(vec![1]),
+                filter: filters.filter.clone(),
+                virtual_columns: None,
+            }),
+            vec![
+                "+----------+----------+----------+",
+                "| Column 0 | Column 1 | Column 2 |",
+                "+----------+----------+----------+",
+                "| 2        | {\"k\":2}  | 2        |",
+                "+----------+----------+----------+",
+            ],
+        ),
+    ] {
+        plan.push_downs = Some(PushDownInfo {
+            virtual_columns: Some(vec![virtual_column.clone()]),
+            prewhere,
+            ..Default::default()
+        });
+        let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+        assert_blocks_sorted_eq(expected, blocks.as_slice());
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_decode_errors() -> Result<()> {
+    let fixture = setup_native_table("(a int not null, b string)", &[
+        "(1, 'x'), (2, 'y')",
+        "(3, 'z')",
+    ])
+    .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let table = get_table(&fixture, &ctx, "t").await?;
+    let plan = table.read_plan(ctx.clone(), None, true).await?;
+    assert_eq!(plan.parts.partitions.len(), 2);
+
+    // Corrupt the pages of column `a` in the block of the second insert.
+    let mut corrupted = None;
+    for part in &plan.parts.partitions {
+        let part = FusePartInfo::from_part(part)?;
+        if part.nums_rows == 1 {
+            corrupted = Some(part.clone());
+        }
+    }
+    let corrupted = corrupted.unwrap();
+    let native_meta = corrupted.columns_meta[&0].as_native().unwrap();
+    let start = native_meta.offset as usize;
+    let end = start + native_meta.pages.iter().map(|p| p.length).sum::<u64>() as usize;
+    let operator = FuseTable::try_from_table(table.as_ref())?.get_operator();
+    let mut data = operator.read(&corrupted.location).await?;
+    data[start..end].fill(0xFF);
+    operator.write(&corrupted.location, data).await?;
+
+    // The scan is aborted by default.
+    let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+    assert!(stream.try_collect::<Vec<_>>().await.is_err());
+
+    // The error is collected, and the rows of the intact part flow through.
+    let ctx = new_query_ctx(&fixture, &[("storage_native_decode_error", "collect")]).await?;
+    let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    let expected = vec![
+        "+----------+----------+",
+        "| Column 0 | Column 1 |",
+        "+----------+----------+",
+        "| 1        | 'x'      |",
+        "| 2        | 'y'      |",
+        "+----------+----------+",
+    ];
+    assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    let errors = ctx.get_scan_decode_errors(plan.table_index);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].part_location, corrupted.location);
+    assert_eq!(errors[0].column, "a");
+    assert_eq!(errors[0].page, 0);
+    assert!(!errors[0].message.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_decode_errors_in_bloom_runtime_filter() -> Result<()> {
+    let fixture = setup_native_table("(a int not null, b int not null)", &[
+        "(1, 10), (2, 20)",
+        "(3, 30)",
+    ])
+    .await?;
+
+    let mut corrupted: Option<FusePartInfo> = None;
+    for collect in [false, true] {
+        let ctx = fixture.new_query_ctx().await?;
+        if collect {
+            ctx.get_settings().set_setting(
+                "storage_native_decode_error".to_string(),
+                "collect".to_string(),
+            )?;
+        }
+        let table = get_table(&fixture, &ctx, "t").await?;
+        // Column `b` is not a prewhere column, its page is read by the bloom runtime filter.
+        let filters = parse_to_filters(ctx.clone(), table.clone(), "a > 0")?;
+        let push_downs = PushDownInfo {
+            prewhere: Some(PrewhereInfo {
+                output_columns: Projection::Columns(vec![0, 1]),
+                prewhere_columns: Projection::Columns(vec![0]),
+                remain_columns: Projection::Columns(vec![1]),
+                filter: filters.filter,
+                virtual_columns: None,
+            }),
+            ..Default::default()
+        };
+        let plan = table.read_plan(ctx.clone(), Some(push_downs), true).await?;
+        assert_eq!(plan.parts.partitions.len(), 2);
+        let hashes = [10u32, 20, 30]
+            .iter()
+            .map(|key| key.fast_hash())
+            .collect::<Vec<_>>();
+        let mut runtime_filter = RuntimeFilterInfo::default();
+        runtime_filter.add_bloom(("b".to_string(), BinaryFuse8::try_from(&hashes)?));
+        ctx.set_runtime_filter((plan.table_index, runtime_filter));
+
+        // Corrupt the pages of column `b` in the block of the second insert.
+        if corrupted.is_none() {
+            for part in &plan.parts.partitions {
+                let part = FusePartInfo::from_part(part)?;
+                if part.nums_rows == 1 {
+                    corrupted = Some(part.clone());
+                }
+            }
+            let part = corrupted.as_ref().unwrap();
+            let native_meta = part.columns_meta[&1].as_native().unwrap();
+            let start = native_meta.offset as usize;
+            let end = start + native_meta.pages.iter().map(|p| p.length).sum::<u64>() as usize;
+            let operator = FuseTable::try_from_table(table.as_ref())?.get_operator();
+            let mut data = operator.read(&part.location).await?;
+            data[start..end].fill(0xFF);
+            operator.write(&part.location, data).await?;
+        }
+
+        let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+        if !collect {
+            // The scan is aborted by default instead of panicking.
+            assert!(stream.try_collect::<Vec<_>>().await.is_err());
+            continue;
+        }
+
+        // The error is collected, and the rows of the intact part flow through.
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+----------+----------+",
+            "| Column 0 | Column 1 |",
+            "+----------+----------+",
+            "| 1        | 10       |",
+            "| 2        | 20       |",
+            "+----------+----------+",
+        ];
+        assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+        let errors = ctx.get_scan_decode_errors(plan.table_index);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].part_location, corrupted.as_ref().unwrap().location);
+        assert_eq!(errors[0].column, "b");
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_max_parts_per_tick() -> Result<()> {
+    let fixture = setup_native_table("(a int)", &[]).await?;
+    let db = fixture.default_db_name();
+
+    for i in 0..5 {
+        let qry = format!("insert into {db}.t values({i})");
+        fixture.execute_command(&qry).await?;
+    }
+
+    // Keep the blocks pending, so no output is pushed between the parts.
+    let ctx = new_query_ctx(&fixture, &[
+        ("storage_native_max_parts_per_tick", "2"),
+        ("storage_native_output_block_rows", "1000"),
+    ])
+    .await?;
+    let table = get_table(&fixture, &ctx, "t").await?;
+    let push_downs = PushDownInfo {
+        projection: Some(Projection::Columns(vec![])),
+        ..Default::default()
+    };
+    let plan = table.read_plan(ctx.clone(), Some(push_downs), true).await?;
+    assert_eq!(plan.parts.partitions.len(), 5);
+
+    // Feed the parts of the empty projection to the transform directly.
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let block_reader = fuse_table.create_block_reader(
+        ctx.clone(),
+        Projection::Columns(vec![]),
+        false,
+        false,
+        false,
+    )?;
+    let input = InputPort::create();
+    let output = OutputPort::create();
+    let upstream_output = OutputPort::create();
+    let downstream_input = InputPort::create();
+    unsafe {
+        connect(&input, &upstream_output);
+        connect(&downstream_input, &output);
+    }
+    let processor = NativeDeserializeDataTransform::create(
+        ctx.clone(),
+        block_reader,
+        &plan,
+        None,
+        input,
+        output,
+        Arc::new(None),
+        Arc::new(None),
+        false,
+        0,
+        DEFAULT_ROW_PER_PAGE,
+    )?;
+    let parts = plan.parts.partitions.clone();
+    let data = parts
+        .iter()
+        .map(|_| NativeDataSource::Normal(NativeSourceData::new()))
+        .collect();
+    upstream_output.push_data(Ok(DataBlock::empty_with_meta(
+        DataSourceWithMeta::create(parts, data),
+    )));
+    upstream_output.finish();
+    downstream_input.set_need_data();
+
+    let mut events = vec![];
+    loop {
+        let event = unsafe { processor.event(EventCause::Other)? };
+        events.push(format!("{:?}", event));
+        match event {
+            Event::Sync => unsafe { processor.process()? },
+            Event::Async => unsafe { processor.async_process().await? },
+            _ => break,
+        }
+    }
+    // The first `Sync` pulls the parts, and the last one flushes the pending blocks.
+    assert_eq!(events, vec![
+        "Sync",
+        "Sync",
+        "Async",
+        "Sync",
+        "Sync",
+        "Async",
+        "Sync",
+        "Sync",
+        "NeedConsume",
+    ]);
+    let block = downstream_input.pull_data().unwrap()?;
+    assert_eq!(block.num_rows(), 5);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_raw() -> Result<()> {
+    let fixture = setup_native_table("(a int not null, b int not null)", &[
+        "(1, 10), (2, 20), (3, 30)",
+    ])
+    .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let table = get_table(&fixture, &ctx, "t").await?;
+    let filters = parse_to_filters(ctx.clone(), table.clone(), "a > 1")?;
+    let push_downs = PushDownInfo {
+        prewhere: Some(PrewhereInfo {
+            output_columns: Projection::Columns(vec![0, 1]),
+            prewhere_columns: Projection::Columns(vec![0]),
+            remain_columns: Projection::Columns(vec![1]),
+            filter: filters.filter,
+            virtual_columns: None,
+        }),
+        limit: Some(1),
+        ..Default::default()
+    };
+    let plan = table.read_plan(ctx.clone(), Some(push_downs), true).await?;
+    let hashes = vec![2u32.fast_hash()];
+    let mut runtime_filter = RuntimeFilterInfo::default();
+    runtime_filter.add_bloom(("a".to_string(), BinaryFuse8::try_from(&hashes)?));
+    ctx.set_runtime_filter((plan.table_index, runtime_filter));
+
+    // The raw transform ignores the prewhere filter, the limit and the runtime filter,
+    // but still fills the meta of the internal columns.
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let block_reader = fuse_table.create_block_reader(
+        ctx.clone(),
+        Projection::Columns(vec![0, 1]),
+        true,
+        false,
+        false,
+    )?;
+    let input = InputPort::create();
+    let output = OutputPort::create();
+    let upstream_output = OutputPort::create();
+    let downstream_input = InputPort::create();
+    unsafe {
+        connect(&input, &upstream_output);
+        connect(&downstream_input, &output);
+    }
+    let processor = NativeDeserializeDataTransform::create_raw(
+        ctx.clone(),
+        block_reader.clone(),
+        &plan,
+        input,
+        output,
+    )?;
+    let parts = plan.parts.partitions.clone();
+    let data = parts
+        .iter()
+        .map(|part| {
+            let data = block_reader.sync_read_native_columns_data(part, &None)?;
+            Ok(NativeDataSource::Normal(data))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    upstream_output.push_data(Ok(DataBlock::empty_with_meta(
+        DataSourceWithMeta::create(parts, data),
+    )));
+    upstream_output.finish();
+    downstream_input.set_need_data();
+
+    let mut blocks = vec![];
+    loop {
+        match unsafe { processor.event(EventCause::Other)? } {
+            Event::Sync => unsafe { processor.process()? },
+            Event::Async => unsafe { processor.async_process().await? },
+            Event::NeedConsume => {
+                blocks.push(downstream_input.pull_data().unwrap()?);
+                downstream_input.set_need_data();
+            }
+            _ => break,
+        }
+    }
+    for block in &blocks {
+        let meta = InternalColumnMeta::from_meta(block.get_meta().unwrap())?;
+        assert_eq!(meta.offsets, None);
+    }
+
+    let expected = vec![
+        "+----------+----------+",
+        "| Column 0 | Column 1 |",
+        "+----------+----------+",
+        "| 1        | 10       |",
+        "| 2        | 20       |",
+        "| 3        | 30       |",
+        "+----------+----------+",
+    ];
+    assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_native_read_with_part_profile() -> Result<()> {
+    let fixture = setup_native_table("(a int not null, b int not null)", &[
+        "(1, 10), (2, 20)",
+        "(3, 30), (4, 40)",
+    ])
+    .await?;
+
+    for part_profile in [true, false] {
+        let ctx = new_query_ctx(&fixture, &[
+            ("storage_native_part_profile", &(part_profile as u64).to_string()),
+        ])
+        .await?;
+        let table = get_table(&fixture, &ctx, "t").await?;
+        let filters = parse_to_filters(ctx.clone(), table.clone(), "a > 2")?;
+        let push_downs = PushDownInfo {
+            prewhere: Some(PrewhereInfo {
+                output_columns: Projection::Columns(vec![0, 1]),
+                prewhere_columns: Projection::Columns(vec![0]),
+                remain_columns: Projection::Columns(vec![1]),
+                filter: filters.filter,
+                virtual_columns: None,
+            }),
+            ..Default::default()
+        };
+        let plan = table.read_plan(ctx.clone(), Some(push_downs), true).await?;
+
+        let stream = table.read_data_block_stream(ctx.clone(), &plan).await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+        let num_rows = blocks.iter().map(|b| b.num_rows()).sum::<usize>();
+        assert_eq!(num_rows, 2);
+
+        let mut profiles = ctx.get_scan_part_profiles(plan.table_index);
+        if !part_profile {
+            assert!(profiles.is_empty());
+            continue;
+        }
+        profiles.sort_by_key(|profile| profile.pages_read);
+        assert_eq!(profiles.len(), 2);
+        assert_ne!(profiles[0].part_location, profiles[1].part_location);
+        // The page of the first part is rejected by the prewhere filter, the page of `b`
+        // is skipped without reading.
+        let pages = profiles
+            .iter()
+            .map(|profile| (profile.pages_read, profile.pages_skipped))
+            .collect::<Vec<_>>();
+        assert_eq!(pages, vec![(1, 1), (2, 0)]);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_fuse_part_page_row_offset() -> Result<()> {
+    let native_meta = |num_values: &[u64]| {
+        ColumnMeta::Native(NativeColumnMeta {
+            offset: 0,
+            pages: num_values
+                .iter()
+                .map(|num_values| PageMeta {
+                    length: 0,
+                    num_values: *num_values,
+                })
+                .collect(),
+        })
+    };
+    // The pages are not uniform, and the leaves of the nested column hold more values than rows.
+    let columns_meta = HashMap::from([(0, native_meta(&[3, 2, 4])), (1, native_meta(&[5, 2, 6]))]);
+    let part = FusePartInfo::create(
+        "block".to_string(),
+        9,
+        columns_meta,
+        None,
+        Compression::Lz4Raw,
+        None,
+        None,
+        None,
+        None,
+        0,
+    );
+    let fuse_part = FusePartInfo::from_part(&part)?;
+
+    // Only the column not nested gives the rows, the leaf of the column 1 counts the elements.
+    assert_eq!(fuse_part.page_rows(1), Some(vec![5, 2, 6]));
+    assert_eq!(fuse_part.page_rows(2), None);
+    let page_rows = fuse_part.page_rows(0);
+    assert_eq!(page_rows, Some(vec![3, 2, 4]));
+    let page_rows = page_rows.as_deref();
+    let offsets = (0..=3)
+        .map(|page| fuse_part.page_row_offset(page_rows, page))
+        .collect::<Vec<_>>();
+    assert_eq!(offsets, vec![0, 3, 5, 9]);
+    let pages = [0, 2, 3, 4, 5, 8, 9]
+        .iter()
+        .map(|offset| fuse_part.page_of_row_offset(page_rows, *offset))
+        .collect::<Vec<_>>();
+    assert_eq!(pages, vec![0, 0, 1, 1, 2, 2, 3]);
+
+    // It falls back to the page size if the page rows are unknown.
+    let offsets = (0..=3)
+        .map(|page| fuse_part.page_row_offset(None, page))
+        .collect::<Vec<_>>();
+    assert_eq!(offsets, vec![0, 9, 9, 9]);
+
+    Ok(())
+}
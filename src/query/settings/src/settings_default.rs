@@ -215,6 +215,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: None,
                 }),
+                ("max_array_literal_length", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(65536),
+                    desc: "Sets the maximum number of elements in an array literal. Setting it to 0 means no limit.",
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
                 ("unquoted_ident_case_sensitive", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Determines whether Databend treats unquoted identifiers as case-sensitive.",
@@ -264,12 +270,30 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_runtime_filter_empty_build_skip", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables skipping the scan of the probe side table when the build side of the join is empty.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("enable_runtime_filter_bloom_index_probe", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables probing the bloom filter index of the probe side blocks with the runtime filters, to skip the blocks without any match before reading them.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("max_execute_time_in_seconds", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Sets the maximum query execution time in seconds. Setting it to 0 means no limit.",
                     mode: SettingMode::Both,
                     range: None,
                 }),
+                ("max_storage_scan_time_in_milliseconds", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum time in milliseconds that a storage scan can take to decode data. Setting it to 0 means no limit.",
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
                 ("collation", DefaultSettingValue {
                     value: UserSettingValue::String("binary".to_owned()),
                     desc: "Sets the character collation. Available values include \"binary\" and \"utf8\".",
@@ -294,6 +318,102 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: None,
                 }),
+                ("storage_native_output_block_rows", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the number of rows of the blocks emitted by the native storage reader, small filtered blocks are concatenated until the number is reached. Setting it to 0 disables the concatenation.",
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
+                ("storage_native_max_output_block_rows", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum number of rows of the blocks emitted by the native storage reader, larger blocks are split into slices of at most this many rows. Setting it to 0 disables the splitting.",
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
+                ("storage_native_decode_parallelism", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the number of remain columns of a page the native storage reader decodes concurrently. Setting it to 0 or 1 decodes them one by one.",
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
+                ("storage_native_split_prewhere", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables the native storage reader to evaluate the prewhere filter column by column, the remaining prewhere columns of a page are not read if no rows pass the conjuncts of the columns read.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("storage_native_topk_approx_order", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables the native storage reader to emit the rows of each page sorted by the top-k column, it reduces the work of the downstream sort for the queries with ORDER BY ... LIMIT.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("storage_native_reuse_array_iters", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables reusing the column decoders of the native storage reader across the parts, instead of building them for each part.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("storage_native_force_full_columns", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables materializing the constant columns of the blocks emitted by the native storage reader as full columns.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("storage_native_lineage_meta", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables attaching the lineage of the source part to the blocks emitted by the native storage reader.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("storage_native_part_rows_mismatch", DefaultSettingValue {
+                    value: UserSettingValue::String("error".to_owned()),
+                    desc: "Sets the behavior when the decoded rows of a native part do not match its rows count. Available values include \"error\" and \"warn\", \"warn\" logs a warning and drops the rows past the rows count.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::String(vec!["error", "warn"])),
+                }),
+                ("storage_native_strict_page_count", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables checking that all the columns of a native part run out of pages together, and raising an error if some columns have pages left.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("storage_native_max_parts_per_tick", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum number of parts the native storage reader finishes in a row before yielding to the scheduler, 0 means no limit.",
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
+                ("storage_native_virtual_column_error", DefaultSettingValue {
+                    value: UserSettingValue::String("error".to_owned()),
+                    desc: "Sets the behavior when the native storage reader fails to generate a virtual column. Available values include \"error\" and \"null\", which fills the failed rows with NULL.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::String(vec!["error", "null"])),
+                }),
+                ("storage_native_decode_error", DefaultSettingValue {
+                    value: UserSettingValue::String("error".to_owned()),
+                    desc: "Sets the behavior when the native storage reader fails to decode a page. Available values include \"error\" and \"collect\", which drops the remaining rows of the part and collects the error for inspection.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::String(vec!["error", "collect"])),
+                }),
+                ("storage_native_part_profile", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables collecting the time the native storage reader spent on each part, along with the pages read and skipped, for profiling slow scans.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("storage_native_filter_batch_rows", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the number of rows the native storage reader evaluates the prewhere filter on at a time, the decoded page is split into batches of this size. Setting it to 0 evaluates the whole page at once.",
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
+                ("storage_native_filter_row_per_page", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(131072),
+                    desc: "Sets the number of rows the selection vectors of the native storage reader filters are allocated for, they grow on demand if a page has more rows.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(1..=131072)),
+                }),
                 ("load_file_metadata_expire_hours", DefaultSettingValue {
                     value: UserSettingValue::UInt64(24 * 7),
                     desc: "Sets the hours that the metadata of files you load data from with COPY INTO will expire in.",
@@ -593,6 +713,24 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::String(vec!["rounding", "truncating"])),
                 }),
+                ("enable_strict_array_zip_with", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables returning an error if the arrays of array_zip_with have different lengths, instead of stopping at the shortest array.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("enable_strict_array_zip", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables returning an error if the arrays of array_zip have different lengths, instead of truncating to the shorter array.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("enable_strict_array_set", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables returning an error if the index of array_set is beyond the end of the array, instead of extending the array with NULLs.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("enable_experimental_rbac_check", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "experiment setting disables stage and udf privilege check(disable by default).",
@@ -617,6 +755,18 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("refresh_hook_dry_run", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Logs the refreshes after new data written instead of executing them, for debugging.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("refresh_after_write_max_attempts", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(3),
+                    desc: "Sets the maximum attempts of each refresh after new data written, the transient failures are retried with backoff.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(1..=10)),
+                }),
             ]);
 
             Ok(Arc::new(DefaultSettings {
@@ -198,6 +198,11 @@ impl Settings {
         self.try_get_u64("max_execute_time_in_seconds")
     }
 
+    // Get max_storage_scan_time_in_milliseconds.
+    pub fn get_max_storage_scan_time_in_milliseconds(&self) -> Result<u64> {
+        self.try_get_u64("max_storage_scan_time_in_milliseconds")
+    }
+
     // Get flight client timeout.
     pub fn get_flight_client_timeout(&self) -> Result<u64> {
         self.try_get_u64("flight_client_timeout")
@@ -229,6 +234,10 @@ impl Settings {
         self.try_get_u64("max_inlist_to_or")
     }
 
+    pub fn get_max_array_literal_length(&self) -> Result<u64> {
+        self.try_get_u64("max_array_literal_length")
+    }
+
     pub fn get_unquoted_ident_case_sensitive(&self) -> Result<bool> {
         Ok(self.try_get_u64("unquoted_ident_case_sensitive")? != 0)
     }
@@ -241,6 +250,18 @@ impl Settings {
         self.try_get_u64("max_result_rows")
     }
 
+    pub fn get_enable_strict_array_zip_with(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_strict_array_zip_with")? != 0)
+    }
+
+    pub fn get_enable_strict_array_zip(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_strict_array_zip")? != 0)
+    }
+
+    pub fn get_enable_strict_array_set(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_strict_array_set")? != 0)
+    }
+
     pub fn get_enable_dphyp(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_dphyp")? != 0)
     }
@@ -262,6 +283,14 @@ impl Settings {
         Ok(self.try_get_u64("enable_runtime_filter")? != 0)
     }
 
+    pub fn get_runtime_filter_empty_build_skip(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_runtime_filter_empty_build_skip")? != 0)
+    }
+
+    pub fn get_runtime_filter_bloom_index_probe(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_runtime_filter_bloom_index_probe")? != 0)
+    }
+
     pub fn get_prefer_broadcast_join(&self) -> Result<bool> {
         Ok(self.try_get_u64("prefer_broadcast_join")? != 0)
     }
@@ -290,6 +319,70 @@ impl Settings {
         self.try_get_u64("hive_parquet_chunk_size")
     }
 
+    pub fn get_storage_native_output_block_rows(&self) -> Result<u64> {
+        self.try_get_u64("storage_native_output_block_rows")
+    }
+
+    pub fn get_storage_native_max_output_block_rows(&self) -> Result<u64> {
+        self.try_get_u64("storage_native_max_output_block_rows")
+    }
+
+    pub fn get_storage_native_decode_parallelism(&self) -> Result<u64> {
+        self.try_get_u64("storage_native_decode_parallelism")
+    }
+
+    pub fn get_storage_native_split_prewhere(&self) -> Result<bool> {
+        Ok(self.try_get_u64("storage_native_split_prewhere")? != 0)
+    }
+
+    pub fn get_storage_native_topk_approx_order(&self) -> Result<bool> {
+        Ok(self.try_get_u64("storage_native_topk_approx_order")? != 0)
+    }
+
+    pub fn get_storage_native_reuse_array_iters(&self) -> Result<bool> {
+        Ok(self.try_get_u64("storage_native_reuse_array_iters")? != 0)
+    }
+
+    pub fn get_storage_native_force_full_columns(&self) -> Result<bool> {
+        Ok(self.try_get_u64("storage_native_force_full_columns")? != 0)
+    }
+
+    pub fn get_storage_native_lineage_meta(&self) -> Result<bool> {
+        Ok(self.try_get_u64("storage_native_lineage_meta")? != 0)
+    }
+
+    pub fn get_storage_native_part_rows_mismatch(&self) -> Result<String> {
+        self.try_get_string("storage_native_part_rows_mismatch")
+    }
+
+    pub fn get_storage_native_strict_page_count(&self) -> Result<bool> {
+        Ok(self.try_get_u64("storage_native_strict_page_count")? != 0)
+    }
+
+    pub fn get_storage_native_max_parts_per_tick(&self) -> Result<u64> {
+        self.try_get_u64("storage_native_max_parts_per_tick")
+    }
+
+    pub fn get_storage_native_virtual_column_error(&self) -> Result<String> {
+        self.try_get_string("storage_native_virtual_column_error")
+    }
+
+    pub fn get_storage_native_decode_error(&self) -> Result<String> {
+        self.try_get_string("storage_native_decode_error")
+    }
+
+    pub fn get_storage_native_part_profile(&self) -> Result<bool> {
+        Ok(self.try_get_u64("storage_native_part_profile")? != 0)
+    }
+
+    pub fn get_storage_native_filter_batch_rows(&self) -> Result<u64> {
+        self.try_get_u64("storage_native_filter_batch_rows")
+    }
+
+    pub fn get_storage_native_filter_row_per_page(&self) -> Result<u64> {
+        self.try_get_u64("storage_native_filter_row_per_page")
+    }
+
     pub fn get_load_file_metadata_expire_hours(&self) -> Result<u64> {
         self.try_get_u64("load_file_metadata_expire_hours")
     }
@@ -536,4 +629,12 @@ impl Settings {
     pub fn set_enable_refresh_virtual_column_after_write(&self, val: bool) -> Result<()> {
         self.try_set_u64("enable_refresh_virtual_column_after_write", u64::from(val))
     }
+
+    pub fn get_refresh_hook_dry_run(&self) -> Result<bool> {
+        Ok(self.try_get_u64("refresh_hook_dry_run")? != 0)
+    }
+
+    pub fn get_refresh_after_write_max_attempts(&self) -> Result<u64> {
+        self.try_get_u64("refresh_after_write_max_attempts")
+    }
 }
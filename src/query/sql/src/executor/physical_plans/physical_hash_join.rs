@@ -31,6 +31,8 @@ use crate::executor::PhysicalPlan;
 use crate::executor::PhysicalPlanBuilder;
 use crate::optimizer::ColumnSet;
 use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::FunctionCall;
 use crate::plans::Join;
 use crate::plans::JoinType;
 use crate::IndexType;
@@ -70,6 +72,9 @@ pub struct HashJoin {
 
     // probe keys for runtime filter
     pub probe_keys_rt: Vec<RemoteExpr<String>>,
+    // The (probe key, build key) pairs of the disjunctive non-equi conditions for runtime
+    // filter, the filters of each condition are combined with OR.
+    pub disjunctive_keys_rt: Vec<Vec<(RemoteExpr<String>, RemoteExpr)>>,
     // Under cluster, mark if the join is broadcast join.
     pub broadcast: bool,
 }
@@ -273,6 +278,19 @@ impl PhysicalPlanBuilder {
             left_join_conditions_rt.push(left_expr_for_runtime_filter.as_remote_expr());
         }
 
+        let mut disjunctive_keys_rt = Vec::new();
+        if join.join_type == JoinType::Inner {
+            for condition in join.non_equi_conditions.iter() {
+                if let Some(keys) = self.disjunctive_keys_for_runtime_filter(
+                    condition,
+                    &probe_schema,
+                    &build_schema,
+                )? {
+                    disjunctive_keys_rt.push(keys);
+                }
+            }
+        }
+
         let mut probe_projections = ColumnSet::new();
         let mut build_projections = ColumnSet::new();
         for column in pre_column_projections.iter() {
@@ -432,6 +450,7 @@ impl PhysicalPlanBuilder {
             build_keys: right_join_conditions,
             probe_keys: left_join_conditions,
             probe_keys_rt: left_join_conditions_rt,
+            disjunctive_keys_rt,
             non_equi_conditions: join
                 .non_equi_conditions
                 .iter()
@@ -454,4 +473,80 @@ impl PhysicalPlanBuilder {
             broadcast: join.broadcast,
         }))
     }
+
+    /// Split the non-equi condition `probe.a = build.x OR probe.b = build.y OR ...` into the
+    /// pairs of the probe and build keys. The probe rows of an inner join matching none of the
+    /// pairs are rejected, so they can be filtered by the runtime filters of the pairs combined
+    /// with OR. Return None if any disjunct is not an equality of a probe and a build column.
+    fn disjunctive_keys_for_runtime_filter(
+        &self,
+        condition: &ScalarExpr,
+        probe_schema: &DataSchemaRef,
+        build_schema: &DataSchemaRef,
+    ) -> Result<Option<Vec<(RemoteExpr<String>, RemoteExpr)>>> {
+        let is_column_of = |column: &BoundColumnRef, schema: &DataSchemaRef| {
+            schema.index_of(&column.column.index.to_string()).is_ok()
+        };
+        let mut disjuncts = vec![condition];
+        let mut keys = Vec::new();
+        while let Some(disjunct) = disjuncts.pop() {
+            let ScalarExpr::FunctionCall(FunctionCall {
+                func_name,
+                arguments,
+                ..
+            }) = disjunct
+            else {
+                return Ok(None);
+            };
+            let (probe, build) = match (func_name.as_str(), arguments.as_slice()) {
+                ("or", [lhs, rhs]) => {
+                    disjuncts.push(rhs);
+                    disjuncts.push(lhs);
+                    continue;
+                }
+                ("eq", [ScalarExpr::BoundColumnRef(lhs), ScalarExpr::BoundColumnRef(rhs)]) => {
+                    if is_column_of(lhs, probe_schema) && is_column_of(rhs, build_schema) {
+                        (lhs, rhs)
+                    } else if is_column_of(rhs, probe_schema) && is_column_of(lhs, build_schema) {
+                        (rhs, lhs)
+                    } else {
+                        return Ok(None);
+                    }
+                }
+                _ => return Ok(None),
+            };
+
+            let probe_key = ScalarExpr::BoundColumnRef(probe.clone())
+                .as_raw_expr()
+                .type_check(&*self.metadata.read())?
+                .project_column_ref(|col| col.column_name.clone());
+            let build_key = ScalarExpr::BoundColumnRef(build.clone())
+                .type_check(build_schema.as_ref())?
+                .project_column_ref(|index| build_schema.index_of(&index.to_string()).unwrap());
+            // Hash the keys of both sides in the same type, the same as the equi conditions.
+            let Some(common_ty) = common_super_type(
+                probe_key.data_type().clone(),
+                build_key.data_type().clone(),
+                &BUILTIN_FUNCTIONS.default_cast_rules,
+            ) else {
+                return Ok(None);
+            };
+            let probe_key = check_cast(
+                probe_key.span(),
+                false,
+                probe_key,
+                &common_ty,
+                &BUILTIN_FUNCTIONS,
+            )?;
+            let build_key = check_cast(
+                build_key.span(),
+                false,
+                build_key,
+                &common_ty,
+                &BUILTIN_FUNCTIONS,
+            )?;
+            keys.push((probe_key.as_remote_expr(), build_key.as_remote_expr()));
+        }
+        Ok(Some(keys))
+    }
 }
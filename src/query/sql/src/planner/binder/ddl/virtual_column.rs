@@ -175,6 +175,7 @@ impl Binder {
                 database,
                 table,
                 segment_locs: None,
+                block_locs: None,
             },
         )))
     }
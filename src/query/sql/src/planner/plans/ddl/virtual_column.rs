@@ -68,6 +68,9 @@ pub struct RefreshVirtualColumnPlan {
     pub database: String,
     pub table: String,
     pub segment_locs: Option<Vec<Location>>,
+    /// The blocks to refresh in `segment_locs`, all the blocks of the segments
+    /// are refreshed if it's `None`.
+    pub block_locs: Option<Vec<String>>,
 }
 
 impl RefreshVirtualColumnPlan {
@@ -53,6 +53,7 @@ use databend_common_expression::types::decimal::DecimalSize;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberScalar;
+use databend_common_expression::Column;
 use databend_common_expression::ColumnIndex;
 use databend_common_expression::ConstantFolder;
 use databend_common_expression::DataField;
@@ -864,7 +865,11 @@ impl<'a> TypeChecker<'a> {
                         // aggregate function
                         Box::new((new_agg_func.into(), data_type))
                     }
-                } else if GENERAL_LAMBDA_FUNCTIONS.contains(&func_name) {
+                } else if GENERAL_LAMBDA_FUNCTIONS.contains(&func_name)
+                    && (lambda.is_some() || !BUILTIN_FUNCTIONS.contains(func_name))
+                {
+                    // Some lambda functions like `array_count` are also scalar functions
+                    // without lambda expression.
                     if lambda.is_none() {
                         return Err(ErrorCode::SemanticError(format!(
                             "function {func_name} must have a lambda expression",
@@ -969,7 +974,6 @@ impl<'a> TypeChecker<'a> {
                     accessor,
                 } = expr
                 {
-                    expr = &**inner_expr;
                     let path = match accessor {
                         MapAccessor::Bracket {
                             key: box Expr::Literal { lit, .. },
@@ -985,17 +989,24 @@ impl<'a> TypeChecker<'a> {
                         }
                         MapAccessor::Colon { key } => Literal::String(key.name.clone()),
                         MapAccessor::DotNumber { key } => Literal::UInt64(*key),
-                        _ => {
-                            return Err(ErrorCode::SemanticError(format!(
-                                "Unsupported accessor: {:?}",
-                                accessor
-                            ))
-                            .set_span(*span));
-                        }
+                        // The other keys, such as `arr[-1]` or `arr[idx]`, are resolved
+                        // as the arguments of the `get` function below.
+                        MapAccessor::Bracket { .. } => break,
                     };
+                    expr = &**inner_expr;
                     paths.push_front((*span, path));
                 }
-                self.resolve_map_access(expr, paths).await?
+                match expr {
+                    Expr::MapAccess {
+                        span,
+                        expr: inner_expr,
+                        accessor: MapAccessor::Bracket { key },
+                    } if paths.is_empty() => {
+                        self.resolve_function(*span, "get", vec![], &[&**inner_expr, &**key])
+                            .await?
+                    }
+                    _ => self.resolve_map_access(expr, paths).await?,
+                }
             }
 
             Expr::Extract {
@@ -1737,6 +1748,15 @@ impl<'a> TypeChecker<'a> {
             .map(|param| param.name.to_lowercase())
             .collect::<Vec<_>>();
 
+        if func_name == "array_zip_with" {
+            return self
+                .resolve_array_zip_with(span, &params, args, lambda)
+                .await;
+        }
+        if func_name == "array_scan" {
+            return self.resolve_array_scan(span, &params, args, lambda).await;
+        }
+
         // TODO: support multiple params
         if params.len() != 1 {
             return Err(ErrorCode::SemanticError(format!(
@@ -1761,21 +1781,59 @@ impl<'a> TypeChecker<'a> {
                 ));
             }
         };
-        let box (lambda_expr, lambda_type) =
-            parse_lambda_expr(self.ctx.clone(), &params[0], &inner_ty, &lambda.expr)?;
+        let box (lambda_expr, lambda_type) = parse_lambda_expr(
+            self.ctx.clone(),
+            &[(params[0].clone(), inner_ty.clone())],
+            &lambda.expr,
+        )?;
 
-        let return_type = if func_name == "array_filter" {
-            if lambda_type.remove_nullable() == DataType::Boolean {
-                arg_type.clone()
-            } else {
-                return Err(ErrorCode::SemanticError(
-                    "invalid lambda function for `array_filter`, the result data type of lambda function must be boolean".to_string()
-                ));
+        if matches!(
+            func_name,
+            "array_filter"
+                | "array_partition"
+                | "array_find"
+                | "array_count"
+                | "array_all"
+                | "array_any"
+        ) && lambda_type.remove_nullable() != DataType::Boolean
+        {
+            return Err(ErrorCode::SemanticError(format!(
+                "invalid lambda function for `{func_name}`, the result data type of lambda function must be boolean"
+            )));
+        }
+        let return_type = match func_name {
+            "array_filter" | "array_dedup_by" => arg_type.clone(),
+            "array_partition" => {
+                let array_ty = arg_type.remove_nullable();
+                let tuple_ty = DataType::Tuple(vec![array_ty.clone(), array_ty]);
+                if arg_type.is_nullable() {
+                    DataType::Nullable(Box::new(tuple_ty))
+                } else {
+                    tuple_ty
+                }
+            }
+            "array_find" | "array_min_by" | "array_max_by" => inner_ty.wrap_nullable(),
+            "array_count" => {
+                if arg_type.is_nullable() {
+                    DataType::Nullable(Box::new(DataType::Number(NumberDataType::UInt64)))
+                } else {
+                    DataType::Number(NumberDataType::UInt64)
+                }
+            }
+            "array_all" | "array_any" => {
+                if arg_type.is_nullable() || lambda_type.is_nullable() {
+                    DataType::Nullable(Box::new(DataType::Boolean))
+                } else {
+                    DataType::Boolean
+                }
+            }
+            _ => {
+                if arg_type.is_nullable() {
+                    DataType::Nullable(Box::new(DataType::Array(Box::new(lambda_type))))
+                } else {
+                    DataType::Array(Box::new(lambda_type))
+                }
             }
-        } else if arg_type.is_nullable() {
-            DataType::Nullable(Box::new(DataType::Array(Box::new(lambda_type))))
-        } else {
-            DataType::Array(Box::new(lambda_type))
         };
 
         let (lambda_func, data_type) = match arg_type.remove_nullable() {
@@ -1788,14 +1846,48 @@ impl<'a> TypeChecker<'a> {
                 .into(),
                 DataType::Null,
             ),
-            DataType::EmptyArray => (
-                ConstantExpr {
-                    span,
-                    value: Scalar::EmptyArray,
-                }
-                .into(),
-                DataType::EmptyArray,
-            ),
+            DataType::EmptyArray => match func_name {
+                "array_partition" => (
+                    ConstantExpr {
+                        span,
+                        value: Scalar::Tuple(vec![Scalar::EmptyArray, Scalar::EmptyArray]),
+                    }
+                    .into(),
+                    DataType::Tuple(vec![DataType::EmptyArray, DataType::EmptyArray]),
+                ),
+                "array_find" | "array_min_by" | "array_max_by" => (
+                    ConstantExpr {
+                        span,
+                        value: Scalar::Null,
+                    }
+                    .into(),
+                    DataType::Null,
+                ),
+                "array_count" => (
+                    ConstantExpr {
+                        span,
+                        value: Scalar::Number(NumberScalar::UInt64(0)),
+                    }
+                    .into(),
+                    DataType::Number(NumberDataType::UInt64),
+                ),
+                "array_all" | "array_any" => (
+                    ConstantExpr {
+                        span,
+                        value: Scalar::Boolean(func_name == "array_all"),
+                    }
+                    .into(),
+                    DataType::Boolean,
+                ),
+                _ => (
+                    ConstantExpr {
+                        span,
+                        value: Scalar::EmptyArray,
+                    }
+                    .into(),
+                    DataType::EmptyArray,
+                ),
+            },
             _ => {
                 // generate lambda expression
                 let lambda_field = DataField::new("0", inner_ty.clone());
@@ -1828,6 +1920,223 @@ impl<'a> TypeChecker<'a> {
         Ok(Box::new((lambda_func, data_type)))
     }
 
+    /// Resolve `array_zip_with`, which combines the elements at the same position of multiple arrays
+    /// with a lambda function, the elements of each array are bound to one of the lambda parameters.
+    #[async_backtrace::framed]
+    async fn resolve_array_zip_with(
+        &mut self,
+        span: Span,
+        params: &[String],
+        args: &[&Expr],
+        lambda: &Lambda,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        if args.len() < 2 || params.len() != args.len() {
+            return Err(ErrorCode::SemanticError(format!(
+                "invalid arguments for lambda function, array_zip_with expects at least 2 arguments and the same number of parameters, but got {} arguments and {} parameters",
+                args.len(),
+                params.len()
+            )));
+        }
+
+        let mut lambda_args = Vec::with_capacity(args.len());
+        let mut columns = Vec::with_capacity(args.len());
+        let mut is_nullable = false;
+        let mut has_empty_array = false;
+        for (param, arg) in params.iter().zip(args.iter()) {
+            let box (arg, arg_type) = self.resolve(arg).await?;
+            let inner_ty = match arg_type.remove_nullable() {
+                DataType::Array(box inner_ty) => inner_ty,
+                DataType::Null => {
+                    return Ok(Box::new((
+                        ConstantExpr {
+                            span,
+                            value: Scalar::Null,
+                        }
+                        .into(),
+                        DataType::Null,
+                    )));
+                }
+                DataType::EmptyArray => {
+                    has_empty_array = true;
+                    DataType::Null
+                }
+                _ => {
+                    return Err(ErrorCode::SemanticError(
+                        "invalid arguments for lambda function, argument data type must be array"
+                            .to_string(),
+                    ));
+                }
+            };
+            is_nullable = is_nullable || arg_type.is_nullable();
+            lambda_args.push(arg);
+            columns.push((param.clone(), inner_ty));
+        }
+        let box (lambda_expr, lambda_type) =
+            parse_lambda_expr(self.ctx.clone(), &columns, &lambda.expr)?;
+
+        // Zip with an empty array is always an empty array.
+        if has_empty_array {
+            return Ok(Box::new((
+                ConstantExpr {
+                    span,
+                    value: Scalar::EmptyArray,
+                }
+                .into(),
+                DataType::EmptyArray,
+            )));
+        }
+
+        let return_type = if is_nullable {
+            DataType::Nullable(Box::new(DataType::Array(Box::new(lambda_type))))
+        } else {
+            DataType::Array(Box::new(lambda_type))
+        };
+
+        // generate lambda expression
+        let lambda_fields = columns
+            .iter()
+            .enumerate()
+            .map(|(index, (_, inner_ty))| DataField::new(&index.to_string(), inner_ty.clone()))
+            .collect();
+        let lambda_schema = DataSchema::new(lambda_fields);
+
+        let expr = lambda_expr
+            .type_check(&lambda_schema)?
+            .project_column_ref(|index| lambda_schema.index_of(&index.to_string()).unwrap());
+        let (expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let remote_lambda_expr = expr.as_remote_expr();
+        let lambda_display = format!("({}) -> {}", params.join(", "), expr.sql_display());
+
+        Ok(Box::new((
+            LambdaFunc {
+                span,
+                func_name: "array_zip_with".to_string(),
+                args: lambda_args,
+                lambda_expr: Box::new(remote_lambda_expr),
+                lambda_display,
+                return_type: Box::new(return_type.clone()),
+            }
+            .into(),
+            return_type,
+        )))
+    }
+
+    /// Resolve `array_scan(array, init, (acc, x) -> expr)`, the accumulator has the common type
+    /// of the initial value and the lambda result, and the lambda result is cast to it,
+    /// so the type of the accumulators stays the same across the positions.
+    #[async_backtrace::framed]
+    async fn resolve_array_scan(
+        &mut self,
+        span: Span,
+        params: &[String],
+        args: &[&Expr],
+        lambda: &Lambda,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        if args.len() != 2 || params.len() != 2 {
+            return Err(ErrorCode::SemanticError(format!(
+                "invalid arguments for lambda function, array_scan expects 2 arguments and 2 parameters, but got {} arguments and {} parameters",
+                args.len(),
+                params.len()
+            )));
+        }
+
+        let box (arg, arg_type) = self.resolve(args[0]).await?;
+        let box (init, init_type) = self.resolve(args[1]).await?;
+        let inner_ty = match arg_type.remove_nullable() {
+            DataType::Array(box inner_ty) => inner_ty,
+            DataType::Null | DataType::EmptyArray => DataType::Null,
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    "invalid arguments for lambda function, argument data type must be array"
+                        .to_string(),
+                ));
+            }
+        };
+        let box (_, lambda_type) = parse_lambda_expr(
+            self.ctx.clone(),
+            &[
+                (params[0].clone(), init_type.clone()),
+                (params[1].clone(), inner_ty.clone()),
+            ],
+            &lambda.expr,
+        )?;
+        let acc_type = type_check::common_super_type(
+            init_type.clone(),
+            lambda_type.clone(),
+            &BUILTIN_FUNCTIONS.default_cast_rules,
+        )
+        .ok_or_else(|| {
+            ErrorCode::SemanticError(format!(
+                "invalid lambda function for `array_scan`, the result data type {lambda_type} is incompatible with the initial value data type {init_type}"
+            ))
+        })?;
+        let box (lambda_expr, _) = parse_lambda_expr(
+            self.ctx.clone(),
+            &[
+                (params[0].clone(), acc_type.clone()),
+                (params[1].clone(), inner_ty.clone()),
+            ],
+            &lambda.expr,
+        )?;
+
+        // Null and Empty array can convert to ConstantExpr
+        match arg_type.remove_nullable() {
+            DataType::Null => {
+                return Ok(Box::new((
+                    ConstantExpr {
+                        span,
+                        value: Scalar::Null,
+                    }
+                    .into(),
+                    DataType::Null,
+                )));
+            }
+            DataType::EmptyArray => {
+                return Ok(Box::new((
+                    ConstantExpr {
+                        span,
+                        value: Scalar::EmptyArray,
+                    }
+                    .into(),
+                    DataType::EmptyArray,
+                )));
+            }
+            _ => {}
+        }
+
+        let return_type = if arg_type.is_nullable() {
+            DataType::Nullable(Box::new(DataType::Array(Box::new(acc_type.clone()))))
+        } else {
+            DataType::Array(Box::new(acc_type.clone()))
+        };
+
+        // generate lambda expression
+        let lambda_schema = DataSchema::new(vec![
+            DataField::new("0", acc_type.clone()),
+            DataField::new("1", inner_ty),
+        ]);
+        let expr = lambda_expr
+            .type_check(&lambda_schema)?
+            .project_column_ref(|index| lambda_schema.index_of(&index.to_string()).unwrap());
+        let expr = type_check::check_cast(span, false, expr, &acc_type, &BUILTIN_FUNCTIONS)?;
+        let (expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let remote_lambda_expr = expr.as_remote_expr();
+        let lambda_display = format!("({}) -> {}", params.join(", "), expr.sql_display());
+
+        Ok(Box::new((
+            LambdaFunc {
+                span,
+                func_name: "array_scan".to_string(),
+                args: vec![arg, wrap_cast(&init, &acc_type)],
+                lambda_expr: Box::new(remote_lambda_expr),
+                lambda_display,
+                return_type: Box::new(return_type.clone()),
+            }
+            .into(),
+            return_type,
+        )))
+    }
+
     /// Resolve function call.
     #[async_backtrace::framed]
     pub async fn resolve_function(
@@ -1871,6 +2180,48 @@ impl<'a> TypeChecker<'a> {
             Self::rewrite_substring(&mut args);
         }
 
+        // Convert the field names of `array_to_struct` and the keys of `array_pivot` to params
+        let params = if (func_name == "array_to_struct" || func_name == "array_pivot")
+            && args.len() == 2
+            && params.is_empty()
+        {
+            let names = match ConstantExpr::try_from(args[1].clone()) {
+                Ok(ConstantExpr {
+                    value: Scalar::Array(Column::String(names)),
+                    ..
+                }) => names
+                    .iter()
+                    .map(|name| String::from_utf8_lossy(name).to_string())
+                    .collect::<Vec<_>>(),
+                _ => {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "The field names of `{func_name}` must be a constant array of strings"
+                    ))
+                    .set_span(span));
+                }
+            };
+            if names.is_empty() || names.iter().any(|name| name.is_empty()) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "The field names of `{func_name}` must not be empty"
+                ))
+                .set_span(span));
+            }
+            if names.iter().duplicates().next().is_some() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "The field names of `{}` must be unique, but got {:?}",
+                    func_name, names
+                ))
+                .set_span(span));
+            }
+            args.truncate(1);
+            names
+                .into_iter()
+                .map(|name| Scalar::String(name.into_bytes()))
+                .collect()
+        } else {
+            params
+        };
+
         if func_name == "grouping" {
             // `grouping` will be rewritten again after resolving grouping sets.
             return Ok(Box::new((
@@ -2691,6 +3042,16 @@ impl<'a> TypeChecker<'a> {
         span: Span,
         exprs: &[Expr],
     ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let max_array_literal_length = self.ctx.get_settings().get_max_array_literal_length()?;
+        if max_array_literal_length > 0 && exprs.len() as u64 > max_array_literal_length {
+            return Err(ErrorCode::SemanticError(format!(
+                "Array literal has {} elements, exceeds the maximum length {}, which can be changed by the setting `max_array_literal_length`",
+                exprs.len(),
+                max_array_literal_length
+            ))
+            .set_span(span));
+        }
+
         let mut elems = Vec::with_capacity(exprs.len());
         for expr in exprs {
             let box (arg, _data_type) = self.resolve(expr).await?;
@@ -3036,6 +3397,23 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// The field names of the tuple built by `array_to_struct` or `array_pivot`, which are
+    /// the names or the keys passed as its params.
+    fn tuple_fields_name(scalar: &ScalarExpr) -> Option<Vec<String>> {
+        match scalar {
+            ScalarExpr::FunctionCall(FunctionCall {
+                func_name, params, ..
+            }) if func_name == "array_to_struct" || func_name == "array_pivot" => params
+                .iter()
+                .map(|param| match param {
+                    Scalar::String(name) => Some(String::from_utf8_lossy(name).to_string()),
+                    _ => None,
+                })
+                .collect(),
+            _ => None,
+        }
+    }
+
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
     async fn resolve_map_access(
@@ -3050,6 +3428,20 @@ impl<'a> TypeChecker<'a> {
         }
 
         let mut table_data_type = infer_schema_type(&data_type)?;
+        // The tuple types are not named, take the field names from the function building it.
+        if let Some(fields_name) = Self::tuple_fields_name(&scalar) {
+            if let TableDataType::Tuple { fields_type, .. } = table_data_type.remove_nullable() {
+                let tuple_type = TableDataType::Tuple {
+                    fields_name,
+                    fields_type,
+                };
+                table_data_type = if table_data_type.is_nullable() {
+                    tuple_type.wrap_nullable()
+                } else {
+                    tuple_type
+                };
+            }
+        }
         // If it is a tuple column, convert it to the internal column specified by the paths.
         // For other types of columns, convert it to get functions.
         if let ScalarExpr::BoundColumnRef(BoundColumnRef { ref column, .. }) = scalar {
@@ -0,0 +1,74 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use criterion::Criterion;
+use databend_common_arrow::arrow::chunk::Chunk;
+use databend_common_arrow::ArrayRef;
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchema;
+use databend_common_expression::Scalar;
+
+const NUM_PARTS: usize = 1000;
+const NUM_COLUMNS: usize = 200;
+const DEFAULT_LEN: usize = 4096;
+
+/// Benchmark building the blocks of many parts of a wide table, whose columns are all
+/// missing and filled with large default values, either cloning the default values
+/// before building the block or building it from the default values directly.
+fn bench_default_values_block(c: &mut Criterion) {
+    let mut group = c.benchmark_group("default_values_block");
+
+    let fields = (0..NUM_COLUMNS)
+        .map(|i| DataField::new(&format!("c{i}"), DataType::String))
+        .collect::<Vec<_>>();
+    let schema = DataSchema::new(fields);
+    let default_vals = (0..NUM_COLUMNS)
+        .map(|_| Scalar::String(vec![b'x'; DEFAULT_LEN]))
+        .collect::<Vec<_>>();
+
+    group.bench_function(format!("cloned/{NUM_PARTS}"), |b| {
+        b.iter(|| {
+            for _ in 0..NUM_PARTS {
+                let default_vals = default_vals.clone();
+                let default_opt_vals = default_vals
+                    .iter()
+                    .map(|default_val| Some(default_val.to_owned()))
+                    .collect::<Vec<_>>();
+                DataBlock::create_with_default_value_and_chunk(
+                    &schema,
+                    &Chunk::<ArrayRef>::new(vec![]),
+                    &default_opt_vals,
+                    8192,
+                )
+                .unwrap();
+            }
+        })
+    });
+
+    group.bench_function(format!("direct/{NUM_PARTS}"), |b| {
+        b.iter(|| {
+            for _ in 0..NUM_PARTS {
+                DataBlock::create_with_default_value(&schema, &default_vals, 8192).unwrap();
+            }
+        })
+    });
+}
+
+criterion_group!(benches, bench_default_values_block);
+criterion_main!(benches);
@@ -0,0 +1,108 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use std::io::Cursor;
+
+use bytes::Bytes;
+use criterion::Criterion;
+use databend_common_arrow::arrow::array::Int64Array;
+use databend_common_arrow::arrow::chunk::Chunk;
+use databend_common_arrow::arrow::datatypes::DataType;
+use databend_common_arrow::arrow::datatypes::Field;
+use databend_common_arrow::arrow::datatypes::Schema;
+use databend_common_arrow::arrow::io::parquet::write::to_parquet_schema;
+use databend_common_arrow::native::read::deserialize::column_iter_to_arrays;
+use databend_common_arrow::native::read::reader::NativeReader;
+use databend_common_arrow::native::write::NativeWriter;
+use databend_common_arrow::native::write::WriteOptions;
+use databend_common_arrow::native::ColumnMeta;
+use databend_common_arrow::native::CommonCompression;
+use databend_common_storages_fuse::io::NativeReaderExt;
+use databend_common_storages_fuse::io::SharedNativeReader;
+
+const NUM_PARTS: usize = 5000;
+
+/// Benchmark decoding many tiny parts of a single column, either building the
+/// ArrayIter for each part or resetting the one built on a shared reader.
+fn bench_native_array_iter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("native_array_iter");
+
+    let field = Field::new("a", DataType::Int64, false);
+    let schema = Schema::from(vec![field.clone()]);
+    let array = Int64Array::from_vec((0..16).collect());
+    let mut bytes = Vec::new();
+    let mut writer = NativeWriter::new(&mut bytes, schema.clone(), WriteOptions {
+        default_compression: CommonCompression::Lz4,
+        max_page_size: Some(8),
+        default_compress_ratio: None,
+        forbidden_compressions: vec![],
+    });
+    writer.start().unwrap();
+    writer.write(&Chunk::new(vec![array.boxed()])).unwrap();
+    writer.finish().unwrap();
+    let meta = writer.metas[0].clone();
+    let bytes = Bytes::from(bytes);
+    let leaves = to_parquet_schema(&schema).unwrap().columns().to_vec();
+
+    let new_reader = |meta: &ColumnMeta| {
+        let mut cursor = Cursor::new(bytes.clone());
+        cursor.set_position(meta.offset);
+        let reader: Box<dyn NativeReaderExt> = Box::new(cursor);
+        NativeReader::new(reader, meta.pages.clone(), vec![])
+    };
+
+    group.bench_function(format!("rebuild/{NUM_PARTS}"), |b| {
+        b.iter(|| {
+            for _ in 0..NUM_PARTS {
+                let array_iter = column_iter_to_arrays(
+                    vec![new_reader(&meta)],
+                    leaves.clone(),
+                    field.clone(),
+                    false,
+                )
+                .unwrap();
+                for array in array_iter {
+                    array.unwrap();
+                }
+            }
+        })
+    });
+
+    group.bench_function(format!("reuse/{NUM_PARTS}"), |b| {
+        b.iter(|| {
+            let shared_reader = SharedNativeReader::new(new_reader(&meta));
+            let mut array_iter = column_iter_to_arrays(
+                vec![shared_reader.clone()],
+                leaves.clone(),
+                field.clone(),
+                false,
+            )
+            .unwrap();
+            for part in 0..NUM_PARTS {
+                if part > 0 {
+                    shared_reader.reset(new_reader(&meta));
+                }
+                for array in array_iter.by_ref() {
+                    array.unwrap();
+                }
+            }
+        })
+    });
+}
+
+criterion_group!(benches, bench_native_array_iter);
+criterion_main!(benches);
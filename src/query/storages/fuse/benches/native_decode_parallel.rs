@@ -0,0 +1,121 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use std::io::Cursor;
+
+use bytes::Bytes;
+use criterion::Criterion;
+use databend_common_arrow::arrow::array::Int64Array;
+use databend_common_arrow::arrow::chunk::Chunk;
+use databend_common_arrow::arrow::datatypes::DataType;
+use databend_common_arrow::arrow::datatypes::Field;
+use databend_common_arrow::arrow::datatypes::Schema;
+use databend_common_arrow::arrow::io::parquet::write::to_parquet_schema;
+use databend_common_arrow::native::read::deserialize::column_iter_to_arrays;
+use databend_common_arrow::native::read::reader::NativeReader;
+use databend_common_arrow::native::read::ArrayIter;
+use databend_common_arrow::native::write::NativeWriter;
+use databend_common_arrow::native::write::WriteOptions;
+use databend_common_arrow::native::CommonCompression;
+use databend_common_base::runtime::Runtime;
+use databend_common_storages_fuse::io::BlockReader;
+use databend_common_storages_fuse::io::NativeReaderExt;
+
+const NUM_COLUMNS: usize = 200;
+const NUM_ROWS: usize = 65536;
+
+/// Benchmark decoding a page of each column of a 200 columns table, either one by one
+/// or concurrently on the blocking threads of a runtime, as the remain columns of the
+/// native scan with `storage_native_decode_parallelism`.
+fn bench_native_decode_parallel(c: &mut Criterion) {
+    let mut group = c.benchmark_group("native_decode_parallel");
+
+    let fields = (0..NUM_COLUMNS)
+        .map(|i| Field::new(format!("c{i}"), DataType::Int64, false))
+        .collect::<Vec<_>>();
+    let schema = Schema::from(fields.clone());
+    let arrays = (0..NUM_COLUMNS)
+        .map(|i| {
+            let values = (0..NUM_ROWS as i64).map(|v| v * i as i64).collect();
+            Int64Array::from_vec(values).boxed()
+        })
+        .collect::<Vec<_>>();
+    let mut bytes = Vec::new();
+    let mut writer = NativeWriter::new(&mut bytes, schema.clone(), WriteOptions {
+        default_compression: CommonCompression::Lz4,
+        max_page_size: Some(NUM_ROWS),
+        default_compress_ratio: None,
+        forbidden_compressions: vec![],
+    });
+    writer.start().unwrap();
+    writer.write(&Chunk::new(arrays)).unwrap();
+    writer.finish().unwrap();
+    let metas = writer.metas.clone();
+    let bytes = Bytes::from(bytes);
+    let leaves = to_parquet_schema(&schema).unwrap().columns().to_vec();
+
+    let array_iters = || {
+        metas
+            .iter()
+            .zip(fields.iter())
+            .zip(leaves.iter())
+            .map(|((meta, field), leaf)| {
+                let mut cursor = Cursor::new(bytes.clone());
+                cursor.set_position(meta.offset);
+                let reader: Box<dyn NativeReaderExt> = Box::new(cursor);
+                let reader = NativeReader::new(reader, meta.pages.clone(), vec![]);
+                column_iter_to_arrays(vec![reader], vec![leaf.clone()], field.clone(), false)
+                    .unwrap()
+            })
+            .collect::<Vec<ArrayIter<'static>>>()
+    };
+
+    group.bench_function(format!("sequential/{NUM_COLUMNS}"), |b| {
+        b.iter(|| {
+            for mut array_iter in array_iters() {
+                array_iter.next().unwrap().unwrap();
+            }
+        })
+    });
+
+    let runtime = Runtime::with_worker_threads(2, None).unwrap();
+    for parallelism in [2, 4, 8] {
+        group.bench_function(format!("parallel_{parallelism}/{NUM_COLUMNS}"), |b| {
+            b.iter(|| {
+                let array_iters = array_iters()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, array_iter)| (index, array_iter, 0))
+                    .collect();
+                let pages = runtime
+                    .inner()
+                    .block_on(BlockReader::decode_pages_in_parallel(
+                        &runtime,
+                        array_iters,
+                        parallelism,
+                    ))
+                    .unwrap();
+                for (_, _, page) in pages {
+                    page.unwrap().unwrap();
+                }
+            })
+        });
+    }
+}
+
+criterion_group!(benches, bench_native_decode_parallel);
+criterion_main!(benches);
@@ -0,0 +1,73 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use std::sync::Arc;
+
+use criterion::Criterion;
+use databend_common_expression::types::Int64Type;
+use databend_common_expression::Aborting;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::SortColumnDescription;
+use rand::rngs::StdRng;
+use rand::Rng;
+use rand::SeedableRng;
+
+const NUM_PAGES: usize = 64;
+const ROWS_PER_PAGE: usize = 8192;
+
+/// Benchmark sorting the pages emitted by the native reader downstream, either sorting
+/// the unordered pages at once or merging the pages sorted by the top-k column, i.e.
+/// with `storage_native_topk_approx_order` enabled, including the cost of sorting them.
+fn bench_topk_approx_order(c: &mut Criterion) {
+    let mut group = c.benchmark_group("topk_approx_order");
+
+    let mut rng = StdRng::seed_from_u64(0);
+    let pages = (0..NUM_PAGES)
+        .map(|_| {
+            let values = (0..ROWS_PER_PAGE).map(|_| rng.gen()).collect::<Vec<i64>>();
+            DataBlock::new_from_columns(vec![Int64Type::from_data(values)])
+        })
+        .collect::<Vec<_>>();
+    let descriptions = vec![SortColumnDescription {
+        offset: 0,
+        asc: true,
+        nulls_first: false,
+        is_nullable: false,
+    }];
+    let aborting: Aborting = Arc::new(Box::new(|| false));
+
+    group.bench_function(format!("page_order/{NUM_PAGES}"), |b| {
+        b.iter(|| {
+            let block = DataBlock::concat(&pages).unwrap();
+            DataBlock::sort(&block, &descriptions, None).unwrap();
+        })
+    });
+
+    group.bench_function(format!("approx_order/{NUM_PAGES}"), |b| {
+        b.iter(|| {
+            let sorted = pages
+                .iter()
+                .map(|page| DataBlock::sort(page, &descriptions, None).unwrap())
+                .collect::<Vec<_>>();
+            DataBlock::merge_sort(&sorted, &descriptions, None, aborting.clone()).unwrap();
+        })
+    });
+}
+
+criterion_group!(benches, bench_topk_approx_order);
+criterion_main!(benches);
@@ -35,7 +35,7 @@ use databend_storages_common_table_meta::meta::Compression;
 use databend_storages_common_table_meta::meta::Location;
 
 /// Fuse table partition information.
-#[derive(serde::Serialize, serde::Deserialize, PartialEq, Debug)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Debug)]
 pub struct FusePartInfo {
     pub location: String,
 
@@ -47,6 +47,9 @@ pub struct FusePartInfo {
 
     pub sort_min_max: Option<(Scalar, Scalar)>,
     pub block_meta_index: Option<BlockMetaIndex>,
+    /// The bloom filter index of the block, probed with the runtime filters before reading.
+    pub bloom_index_location: Option<Location>,
+    pub bloom_index_size: u64,
 }
 
 #[typetag::serde(name = "fuse")]
@@ -79,6 +82,8 @@ impl FusePartInfo {
         sort_min_max: Option<(Scalar, Scalar)>,
         block_meta_index: Option<BlockMetaIndex>,
         create_on: Option<DateTime<Utc>>,
+        bloom_index_location: Option<Location>,
+        bloom_index_size: u64,
     ) -> Arc<Box<dyn PartInfo>> {
         Arc::new(Box::new(FusePartInfo {
             location,
@@ -89,6 +94,8 @@ impl FusePartInfo {
             sort_min_max,
             block_meta_index,
             columns_stat,
+            bloom_index_location,
+            bloom_index_size,
         }))
     }
 
@@ -114,6 +121,60 @@ impl FusePartInfo {
             .map(|meta| meta.page_size)
             .unwrap_or(self.nums_rows)
     }
+
+    /// The rows of each page of the block, read from the native meta of the column `column_id`.
+    ///
+    /// The column must not be nested, the values of the leaves of nested columns are the
+    /// elements rather than the rows. Returns `None` if the column is not in native format.
+    pub fn page_rows(&self, column_id: ColumnId) -> Option<Vec<usize>> {
+        match self.columns_meta.get(&column_id) {
+            Some(ColumnMeta::Native(meta)) => Some(
+                meta.pages
+                    .iter()
+                    .map(|page| page.num_values as usize)
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
+    /// The row offset of the page in the block.
+    ///
+    /// The blocks written by older versions or before the page size setting changed may
+    /// have pages of different sizes, so the offset is the sum of the `page_rows` before
+    /// it, it falls back to `page_size` if the page rows are unknown.
+    pub fn page_row_offset(&self, page_rows: Option<&[usize]>, page: usize) -> usize {
+        let offset = match page_rows {
+            Some(page_rows) => page_rows.iter().take(page).sum(),
+            None => self.page_size() * page,
+        };
+        offset.min(self.nums_rows)
+    }
+
+    /// The page containing the row at `offset` in the block, the reverse of `page_row_offset`.
+    pub fn page_of_row_offset(&self, page_rows: Option<&[usize]>, offset: usize) -> usize {
+        match page_rows {
+            Some(page_rows) => {
+                let mut end = 0;
+                page_rows
+                    .iter()
+                    .position(|rows| {
+                        end += rows;
+                        offset < end
+                    })
+                    .unwrap_or(page_rows.len())
+            }
+            None => offset / self.page_size().max(1),
+        }
+    }
+
+    /// Whether the block of this part is stored in parquet format.
+    /// A table migrated from parquet to native may contain blocks of both formats.
+    pub fn is_parquet_format(&self) -> bool {
+        self.columns_meta
+            .values()
+            .any(|meta| matches!(meta, ColumnMeta::Parquet(_)))
+    }
 }
 
 /// Fuse table lazy partition information.
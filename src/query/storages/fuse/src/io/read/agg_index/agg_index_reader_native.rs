@@ -55,6 +55,8 @@ impl AggIndexReader {
                     None,
                     None,
                     None,
+                    None,
+                    0,
                 );
                 let res = self
                     .reader
@@ -107,6 +109,8 @@ impl AggIndexReader {
                     None,
                     None,
                     None,
+                    None,
+                    0,
                 );
                 let res = self
                     .reader
@@ -52,6 +52,8 @@ impl AggIndexReader {
                     None,
                     None,
                     None,
+                    None,
+                    0,
                 );
                 let res = self
                     .reader
@@ -102,6 +104,8 @@ impl AggIndexReader {
                     None,
                     None,
                     None,
+                    None,
+                    0,
                 );
                 Some((part, res))
             }
@@ -17,12 +17,14 @@ use std::collections::HashSet;
 use std::io::BufReader;
 use std::ops::Range;
 use std::sync::Arc;
+use std::sync::Mutex;
 
 use databend_common_arrow::arrow::array::Array;
 use databend_common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use databend_common_arrow::native::read::reader::infer_schema;
 use databend_common_arrow::native::read::reader::NativeReader;
 use databend_common_arrow::native::read::NativeReadBuf;
+use databend_common_arrow::native::read::PageIterator;
 use databend_common_catalog::plan::PartInfoPtr;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
@@ -49,6 +51,50 @@ pub type Reader = Box<dyn NativeReaderExt>;
 
 pub type NativeSourceData = BTreeMap<usize, Vec<NativeReader<Reader>>>;
 
+/// A native reader whose pages can be replaced by the reader of another part.
+///
+/// The array iterator built on top of it keeps its decoder state across parts,
+/// so it's reused by `reset` instead of being rebuilt for each part.
+#[derive(Clone)]
+pub struct SharedNativeReader {
+    inner: Arc<Mutex<NativeReader<Reader>>>,
+}
+
+impl SharedNativeReader {
+    pub fn new(reader: NativeReader<Reader>) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(reader)),
+        }
+    }
+
+    /// Replace the pages with the ones of `reader`, the scratch buffer is kept.
+    pub fn reset(&self, mut reader: NativeReader<Reader>) {
+        let mut inner = self.inner.lock().unwrap();
+        let mut scratch = vec![];
+        inner.swap_buffer(&mut scratch);
+        reader.swap_buffer(&mut scratch);
+        *inner = reader;
+    }
+}
+
+impl PageIterator for SharedNativeReader {
+    fn swap_buffer(&mut self, buffer: &mut Vec<u8>) {
+        self.inner.lock().unwrap().swap_buffer(buffer)
+    }
+}
+
+impl Iterator for SharedNativeReader {
+    type Item = databend_common_arrow::arrow::error::Result<(u64, Vec<u8>)>;
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.lock().unwrap().nth(n)
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.lock().unwrap().next()
+    }
+}
+
 impl BlockReader {
     #[async_backtrace::framed]
     pub async fn async_read_native_columns_data(
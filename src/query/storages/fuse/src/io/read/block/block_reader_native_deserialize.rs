@@ -21,10 +21,12 @@ use databend_common_arrow::arrow::chunk::Chunk;
 use databend_common_arrow::arrow::datatypes::DataType as ArrowType;
 use databend_common_arrow::arrow::datatypes::Field;
 use databend_common_arrow::arrow::datatypes::Field as ArrowField;
+use databend_common_arrow::arrow::error::Result as ArrowResult;
 use databend_common_arrow::native::read::batch_read::batch_read_array;
 use databend_common_arrow::native::read::column_iter_to_arrays;
 use databend_common_arrow::native::read::reader::NativeReader;
 use databend_common_arrow::native::read::ArrayIter;
+use databend_common_arrow::native::read::PageIterator;
 use databend_common_arrow::parquet::metadata::ColumnDescriptor;
 use databend_common_arrow::parquet::metadata::Descriptor;
 use databend_common_arrow::parquet::metadata::SchemaDescriptor;
@@ -33,6 +35,7 @@ use databend_common_arrow::parquet::schema::types::ParquetType;
 use databend_common_arrow::parquet::schema::types::PhysicalType;
 use databend_common_arrow::parquet::schema::types::PrimitiveType;
 use databend_common_arrow::parquet::schema::Repetition;
+use databend_common_base::runtime::Runtime;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::ColumnId;
@@ -289,11 +292,14 @@ impl BlockReader {
         }
     }
 
-    pub(crate) fn build_array_iter(
+    pub(crate) fn build_array_iter<I>(
         column_node: &ColumnNode,
         leaves: Vec<ColumnDescriptor>,
-        readers: Vec<NativeReader<Box<dyn NativeReaderExt>>>,
-    ) -> Result<ArrayIter<'static>> {
+        readers: Vec<I>,
+    ) -> Result<ArrayIter<'static>>
+    where
+        I: Iterator<Item = ArrowResult<(u64, Vec<u8>)>> + PageIterator + Send + Sync + 'static,
+    {
         let field = column_node.field.clone();
         let is_nested = column_node.is_nested;
         match column_iter_to_arrays(readers, leaves, field, is_nested) {
@@ -302,6 +308,34 @@ impl BlockReader {
         }
     }
 
+    /// Decode the next page of each ArrayIter, after skipping the given number of pages,
+    /// on the blocking threads of `runtime`. The ArrayIters are split into at most
+    /// `parallelism` groups decoded concurrently, they are returned along with their pages.
+    #[async_backtrace::framed]
+    pub async fn decode_pages_in_parallel(
+        runtime: &Runtime,
+        array_iters: Vec<(usize, ArrayIter<'static>, usize)>,
+        parallelism: usize,
+    ) -> Result<Vec<(usize, ArrayIter<'static>, Option<ArrowResult<Box<dyn Array>>>)>> {
+        let chunk_size = array_iters.len().div_ceil(parallelism.max(1)).max(1);
+        let mut array_iters = array_iters.into_iter().peekable();
+        let mut tasks = Vec::with_capacity(parallelism);
+        while array_iters.peek().is_some() {
+            let chunk = array_iters.by_ref().take(chunk_size).collect::<Vec<_>>();
+            tasks.push(runtime.spawn_blocking(move || {
+                Ok(chunk
+                    .into_iter()
+                    .map(|(index, mut array_iter, skip_pages)| {
+                        let page = array_iter.nth(skip_pages);
+                        (index, array_iter, page)
+                    })
+                    .collect::<Vec<_>>())
+            }));
+        }
+        let pages = futures::future::try_join_all(tasks).await?;
+        Ok(pages.into_iter().flatten().collect())
+    }
+
     pub(crate) fn build_virtual_array_iter(
         name: String,
         readers: Vec<NativeReader<Box<dyn NativeReaderExt>>>,
@@ -81,8 +81,7 @@ impl BlockReader {
 
     pub fn build_default_values_block(&self, num_rows: usize) -> Result<DataBlock> {
         let data_schema = self.data_schema();
-        let default_vals = self.default_vals.clone();
-        DataBlock::create_with_default_value(&data_schema, &default_vals, num_rows)
+        DataBlock::create_with_default_value(&data_schema, &self.default_vals, num_rows)
     }
 
     /// Deserialize column chunks data from parquet format to DataBlock with a uncompressed buffer.
@@ -28,4 +28,5 @@ pub use block_reader_deserialize::FieldDeserializationContext;
 pub use block_reader_merge_io::MergeIOReadResult;
 pub use block_reader_native::NativeReaderExt;
 pub use block_reader_native::NativeSourceData;
+pub use block_reader_native::SharedNativeReader;
 pub use decompressor::UncompressedBuffer;
@@ -26,6 +26,7 @@ pub use block::BlockReader;
 pub use block::MergeIOReadResult;
 pub use block::NativeReaderExt;
 pub use block::NativeSourceData;
+pub use block::SharedNativeReader;
 pub use block::UncompressedBuffer;
 pub use bloom::BloomBlockFilterReader;
 pub use meta::CompactSegmentInfoReader;
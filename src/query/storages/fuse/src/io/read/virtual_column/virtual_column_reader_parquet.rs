@@ -96,6 +96,8 @@ impl VirtualColumnReader {
                 None,
                 None,
                 None,
+                None,
+                0,
             );
 
             let merge_io_result =
@@ -138,6 +140,8 @@ impl VirtualColumnReader {
                 None,
                 None,
                 None,
+                None,
+                0,
             );
 
             let merge_io_result = BlockReader::merge_io_read(
@@ -388,6 +388,11 @@ where F: SnapshotGenerator + Send + 'static
                                 SegmentInfo::VERSION,
                             ))?;
                         }
+                        // Only the blocks of the committed snapshot are recorded, they are
+                        // refreshed by the hooks after the write.
+                        for block in self.abort_operation.blocks.iter() {
+                            self.ctx.add_block_location(block.to_string())?;
+                        }
                         self.state = State::Finish;
                     }
                     Err(e) if self.is_error_recoverable(&e) => {
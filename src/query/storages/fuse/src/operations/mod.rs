@@ -41,6 +41,10 @@ pub use delete::MutationBlockPruningContext;
 pub use merge_into::*;
 pub use mutation::*;
 pub use read::build_row_fetcher_pipeline;
+pub use read::order_bloom_runtime_filters;
+pub use read::DataSourceWithMeta;
+pub use read::NativeDataSource;
+pub use read::NativeDeserializeDataTransform;
 pub use replace_into::*;
 pub use util::acquire_task_permit;
 pub use util::column_parquet_metas;
@@ -18,6 +18,7 @@ use std::sync::Arc;
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::InternalColumnMeta;
 use databend_common_catalog::plan::PartInfoPtr;
+use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::plan::StealablePartitions;
 use databend_common_catalog::plan::TopK;
 use databend_common_catalog::table_context::TableContext;
@@ -61,6 +62,7 @@ pub fn build_fuse_native_source_pipeline(
         max_io_requests = max_io_requests.min(16);
     }
 
+    let limit = PushDownInfo::scan_limit_of_push_downs(plan.push_downs.as_ref());
     let mut source_builder = SourcePipeBuilder::create();
 
     match block_reader.support_blocking_api() {
@@ -86,6 +88,7 @@ pub fn build_fuse_native_source_pipeline(
                         partitions.clone(),
                         index_reader.clone(),
                         virtual_reader.clone(),
+                        limit,
                     )?,
                 );
             }
@@ -113,6 +116,7 @@ pub fn build_fuse_native_source_pipeline(
                         partitions.clone(),
                         index_reader.clone(),
                         virtual_reader.clone(),
+                        limit,
                     )?,
                 );
             }
@@ -121,6 +125,10 @@ pub fn build_fuse_native_source_pipeline(
         }
     };
 
+    let force_full_columns = ctx.get_settings().get_storage_native_force_full_columns()?;
+    let filter_batch_rows = ctx.get_settings().get_storage_native_filter_batch_rows()? as usize;
+    let filter_row_per_page =
+        ctx.get_settings().get_storage_native_filter_row_per_page()? as usize;
     pipeline.add_transform(|transform_input, transform_output| {
         NativeDeserializeDataTransform::create(
             ctx.clone(),
@@ -131,6 +139,9 @@ pub fn build_fuse_native_source_pipeline(
             transform_output,
             index_reader.clone(),
             virtual_reader.clone(),
+            force_full_columns,
+            filter_batch_rows,
+            filter_row_per_page,
         )
     })?;
 
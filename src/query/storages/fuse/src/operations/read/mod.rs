@@ -26,9 +26,12 @@ mod runtime_filter_prunner;
 
 mod data_source_with_meta;
 
+pub use data_source_with_meta::DataSourceWithMeta;
 pub use fuse_rows_fetcher::build_row_fetcher_pipeline;
 pub use fuse_source::build_fuse_parquet_source_pipeline;
+pub use native_data_source::NativeDataSource;
 pub use native_data_source_deserializer::NativeDeserializeDataTransform;
 pub use native_data_source_reader::ReadNativeDataSource;
 pub use parquet_data_source_deserializer::DeserializeDataTransform;
 pub use parquet_data_source_reader::ReadParquetDataSource;
+pub use runtime_filter_prunner::order_bloom_runtime_filters;
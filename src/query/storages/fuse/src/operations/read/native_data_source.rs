@@ -14,12 +14,15 @@
 
 use databend_common_expression::BlockMetaInfo;
 
+use crate::io::MergeIOReadResult;
 use crate::io::NativeSourceData;
 use crate::operations::read::data_source_with_meta::DataSourceWithMeta;
 
 pub enum NativeDataSource {
     AggIndex(NativeSourceData),
     Normal(NativeSourceData),
+    // The block is stored in parquet format, e.g. written before the table migrated to native.
+    Parquet(MergeIOReadResult),
 }
 
 #[typetag::serde(name = "fuse_data_source")]
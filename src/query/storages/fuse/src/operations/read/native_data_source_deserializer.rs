@@ -14,43 +14,62 @@
 
 use std::any::Any;
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
-use std::ops::BitAnd;
+use std::ops::Range;
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
 
 use databend_common_arrow::arrow::array::Array;
 use databend_common_arrow::arrow::bitmap::MutableBitmap;
+use databend_common_arrow::arrow::error::Result as ArrowResult;
 use databend_common_arrow::native::read::ArrayIter;
 use databend_common_arrow::parquet::metadata::ColumnDescriptor;
 use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_catalog::plan::gen_lineage_meta;
 use databend_common_catalog::plan::gen_mutation_stream_meta;
 use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::InternalColumnMeta;
+use databend_common_catalog::plan::LineageMeta;
 use databend_common_catalog::plan::PartInfoPtr;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::plan::StreamColumnMeta;
 use databend_common_catalog::plan::TopK;
 use databend_common_catalog::plan::VirtualColumnInfo;
+use databend_common_catalog::runtime_filter_info::RuntimeFilterCombinator;
+use databend_common_catalog::table_context::ColumnMask;
+use databend_common_catalog::table_context::ScanDecodeError;
+use databend_common_catalog::table_context::ScanPartProfile;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::build_select_expr;
 use databend_common_expression::eval_function;
 use databend_common_expression::filter_helper::FilterHelpers;
+use databend_common_expression::types::AnyType;
 use databend_common_expression::types::BooleanType;
 use databend_common_expression::types::DataType;
 use databend_common_expression::BlockEntry;
 use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::BlockMetaInfoPtr;
 use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
 use databend_common_expression::ColumnId;
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchema;
 use databend_common_expression::Evaluator;
 use databend_common_expression::Expr;
-use databend_common_expression::FieldIndex;
 use databend_common_expression::FilterExecutor;
 use databend_common_expression::FunctionContext;
 use databend_common_expression::Scalar;
+use databend_common_expression::SelectExpr;
+use databend_common_expression::SortColumnDescription;
+use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TopKSorter;
 use databend_common_expression::Value;
 use databend_common_functions::BUILTIN_FUNCTIONS;
@@ -60,19 +79,35 @@ use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_sql::BloomIndexColumns;
 use databend_common_sql::IndexType;
-use xorf::BinaryFuse8;
+use log::warn;
 
 use super::fuse_source::fill_internal_column_meta;
 use super::native_data_source::NativeDataSource;
 use crate::fuse_part::FusePartInfo;
 use crate::io::AggIndexReader;
 use crate::io::BlockReader;
+use crate::io::SharedNativeReader;
 use crate::io::VirtualColumnReader;
 use crate::operations::read::data_source_with_meta::DataSourceWithMeta;
+use crate::operations::read::runtime_filter_prunner::combine_bloom_bitmaps;
+use crate::operations::read::runtime_filter_prunner::inlist_filter_to_point_queries;
+use crate::operations::read::runtime_filter_prunner::order_bloom_runtime_filters;
+use crate::operations::read::runtime_filter_prunner::resolve_bloom_runtime_filters;
 use crate::operations::read::runtime_filter_prunner::update_bitmap_with_bloom_filter;
+use crate::operations::read::runtime_filter_prunner::BloomRuntimeFilterGroup;
+use crate::pruning::BloomPruner;
+use crate::pruning::BloomPrunerCreator;
 use crate::DEFAULT_ROW_PER_PAGE;
 
+// A page whose prewhere columns are read and filtered, waiting for its remain columns.
+struct PendingPage {
+    arrays: Vec<(usize, Box<dyn Array>)>,
+    need_to_fill_data: bool,
+    filtered_count: Option<usize>,
+}
+
 pub struct NativeDeserializeDataTransform {
     ctx: Arc<dyn TableContext>,
     table_index: IndexType,
@@ -84,6 +119,51 @@ pub struct NativeDeserializeDataTransform {
     input: Arc<InputPort>,
     output: Arc<OutputPort>,
     output_data: Option<DataBlock>,
+    // The blocks split from a large block, waiting to be output after `output_data`.
+    output_queue: VecDeque<DataBlock>,
+    // The blocks waiting to be concatenated until `output_block_rows` is reached.
+    pending_blocks: Vec<DataBlock>,
+    pending_rows: usize,
+    // The number of rows of the output blocks, 0 means output the blocks as they are.
+    output_block_rows: usize,
+    // The maximum number of rows of the output blocks, larger blocks are split into
+    // slices of at most this many rows. 0 means no limit.
+    max_output_block_rows: usize,
+    // Materialize the constant columns of the output blocks as full columns,
+    // for the downstream operators that require full columns.
+    force_full_columns: bool,
+    // The scan is aborted if it's still decoding pages after the deadline, which is counted
+    // from the creation of the query.
+    deadline: Option<Instant>,
+    // Skip all the remaining parts if the build side of the join is empty.
+    skip_on_empty_build: bool,
+    // The limit without order by, all the scan processors of the table stop reading
+    // once they have emitted `limit` rows in total.
+    limit: Option<usize>,
+    // Return an error if the decoded rows of a part do not match its rows count,
+    // otherwise log a warning and drop the rows past the rows count.
+    error_on_rows_mismatch: bool,
+    // Return an error if some columns of a part still have pages left when the pages of
+    // another column are exhausted, otherwise the part is finished silently.
+    strict_page_count: bool,
+    // Wrap the meta of the output blocks with the lineage of their source parts.
+    lineage_meta: bool,
+    // Fill the rows failed to generate the virtual columns with NULL instead of aborting the scan.
+    null_on_virtual_column_error: bool,
+    // Yield to the scheduler after finishing this many parts in a row, 0 means no limit.
+    max_parts_per_tick: usize,
+    finished_parts_in_tick: usize,
+    // Collect the pages failed to be decoded to the context and drop the remaining rows
+    // of their parts, instead of aborting the scan.
+    collect_decode_errors: bool,
+    // Collect the time spent on each part to the context, along with the pages read and skipped.
+    part_profile: bool,
+    // The time current part is initialised, and the pages read and skipped before it.
+    part_profile_start: Option<(Instant, u64, u64)>,
+    // The masks of the columns, keyed by the index in `src_schema`. They are applied to
+    // the arrays right after decoding, so the prewhere filter, the runtime filters and
+    // the downstream operators only see the masked values.
+    column_masks: HashMap<usize, (DataType, ColumnMask)>,
     parts: VecDeque<PartInfoPtr>,
     chunks: VecDeque<NativeDataSource>,
 
@@ -98,11 +178,31 @@ pub struct NativeDeserializeDataTransform {
     prewhere_filter: Arc<Option<Expr>>,
     prewhere_virtual_columns: Option<Vec<VirtualColumnInfo>>,
     filter_executor: Option<FilterExecutor>,
+    // The prewhere filter split into the conjuncts of each prewhere column, keyed by the
+    // position of the last prewhere column they reference. They are evaluated one by one
+    // while reading the prewhere columns, empty means the filter is evaluated at once.
+    prewhere_stages: Vec<(usize, FilterExecutor)>,
+    // The number of rows the prewhere filter is evaluated on at a time,
+    // 0 means evaluate the whole page at once.
+    filter_batch_rows: usize,
+    // The number of rows the selection vectors of the filter executors are allocated for.
+    filter_row_per_page: usize,
+    // The number of remain columns decoded at a time on the shared IO runtime,
+    // 0 or 1 means decode them one by one on the processor thread.
+    decode_parallelism: usize,
+    // The page whose remain columns are being decoded in `async_process`, and the pages
+    // decoded for it, keyed by the column index.
+    pending_page: Option<PendingPage>,
+    decoded_pages: Option<HashMap<usize, Option<ArrowResult<Box<dyn Array>>>>>,
 
     skipped_page: usize,
     // The row offset of current part.
     // It's used to compute the row offset in one block (single data file in one segment).
     offset_in_part: usize,
+    // The column the rows of the pages are read from, the first column not nested.
+    page_rows_column: Option<ColumnId>,
+    // The rows of each page of current part, read once the part is initialised.
+    page_rows: Option<Vec<usize>>,
 
     read_columns: Vec<usize>,
     // Column ids are columns that have been read out,
@@ -114,19 +214,42 @@ pub struct NativeDeserializeDataTransform {
     // These columns need to fill in the default values.
     read_column_ids: HashSet<ColumnId>,
     top_k: Option<(TopK, TopKSorter, usize)>,
+    // Sort the rows of each page by the top-k column before emitting them, so the downstream
+    // sort merges roughly ordered blocks. The set of rows emitted is not changed.
+    top_k_approx_order: bool,
     // Identifies whether the ArrayIter has been initialised.
     inited: bool,
     // The ArrayIter of each columns to read Pages in order.
     array_iters: BTreeMap<usize, ArrayIter<'static>>,
+    // Reuse the ArrayIters of the finished parts for the next parts instead of rebuilding them.
+    reuse_array_iters: bool,
+    // The shared readers the ArrayIters of current part are built on, keyed by the column index.
+    shared_readers: BTreeMap<usize, SharedNativeReader>,
+    // The ArrayIters released by the finished parts, waiting to be reset for the next part.
+    array_iter_pool: BTreeMap<usize, (ArrayIter<'static>, SharedNativeReader)>,
     // The Page numbers of each ArrayIter can skip.
     array_skip_pages: BTreeMap<usize, usize>,
+    // The pages read and skipped of each column, keyed by the index in `src_schema`,
+    // they are reported to the metrics when the scan finishes.
+    pages_read: BTreeMap<usize, u64>,
+    pages_skipped: BTreeMap<usize, u64>,
 
     index_reader: Arc<Option<AggIndexReader>>,
     virtual_reader: Arc<Option<VirtualColumnReader>>,
 
     base_block_ids: Option<Scalar>,
 
-    cached_bloom_runtime_filter: Option<Vec<(FieldIndex, BinaryFuse8)>>,
+    cached_bloom_runtime_filter: Option<Vec<BloomRuntimeFilterGroup>>,
+
+    // Probe the bloom filter index of each part with the inlist runtime filters before
+    // reading it, the parts without any match are skipped entirely.
+    bloom_index_probe: bool,
+    table_schema: TableSchemaRef,
+    cached_bloom_index_pruners: Option<Vec<Arc<dyn BloomPruner + Send + Sync>>>,
+    // The bloom filter index of current part is waiting to be probed in `async_process`.
+    bloom_index_probe_pending: bool,
+    // The result of probing the bloom filter index of current part, None if not probed yet.
+    bloom_index_keep: Option<bool>,
 }
 
 impl NativeDeserializeDataTransform {
@@ -140,13 +263,110 @@ impl NativeDeserializeDataTransform {
         output: Arc<OutputPort>,
         index_reader: Arc<Option<AggIndexReader>>,
         virtual_reader: Arc<Option<VirtualColumnReader>>,
+        force_full_columns: bool,
+        filter_batch_rows: usize,
+        filter_row_per_page: usize,
+    ) -> Result<ProcessorPtr> {
+        Self::try_create(
+            ctx,
+            block_reader,
+            plan,
+            top_k,
+            input,
+            output,
+            index_reader,
+            virtual_reader,
+            force_full_columns,
+            filter_batch_rows,
+            filter_row_per_page,
+            false,
+        )
+    }
+
+    /// Create a transform that only decodes the projected columns of the parts into full blocks,
+    /// the prewhere filter, top-k, limit and runtime filters of the plan are all ignored.
+    /// It's used to bulk read the native blocks of a table, e.g. to export the table.
+    /// The internal columns and the stream columns are still filled if the reader queries them.
+    pub fn create_raw(
+        ctx: Arc<dyn TableContext>,
+        block_reader: Arc<BlockReader>,
+        plan: &DataSourcePlan,
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+    ) -> Result<ProcessorPtr> {
+        Self::try_create(
+            ctx,
+            block_reader,
+            plan,
+            None,
+            input,
+            output,
+            Arc::new(None),
+            Arc::new(None),
+            false,
+            0,
+            DEFAULT_ROW_PER_PAGE,
+            true,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn try_create(
+        ctx: Arc<dyn TableContext>,
+        block_reader: Arc<BlockReader>,
+        plan: &DataSourcePlan,
+        top_k: Option<TopK>,
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        index_reader: Arc<Option<AggIndexReader>>,
+        virtual_reader: Arc<Option<VirtualColumnReader>>,
+        force_full_columns: bool,
+        filter_batch_rows: usize,
+        filter_row_per_page: usize,
+        raw: bool,
     ) -> Result<ProcessorPtr> {
         let scan_progress = ctx.get_scan_progress();
+        let output_block_rows = ctx.get_settings().get_storage_native_output_block_rows()? as usize;
+        let max_output_block_rows =
+            ctx.get_settings().get_storage_native_max_output_block_rows()? as usize;
+        let error_on_rows_mismatch =
+            ctx.get_settings().get_storage_native_part_rows_mismatch()? == "error";
+        let strict_page_count = ctx.get_settings().get_storage_native_strict_page_count()?;
+        let lineage_meta = ctx.get_settings().get_storage_native_lineage_meta()?;
+        let null_on_virtual_column_error =
+            ctx.get_settings().get_storage_native_virtual_column_error()? == "null";
+        let max_parts_per_tick =
+            ctx.get_settings().get_storage_native_max_parts_per_tick()? as usize;
+        let collect_decode_errors =
+            ctx.get_settings().get_storage_native_decode_error()? == "collect";
+        let part_profile = ctx.get_settings().get_storage_native_part_profile()?;
+        let skip_on_empty_build =
+            !raw && ctx.get_settings().get_runtime_filter_empty_build_skip()?;
+        let bloom_index_probe = !raw && ctx.get_settings().get_runtime_filter_bloom_index_probe()?;
+        let reuse_array_iters = ctx.get_settings().get_storage_native_reuse_array_iters()?;
+        let decode_parallelism =
+            ctx.get_settings().get_storage_native_decode_parallelism()? as usize;
+        let split_prewhere = ctx.get_settings().get_storage_native_split_prewhere()?;
+        let top_k_approx_order = ctx.get_settings().get_storage_native_topk_approx_order()?;
+        // The raw transform reads all the columns without any filter.
+        let push_downs = if raw { None } else { plan.push_downs.as_ref() };
+        let deadline = match ctx
+            .get_settings()
+            .get_max_storage_scan_time_in_milliseconds()?
+        {
+            0 => None,
+            // The scan time is counted from the creation of the query, not the build of the
+            // pipeline, which may happen long after the query starts.
+            millis => {
+                let elapsed = ctx.get_created_time().elapsed().unwrap_or_default();
+                Some(Instant::now() + Duration::from_millis(millis).saturating_sub(elapsed))
+            }
+        };
 
         let mut src_schema: DataSchema = (block_reader.schema().as_ref()).into();
 
         let mut prewhere_columns: Vec<usize> =
-            match PushDownInfo::prewhere_of_push_downs(plan.push_downs.as_ref()) {
+            match PushDownInfo::prewhere_of_push_downs(push_downs) {
                 None => (0..src_schema.num_fields()).collect(),
                 Some(v) => {
                     let projected_schema = v
@@ -186,7 +406,7 @@ impl NativeDeserializeDataTransform {
                     }
                     src_schema = DataSchema::new(fields);
                 }
-                if let Some(prewhere) = &push_downs.prewhere {
+                if let Some(prewhere) = push_downs.prewhere.as_ref().filter(|_| !raw) {
                     if let Some(virtual_columns) = &prewhere.virtual_columns {
                         for virtual_column in virtual_columns {
                             prewhere_columns
@@ -211,7 +431,7 @@ impl NativeDeserializeDataTransform {
 
         let func_ctx = ctx.get_function_context()?;
         let prewhere_schema = src_schema.project(&prewhere_columns);
-        let prewhere_filter = Self::build_prewhere_filter_expr(plan, &prewhere_schema)?;
+        let prewhere_filter = Self::build_prewhere_filter_expr(push_downs, &prewhere_schema)?;
 
         let filter_executor = if let Some(expr) = prewhere_filter.as_ref() {
             let (select_expr, has_or) = build_select_expr(expr);
@@ -219,7 +439,7 @@ impl NativeDeserializeDataTransform {
                 select_expr,
                 func_ctx.clone(),
                 has_or,
-                DEFAULT_ROW_PER_PAGE,
+                filter_row_per_page,
                 None,
                 &BUILTIN_FUNCTIONS,
                 false,
@@ -228,10 +448,35 @@ impl NativeDeserializeDataTransform {
             None
         };
 
+        // The virtual columns of prewhere are generated after all the prewhere columns are read,
+        // so the filter can't be split if they exist.
+        let prewhere_stages = match prewhere_filter.as_ref() {
+            Some(expr)
+                if split_prewhere
+                    && prewhere_virtual_columns.is_none()
+                    && prewhere_columns.len() > 1 =>
+            {
+                let stages = Self::build_prewhere_stages(expr, &func_ctx, filter_row_per_page);
+                if stages.len() > 1 { stages } else { vec![] }
+            }
+            _ => vec![],
+        };
+
         let mut output_schema = plan.schema().as_ref().clone();
         output_schema.remove_internal_fields();
         let output_schema: DataSchema = (&output_schema).into();
 
+        let limit = PushDownInfo::scan_limit_of_push_downs(push_downs);
+
+        let column_masks = ctx
+            .get_column_masks(plan.table_index)
+            .into_iter()
+            .filter_map(|(name, mask)| {
+                let index = src_schema.index_of(&name).ok()?;
+                Some((index, (src_schema.field(index).data_type().clone(), mask)))
+            })
+            .collect();
+
         let mut column_leaves = Vec::with_capacity(block_reader.project_column_nodes.len());
         for column_node in &block_reader.project_column_nodes {
             let leaves: Vec<ColumnDescriptor> = column_node
@@ -241,6 +486,11 @@ impl NativeDeserializeDataTransform {
                 .collect::<Vec<_>>();
             column_leaves.push(leaves);
         }
+        let page_rows_column = block_reader
+            .project_column_nodes
+            .iter()
+            .find(|column_node| !column_node.is_nested)
+            .and_then(|column_node| column_node.leaf_column_ids.first().copied());
 
         Ok(ProcessorPtr::create(Box::new(
             NativeDeserializeDataTransform {
@@ -253,6 +503,25 @@ impl NativeDeserializeDataTransform {
                 input,
                 output,
                 output_data: None,
+                output_queue: VecDeque::new(),
+                pending_blocks: vec![],
+                pending_rows: 0,
+                output_block_rows,
+                max_output_block_rows,
+                force_full_columns,
+                deadline,
+                skip_on_empty_build,
+                limit,
+                error_on_rows_mismatch,
+                strict_page_count,
+                lineage_meta,
+                null_on_virtual_column_error,
+                max_parts_per_tick,
+                finished_parts_in_tick: 0,
+                collect_decode_errors,
+                part_profile,
+                part_profile_start: None,
+                column_masks,
                 parts: VecDeque::new(),
                 chunks: VecDeque::new(),
 
@@ -266,30 +535,51 @@ impl NativeDeserializeDataTransform {
                 prewhere_filter,
                 prewhere_virtual_columns,
                 filter_executor,
+                prewhere_stages,
+                filter_batch_rows,
+                filter_row_per_page,
+                decode_parallelism,
+                pending_page: None,
+                decoded_pages: None,
                 skipped_page: 0,
                 top_k,
+                top_k_approx_order,
                 read_columns: vec![],
                 read_column_ids: HashSet::new(),
                 inited: false,
                 array_iters: BTreeMap::new(),
+                reuse_array_iters,
+                shared_readers: BTreeMap::new(),
+                array_iter_pool: BTreeMap::new(),
                 array_skip_pages: BTreeMap::new(),
+                pages_read: BTreeMap::new(),
+                pages_skipped: BTreeMap::new(),
                 offset_in_part: 0,
+                page_rows_column,
+                page_rows: None,
 
                 index_reader,
                 virtual_reader,
 
                 base_block_ids: plan.base_block_ids.clone(),
-                cached_bloom_runtime_filter: None,
+                // The raw transform has no runtime filters to fetch.
+                cached_bloom_runtime_filter: raw.then(Vec::new),
+
+                bloom_index_probe,
+                table_schema: plan.source_info.schema(),
+                cached_bloom_index_pruners: None,
+                bloom_index_probe_pending: false,
+                bloom_index_keep: None,
             },
         )))
     }
 
     fn build_prewhere_filter_expr(
-        plan: &DataSourcePlan,
+        push_downs: Option<&PushDownInfo>,
         schema: &DataSchema,
     ) -> Result<Arc<Option<Expr>>> {
         Ok(Arc::new(
-            PushDownInfo::prewhere_of_push_downs(plan.push_downs.as_ref()).map(|v| {
+            PushDownInfo::prewhere_of_push_downs(push_downs).map(|v| {
                 v.filter
                     .as_expr(&BUILTIN_FUNCTIONS)
                     .project_column_ref(|name| schema.column_with_name(name).unwrap().0)
@@ -297,25 +587,127 @@ impl NativeDeserializeDataTransform {
         ))
     }
 
+    /// Split the prewhere filter into its conjuncts and group them by the last prewhere column
+    /// they reference, so each group can be evaluated once the column is read.
+    fn build_prewhere_stages(
+        expr: &Expr,
+        func_ctx: &FunctionContext,
+        row_per_page: usize,
+    ) -> Vec<(usize, FilterExecutor)> {
+        fn split_conjuncts(expr: &Expr, conjuncts: &mut Vec<Expr>) {
+            match expr {
+                Expr::FunctionCall { function, args, .. }
+                    if matches!(function.signature.name.as_str(), "and" | "and_filters") =>
+                {
+                    for arg in args {
+                        split_conjuncts(arg, conjuncts);
+                    }
+                }
+                _ => conjuncts.push(expr.clone()),
+            }
+        }
+
+        let mut conjuncts = vec![];
+        split_conjuncts(expr, &mut conjuncts);
+
+        let mut groups: BTreeMap<usize, (Vec<SelectExpr>, bool)> = BTreeMap::new();
+        for conjunct in conjuncts {
+            // The column refs are the positions in the prewhere columns.
+            let position = conjunct.column_refs().into_keys().max().unwrap_or(0);
+            let (select_expr, has_or) = build_select_expr(&conjunct);
+            let group = groups.entry(position).or_default();
+            group.0.push(select_expr);
+            group.1 |= has_or;
+        }
+
+        groups
+            .into_iter()
+            .map(|(position, (mut select_exprs, has_or))| {
+                let select_expr = if select_exprs.len() == 1 {
+                    select_exprs.pop().unwrap()
+                } else {
+                    SelectExpr::And(select_exprs)
+                };
+                let filter_executor = FilterExecutor::new(
+                    select_expr,
+                    func_ctx.clone(),
+                    has_or,
+                    row_per_page,
+                    None,
+                    &BUILTIN_FUNCTIONS,
+                    false,
+                );
+                (position, filter_executor)
+            })
+            .collect()
+    }
+
     fn add_block(&mut self, data_block: DataBlock) -> Result<()> {
         let rows = data_block.num_rows();
         if rows == 0 {
             return Ok(());
         }
+        let data_block = if self.force_full_columns {
+            data_block.convert_to_full()
+        } else {
+            data_block
+        };
         let progress_values = ProgressValues {
             rows,
             bytes: data_block.memory_size(),
         };
         self.scan_progress.incr(&progress_values);
-        self.output_data = Some(data_block);
+        if self.limit.is_some() {
+            self.ctx.add_limited_scan_rows(self.table_index, rows);
+        }
+        // Blocks carrying `InternalColumnMeta` or stream meta are bound to their own part,
+        // they are output as they are to keep the meta consistent with the rows.
+        if self.output_block_rows > 0 && data_block.get_meta().is_none() {
+            self.pending_rows += rows;
+            self.pending_blocks.push(data_block);
+            Ok(())
+        } else {
+            self.output_block(data_block)
+        }
+    }
+
+    /// Split the output block by `max_output_block_rows`.
+    fn output_block(&mut self, data_block: DataBlock) -> Result<()> {
+        for data_block in split_output_block(data_block, self.max_output_block_rows)? {
+            if self.output_data.is_none() {
+                self.output_data = Some(data_block);
+            } else {
+                self.output_queue.push_back(data_block);
+            }
+        }
         Ok(())
     }
 
+    fn need_flush_pending_blocks(&self) -> bool {
+        self.output_block_rows > 0 && self.pending_rows >= self.output_block_rows
+    }
+
+    /// Concatenate the pending blocks and output `output_block_rows` rows,
+    /// the last block may be smaller if there is no more data.
+    fn flush_pending_blocks(&mut self) -> Result<()> {
+        let blocks = std::mem::take(&mut self.pending_blocks);
+        let block = DataBlock::concat(&blocks)?;
+        let num_rows = block.num_rows();
+        let output_rows = num_rows.min(self.output_block_rows);
+        if num_rows > output_rows {
+            self.pending_blocks.push(block.slice(output_rows..num_rows));
+        }
+        self.pending_rows = num_rows - output_rows;
+        self.output_block(block.slice(0..output_rows))
+    }
+
     /// If the virtual column has already generated, add it directly,
-    /// otherwise extract it from the source column
+    /// otherwise extract it from the source column.
+    /// The `selection` is the rows of the chunks kept in the block, if the block is filtered.
     fn add_virtual_columns(
         &self,
         chunks: Vec<(usize, Box<dyn Array>)>,
+        selection: Option<&[u32]>,
         schema: &DataSchema,
         virtual_columns: &Option<Vec<VirtualColumnInfo>>,
         block: &mut DataBlock,
@@ -330,13 +722,15 @@ impl NativeDeserializeDataTransform {
                 {
                     let data_type: DataType =
                         (*self.src_schema.field(src_index).data_type()).clone();
-                    let column = BlockEntry::new(
-                        data_type.clone(),
-                        Value::Column(Column::from_arrow(array.as_ref(), &data_type)),
-                    );
+                    let column = Column::from_arrow(array.as_ref(), &data_type);
+                    let column = match selection {
+                        Some(selection) => column.take(selection, &mut None),
+                        None => column,
+                    };
+                    let num_rows = column.len();
+                    let column = BlockEntry::new(data_type, Value::Column(column));
                     // If the source column is the default value, num_rows may be zero
                     if block.num_columns() > 0 && block.num_rows() == 0 {
-                        let num_rows = array.len();
                         let mut columns = block.columns().to_vec();
                         columns.push(column);
                         *block = DataBlock::new(columns, num_rows);
@@ -353,14 +747,40 @@ impl NativeDeserializeDataTransform {
                     DataType::String,
                 );
 
-                let (value, data_type) = eval_function(
+                let (value, data_type) = match eval_function(
                     None,
                     "get_by_keypath",
-                    [src_arg, path_arg],
+                    [src_arg, path_arg.clone()],
                     &self.func_ctx,
                     block.num_rows(),
                     &BUILTIN_FUNCTIONS,
-                )?;
+                ) {
+                    Ok(result) => result,
+                    Err(err) if self.null_on_virtual_column_error => {
+                        warn!(
+                            "Failed to generate virtual column {}, fill the failed rows with NULL: {}",
+                            virtual_column.name, err
+                        );
+                        let data_type = self
+                            .src_schema
+                            .field_with_name(&virtual_column.name)?
+                            .data_type()
+                            .clone();
+                        let column = self.eval_virtual_column_by_row(
+                            source,
+                            path_arg,
+                            &data_type,
+                            block.num_rows(),
+                        );
+                        (Value::Column(column), data_type)
+                    }
+                    Err(err) => {
+                        return Err(err.add_message(format!(
+                            "generate virtual column {} from source column {} with key paths {} failed:",
+                            virtual_column.name, virtual_column.source_name, virtual_column.key_paths
+                        )));
+                    }
+                };
 
                 let column = BlockEntry::new(data_type, value);
                 block.add_column(column);
@@ -370,14 +790,41 @@ impl NativeDeserializeDataTransform {
         Ok(())
     }
 
+    /// Generate the virtual column row by row, the rows failed to generate are filled with NULL.
+    fn eval_virtual_column_by_row(
+        &self,
+        source: &BlockEntry,
+        path_arg: (Value<AnyType>, DataType),
+        data_type: &DataType,
+        num_rows: usize,
+    ) -> Column {
+        let mut builder = ColumnBuilder::with_capacity(data_type, num_rows);
+        for row in 0..num_rows {
+            let value = source.value.index(row).unwrap().to_owned();
+            let src_arg = (Value::Scalar(value), source.data_type.clone());
+            match eval_function(
+                None,
+                "get_by_keypath",
+                [src_arg, path_arg.clone()],
+                &self.func_ctx,
+                1,
+                &BUILTIN_FUNCTIONS,
+            ) {
+                Ok((value, _)) => builder.push(value.index(0).unwrap()),
+                Err(_) => builder.push_default(),
+            }
+        }
+        builder.build()
+    }
+
     /// If the top-k or all prewhere columns are default values, check if the filter is met,
     /// and if not, ignore all pages, otherwise continue without repeating the check for subsequent processes.
     fn check_default_values(&mut self) -> Result<bool> {
         if self.prewhere_columns.len() > 1 {
             if let Some((_, sorter, index)) = self.top_k.as_mut() {
                 if !self.array_iters.contains_key(index) {
-                    let default_val = self.block_reader.default_vals[*index].clone();
-                    if sorter.never_match_value(&default_val) {
+                    let default_val = &self.block_reader.default_vals[*index];
+                    if sorter.never_match_value(default_val) {
                         return Ok(true);
                     }
                 }
@@ -398,28 +845,27 @@ impl NativeDeserializeDataTransform {
             };
 
             if *all_defaults && all_virtual_defaults {
-                let columns = &mut self
+                // The virtual columns are after the other columns, they are extracted
+                // from the default values of their source columns.
+                let columns = self
                     .prewhere_columns
                     .iter()
+                    .filter(|index| **index < self.block_reader.default_vals.len())
                     .map(|index| {
                         let data_type = self.src_schema.field(*index).data_type().clone();
                         let default_val = &self.block_reader.default_vals[*index];
                         BlockEntry::new(data_type, Value::Scalar(default_val.to_owned()))
                     })
                     .collect::<Vec<_>>();
+                let mut prewhere_block = DataBlock::new(columns, 1);
+                self.add_virtual_columns(
+                    vec![],
+                    None,
+                    &self.prewhere_schema,
+                    &self.prewhere_virtual_columns,
+                    &mut prewhere_block,
+                )?;
 
-                if let Some(ref prewhere_virtual_columns) = &self.prewhere_virtual_columns {
-                    for virtual_column in prewhere_virtual_columns {
-                        // if the source column is default value, the virtual column is always Null.
-                        let column = BlockEntry::new(
-                            DataType::from(&*virtual_column.data_type),
-                            Value::Scalar(Scalar::Null),
-                        );
-                        columns.push(column);
-                    }
-                }
-
-                let prewhere_block = DataBlock::new(columns.to_vec(), 1);
                 let evaluator = Evaluator::new(&prewhere_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
                 let filter = evaluator
                     .run(filter)
@@ -438,9 +884,10 @@ impl NativeDeserializeDataTransform {
                         let num_rows = part.nums_rows;
 
                         let data_type = self.src_schema.field(*index).data_type().clone();
-                        let default_val = self.block_reader.default_vals[*index].clone();
-                        let value = Value::Scalar(default_val);
-                        let col = value.convert_to_full_column(&data_type, num_rows);
+                        let default_val = &self.block_reader.default_vals[*index];
+                        let col =
+                            ColumnBuilder::repeat(&default_val.as_ref(), num_rows, &data_type)
+                                .build();
                         let mut bitmap = MutableBitmap::from_len_set(num_rows);
                         sorter.push_column(&col, &mut bitmap);
                     }
@@ -451,36 +898,363 @@ impl NativeDeserializeDataTransform {
     }
 
     /// No more data need to read, finish process.
+    /// Pop the finished part, and count it for yielding to the scheduler.
+    fn pop_finished_part(&mut self) -> PartInfoPtr {
+        self.finished_parts_in_tick += 1;
+        self.parts.pop_front().unwrap()
+    }
+
     fn finish_process(&mut self) -> Result<()> {
         let _ = self.chunks.pop_front();
-        let _ = self.parts.pop_front().unwrap();
+        let part = self.pop_finished_part();
+        self.record_part_profile(&part)?;
 
         self.inited = false;
-        self.array_iters.clear();
+        self.clear_array_iters();
         self.array_skip_pages.clear();
         self.offset_in_part = 0;
+        self.page_rows = None;
         self.read_column_ids.clear();
+        self.bloom_index_keep = None;
+        Ok(())
+    }
+
+    /// Collect the profile of the finished part to the context, if it has been initialised
+    /// while `part_profile` is set.
+    fn record_part_profile(&mut self, part: &PartInfoPtr) -> Result<()> {
+        if let Some((start, pages_read, pages_skipped)) = self.part_profile_start.take() {
+            let fuse_part = FusePartInfo::from_part(part)?;
+            let profile = ScanPartProfile {
+                part_location: fuse_part.location.clone(),
+                elapsed: start.elapsed(),
+                pages_read: self.pages_read.values().sum::<u64>() - pages_read,
+                pages_skipped: self.pages_skipped.values().sum::<u64>() - pages_skipped,
+            };
+            self.ctx.add_scan_part_profile(self.table_index, profile);
+        }
         Ok(())
     }
 
+    /// The remain columns whose current pages are decoded concurrently in `async_process`,
+    /// empty if the parallelism is disabled or there's at most one column to decode.
+    ///
+    /// All the remain columns are needed once the filters pass, so the decoding order
+    /// doesn't matter.
+    fn parallel_remain_columns(&self) -> Vec<usize> {
+        if self.decode_parallelism <= 1 {
+            return vec![];
+        }
+        let indices = self
+            .remain_columns
+            .iter()
+            .filter(|index| self.array_iters.contains_key(index))
+            .copied()
+            .collect::<Vec<_>>();
+        if indices.len() <= 1 {
+            return vec![];
+        }
+        indices
+    }
+
+    /// Read the remain columns of the current page, the pages already decoded in
+    /// `async_process` are taken from `decoded_pages`, then filter the block and output it.
+    fn read_remain_columns(
+        &mut self,
+        page: PendingPage,
+        mut decoded_pages: HashMap<usize, Option<ArrowResult<Box<dyn Array>>>>,
+    ) -> Result<()> {
+        let PendingPage {
+            mut arrays,
+            mut need_to_fill_data,
+            filtered_count,
+        } = page;
+        let mut decode_error = None;
+        for index in self.remain_columns.iter() {
+            if let Some(array_iter) = self.array_iters.get_mut(index) {
+                let skip_pages = self.array_skip_pages.get(index).unwrap();
+                let page = match decoded_pages.remove(index) {
+                    Some(page) => page,
+                    None => array_iter.nth(*skip_pages),
+                };
+
+                match page {
+                    Some(Err(err)) => {
+                        decode_error = Some((*index, err.into()));
+                        break;
+                    }
+                    Some(Ok(array)) => {
+                        self.read_columns.push(*index);
+                        *self.pages_read.entry(*index).or_default() += 1;
+                        let array = mask_array(&self.column_masks, *index, array)?;
+                        arrays.push((*index, array));
+                        self.array_skip_pages.insert(*index, 0);
+                    }
+                    None => {
+                        return self.finish_process_with_all_pages();
+                    }
+                }
+            } else {
+                need_to_fill_data = true;
+            }
+        }
+        if let Some((index, err)) = decode_error {
+            return self.handle_decode_error(index, err);
+        }
+
+        // Drop the rows past the rows count of the part, the part is finished with this page.
+        let num_rows = arrays.first().map_or(0, |(_, array)| array.len());
+        let keep_rows = self.page_rows_within_part(num_rows)?;
+        let truncated = keep_rows < num_rows;
+        let filtered_count = match filtered_count {
+            Some(count) if truncated => {
+                let filter_executor = self.filter_executor.as_mut().unwrap();
+                let selection = &mut filter_executor.mut_true_selection()[0..count];
+                let kept = selection
+                    .iter()
+                    .copied()
+                    .filter(|idx| (*idx as usize) < keep_rows)
+                    .collect::<Vec<_>>();
+                selection[0..kept.len()].copy_from_slice(&kept);
+                Some(kept.len())
+            }
+            filtered_count => filtered_count,
+        };
+        if truncated {
+            if keep_rows == 0 || filtered_count == Some(0) {
+                self.offset_in_part += keep_rows;
+                return self.finish_process();
+            }
+            arrays = arrays
+                .into_iter()
+                .map(|(index, array)| (index, array.sliced(0, keep_rows)))
+                .collect();
+        }
+
+        let block = self.block_reader.build_block(arrays.clone(), None)?;
+        // Step 6: fill missing field default value if need
+        let mut block = if need_to_fill_data {
+            self.block_reader
+                .fill_missing_native_column_values(block, &self.read_column_ids)?
+        } else {
+            block
+        };
+
+        let origin_num_rows = block.num_rows();
+        let mut block = if let Some(count) = &filtered_count {
+            let filter_executor = self.filter_executor.as_mut().unwrap();
+            filter_executor.take(block, origin_num_rows, *count)?
+        } else {
+            block
+        };
+
+        // Step 7: Add optional virtual columns, they are generated after the filter is applied,
+        // so the source values of the rows filtered out are not parsed.
+        // The selection is in the order of the rows taken, as `take` sorts it if needed.
+        let selection = filtered_count
+            .filter(|count| *count != origin_num_rows)
+            .map(|count| &self.filter_executor.as_ref().unwrap().true_selection()[0..count]);
+        self.add_virtual_columns(
+            arrays,
+            selection,
+            &self.src_schema,
+            &self.virtual_columns,
+            &mut block,
+        )?;
+
+        // Sort the rows of the page by the top-k column, the top-k sorter has only removed
+        // the rows that can't make the heap, the rows kept are still in the page order.
+        // The blocks carrying meta bound to the row positions are kept in the page order.
+        if self.top_k_approx_order
+            && !self.block_reader.query_internal_columns()
+            && !self.block_reader.update_stream_columns()
+        {
+            if let Some((top_k, _, index)) = &self.top_k {
+                let description = SortColumnDescription {
+                    offset: *index,
+                    asc: top_k.asc,
+                    nulls_first: top_k.nulls_first,
+                    is_nullable: self.src_schema.field(*index).is_nullable(),
+                };
+                block = DataBlock::sort(&block, &[description], None)?;
+            }
+        }
+
+        // Step 8: Fill `InternalColumnMeta` as `DataBlock.meta` if query internal columns,
+        // `TransformAddInternalColumns` will generate internal columns using `InternalColumnMeta` in next pipeline.
+        let mut block = block.resort(&self.src_schema, &self.output_schema)?;
+        if self.block_reader.query_internal_columns() {
+            let offsets = if let Some(count) = filtered_count {
+                let filter_executor = self.filter_executor.as_mut().unwrap();
+                filter_executor.mut_true_selection()[0..count]
+                    .iter()
+                    .map(|idx| *idx as usize + self.offset_in_part)
+                    .collect::<Vec<_>>()
+            } else {
+                (self.offset_in_part..self.offset_in_part + origin_num_rows).collect()
+            };
+
+            let fuse_part = FusePartInfo::from_part(&self.parts[0])?;
+            block = fill_internal_column_meta(
+                block,
+                fuse_part,
+                Some(offsets),
+                self.base_block_ids.clone(),
+            )?;
+        }
+
+        if self.block_reader.update_stream_columns() {
+            let inner_meta = block.take_meta();
+            let fuse_part = FusePartInfo::from_part(&self.parts[0])?;
+            let meta = gen_mutation_stream_meta(inner_meta, &fuse_part.location)?;
+            block = block.add_meta(Some(Box::new(meta)))?;
+        }
+
+        if self.lineage_meta {
+            let fuse_part = FusePartInfo::from_part(&self.parts[0])?;
+            block = add_lineage_meta(block, fuse_part)?;
+        }
+
+        // Step 9: Add the block to output data
+        self.offset_in_part += origin_num_rows;
+        self.add_block(block)?;
+        if truncated {
+            return self.finish_process();
+        }
+        Ok(())
+    }
+
+    /// Clear the ArrayIters of the finished part, the ones built on the shared readers
+    /// are kept in the pool to be reset for the next part.
+    fn clear_array_iters(&mut self) {
+        let array_iters = std::mem::take(&mut self.array_iters);
+        for (index, array_iter) in array_iters {
+            if let Some(shared_reader) = self.shared_readers.remove(&index) {
+                self.array_iter_pool.insert(index, (array_iter, shared_reader));
+            }
+        }
+        self.shared_readers.clear();
+    }
+
+    /// Finish all the remaining parts without decoding any pages, either the build side
+    /// of the join is empty and no rows can match, or the limit has been reached.
+    fn finish_remaining_parts(&mut self) -> Result<()> {
+        // The current part may have been partially decoded.
+        if let Some(part) = self.parts.front().cloned() {
+            self.record_part_profile(&part)?;
+        }
+        self.chunks.clear();
+        self.parts.clear();
+
+        self.inited = false;
+        self.clear_array_iters();
+        self.array_skip_pages.clear();
+        self.offset_in_part = 0;
+        self.page_rows = None;
+        self.read_column_ids.clear();
+        self.bloom_index_keep = None;
+        Ok(())
+    }
+
+    /// All pages of the part have been decoded, check if the decoded rows match the
+    /// rows count of the part before finishing it, a mismatch means the part is corrupted
+    /// and the row offsets of internal columns are misaligned.
+    fn finish_process_with_all_pages(&mut self) -> Result<()> {
+        if self.strict_page_count {
+            self.check_pages_exhausted()?;
+        }
+        let fuse_part = FusePartInfo::from_part(&self.parts[0])?;
+        let expected_offset = self.part_end_offset(fuse_part);
+        if self.offset_in_part != expected_offset {
+            let message = format!(
+                "The decoded rows of part {} do not match its rows count, expected row offset {}, but got {}",
+                fuse_part.location, expected_offset, self.offset_in_part
+            );
+            if self.error_on_rows_mismatch {
+                return Err(ErrorCode::StorageOther(message));
+            }
+            warn!("{}", message);
+        }
+        self.finish_process()
+    }
+
+    /// The row offset current part ends at, according to its rows count.
+    fn part_end_offset(&self, fuse_part: &FusePartInfo) -> usize {
+        match fuse_part.range() {
+            Some(range) => fuse_part.page_row_offset(self.page_rows.as_deref(), range.end),
+            None => fuse_part.nums_rows,
+        }
+    }
+
+    /// The number of rows of current page within the rows count of the part. The pages of a
+    /// corrupted part may have more rows than it declares, the rows past it are dropped with
+    /// a warning, or an error is returned if `error_on_rows_mismatch` is set.
+    fn page_rows_within_part(&self, num_rows: usize) -> Result<usize> {
+        let fuse_part = FusePartInfo::from_part(&self.parts[0])?;
+        let end_offset = self.part_end_offset(fuse_part);
+        if self.offset_in_part + num_rows <= end_offset {
+            return Ok(num_rows);
+        }
+        let message = format!(
+            "The decoded rows of part {} exceed its rows count, expected row offset {}, but got {}",
+            fuse_part.location,
+            end_offset,
+            self.offset_in_part + num_rows
+        );
+        if self.error_on_rows_mismatch {
+            return Err(ErrorCode::StorageOther(message));
+        }
+        warn!("{}, drop the rows past it", message);
+        Ok(end_offset.saturating_sub(self.offset_in_part))
+    }
+
+    /// The pages of a column are exhausted, check that the pages of the other columns are
+    /// exhausted as well. The columns of a part always have the same number of pages, the
+    /// columns decoded a page in current step or with pages left mean the file is truncated.
+    fn check_pages_exhausted(&mut self) -> Result<()> {
+        let mut remaining = self.read_columns.clone();
+        for (index, array_iter) in self.array_iters.iter_mut() {
+            if remaining.contains(index) {
+                continue;
+            }
+            let skip_pages = self.array_skip_pages.get(index).copied().unwrap_or_default();
+            if array_iter.nth(skip_pages).is_some() {
+                remaining.push(*index);
+            }
+        }
+        if remaining.is_empty() {
+            return Ok(());
+        }
+
+        remaining.sort();
+        let columns = remaining
+            .iter()
+            .map(|index| self.src_schema.field(*index).name().as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let fuse_part = FusePartInfo::from_part(&self.parts[0])?;
+        Err(ErrorCode::StorageOther(format!(
+            "The columns of part {} disagree on the number of pages, columns [{}] still have pages left after row offset {}",
+            fuse_part.location, columns, self.offset_in_part
+        )))
+    }
+
     /// All columns are default values, not need to read.
     fn finish_process_with_default_values(&mut self) -> Result<()> {
         let _ = self.chunks.pop_front();
-        let part = self.parts.pop_front().unwrap();
+        let part = self.pop_finished_part();
+        self.record_part_profile(&part)?;
         let fuse_part = FusePartInfo::from_part(&part)?;
 
         let num_rows = fuse_part.nums_rows;
         let mut data_block = self.block_reader.build_default_values_block(num_rows)?;
-        if let Some(ref virtual_columns) = &self.virtual_columns {
-            for virtual_column in virtual_columns {
-                // if the source column is default value, the virtual column is always Null.
-                let column = BlockEntry::new(
-                    DataType::from(&*virtual_column.data_type),
-                    Value::Scalar(Scalar::Null),
-                );
-                data_block.add_column(column);
-            }
-        }
+        // The source columns are the default values, extract the virtual columns from them.
+        self.add_virtual_columns(
+            vec![],
+            None,
+            &self.src_schema,
+            &self.virtual_columns,
+            &mut data_block,
+        )?;
 
         if self.block_reader.query_internal_columns() {
             data_block = fill_internal_column_meta(
@@ -497,35 +1271,198 @@ impl NativeDeserializeDataTransform {
             data_block = data_block.add_meta(Some(Box::new(meta)))?;
         }
 
+        if self.lineage_meta {
+            data_block = add_lineage_meta(data_block, fuse_part)?;
+        }
+
         let data_block = data_block.resort(&self.src_schema, &self.output_schema)?;
         self.add_block(data_block)?;
 
         self.inited = false;
-        self.array_iters.clear();
+        self.clear_array_iters();
         self.array_skip_pages.clear();
         self.offset_in_part = 0;
+        self.page_rows = None;
         self.read_column_ids.clear();
+        self.bloom_index_keep = None;
         Ok(())
     }
 
     /// Empty projection use empty block.
     fn finish_process_with_empty_block(&mut self) -> Result<()> {
         let _ = self.chunks.pop_front();
-        let part = self.parts.pop_front().unwrap();
+        let part = self.pop_finished_part();
         let fuse_part = FusePartInfo::from_part(&part)?;
 
         let num_rows = fuse_part.nums_rows;
         let data_block = DataBlock::new(vec![], num_rows);
-        let data_block = if self.block_reader.query_internal_columns() {
+        let mut data_block = if self.block_reader.query_internal_columns() {
             fill_internal_column_meta(data_block, fuse_part, None, self.base_block_ids.clone())?
         } else {
             data_block
         };
+        if self.lineage_meta {
+            data_block = add_lineage_meta(data_block, fuse_part)?;
+        }
 
         self.add_block(data_block)?;
         Ok(())
     }
 
+    /// The block is stored in parquet format, deserialize the whole block and apply the
+    /// same filters as the native pages to it: the prewhere filter, the top-k and the bloom
+    /// runtime filters.
+    fn finish_process_with_parquet_data(&mut self) -> Result<()> {
+        let data = match self.chunks.pop_front() {
+            Some(NativeDataSource::Parquet(data)) => data,
+            _ => unreachable!(),
+        };
+        let part = self.pop_finished_part();
+        let fuse_part = FusePartInfo::from_part(&part)?;
+
+        if let Some(((_top_k, sorter, _index), min_max)) =
+            self.top_k.as_mut().zip(fuse_part.sort_min_max.as_ref())
+        {
+            if sorter.never_match(min_max) {
+                return Ok(());
+            }
+        }
+
+        let columns_chunks = data.columns_chunks()?;
+        let block = self.block_reader.deserialize_parquet_chunks_with_buffer(
+            &fuse_part.location,
+            fuse_part.nums_rows,
+            &fuse_part.compression,
+            &fuse_part.columns_meta,
+            columns_chunks,
+            None,
+        )?;
+        // Deserializing the whole block takes a while, check the deadline again.
+        self.check_deadline()?;
+        // The masks are applied before the prewhere filter, the same as the native pages.
+        let block_schema = self.block_reader.data_schema();
+        let mut block = mask_block(&self.column_masks, &self.src_schema, &block_schema, block)?;
+        // Virtual columns are always extracted from the source columns.
+        self.add_virtual_columns(
+            vec![],
+            None,
+            &self.src_schema,
+            &self.virtual_columns,
+            &mut block,
+        )?;
+
+        let origin_num_rows = block.num_rows();
+        let mut filtered_count = None;
+        if let Some(filter_executor) = self.filter_executor.as_mut() {
+            let columns = self
+                .prewhere_columns
+                .iter()
+                .map(|index| block.get_by_offset(*index).clone())
+                .collect::<Vec<_>>();
+            let prewhere_block = DataBlock::new(columns, origin_num_rows);
+            let count =
+                filter_executor.select_in_batches(&prewhere_block, self.filter_batch_rows)?;
+            if count == 0 {
+                return Ok(());
+            }
+            filtered_count = Some(count);
+        }
+
+        if self.top_k.is_some() {
+            let count = match filtered_count {
+                Some(count) => count,
+                None => self.select_rows(None, MutableBitmap::from_len_set(origin_num_rows))?,
+            };
+            let filter_executor = self.filter_executor.as_mut().unwrap();
+            let (_, sorter, index) = self.top_k.as_mut().unwrap();
+            let entry = block.get_by_offset(*index);
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, origin_num_rows);
+            let count = sorter.push_column_with_selection(
+                &column,
+                filter_executor.mut_true_selection(),
+                count,
+            );
+            if count == 0 {
+                return Ok(());
+            }
+            filtered_count = Some(count);
+        }
+
+        if let Some(bitmap) = self.bloom_runtime_filter_bitmap(&block)? {
+            let count = self.select_rows(filtered_count, bitmap)?;
+            if count == 0 {
+                return Ok(());
+            }
+            filtered_count = Some(count);
+        }
+
+        if let Some(count) = filtered_count {
+            let filter_executor = self.filter_executor.as_mut().unwrap();
+            block = filter_executor.take(block, origin_num_rows, count)?;
+        }
+
+        let mut block = block.resort(&self.src_schema, &self.output_schema)?;
+        if self.block_reader.query_internal_columns() {
+            let offsets = filtered_count.map(|count| {
+                let filter_executor = self.filter_executor.as_mut().unwrap();
+                filter_executor.mut_true_selection()[0..count]
+                    .iter()
+                    .map(|idx| *idx as usize)
+                    .collect::<Vec<_>>()
+            });
+            block =
+                fill_internal_column_meta(block, fuse_part, offsets, self.base_block_ids.clone())?;
+        }
+
+        if self.block_reader.update_stream_columns() {
+            let inner_meta = block.take_meta();
+            let meta = gen_mutation_stream_meta(inner_meta, &fuse_part.location)?;
+            block = block.add_meta(Some(Box::new(meta)))?;
+        }
+
+        if self.lineage_meta {
+            block = add_lineage_meta(block, fuse_part)?;
+        }
+
+        self.add_block(block)
+    }
+
+    /// Return the error of the page failed to be decoded, or collect it to the context if
+    /// `collect_decode_errors` is set. The pages following the corrupted one can't be
+    /// trusted either, so the remaining rows of the part are dropped.
+    fn handle_decode_error(&mut self, index: usize, err: ErrorCode) -> Result<()> {
+        if !self.collect_decode_errors {
+            return Err(err);
+        }
+        let fuse_part = FusePartInfo::from_part(&self.parts[0])?;
+        let error = ScanDecodeError {
+            part_location: fuse_part.location.clone(),
+            column: self.src_schema.field(index).name().clone(),
+            page: fuse_part.page_of_row_offset(self.page_rows.as_deref(), self.offset_in_part),
+            message: err.message(),
+        };
+        warn!(
+            "Failed to decode page {} of column {} in part {}, drop the remaining rows: {}",
+            error.page, error.column, error.part_location, error.message
+        );
+        self.ctx.add_scan_decode_error(self.table_index, error);
+        self.finish_process()
+    }
+
+    /// Check if the scan exceeds the deadline, it's checked once per page to keep it cheap.
+    fn check_deadline(&self) -> Result<()> {
+        if let Some(deadline) = self.deadline {
+            if Instant::now() >= deadline {
+                return Err(ErrorCode::AbortedQuery(
+                    "Aborted query, because the scan time exceeds the maximum storage scan time limit",
+                ));
+            }
+        }
+        Ok(())
+    }
+
     /// Update the number of pages that can be skipped per column.
     fn finish_process_skip_page(&mut self) -> Result<()> {
         self.skipped_page += 1;
@@ -534,112 +1471,254 @@ impl NativeDeserializeDataTransform {
                 continue;
             }
             *skip_num += 1;
+            *self.pages_skipped.entry(*i).or_default() += 1;
         }
         Ok(())
     }
 
-    // TODO(xudong): add selectivity prediction
+    /// Report the pages read and skipped of each leaf column to the metrics.
+    fn report_page_metrics(&self) {
+        for (index, pages) in self.pages_read.iter() {
+            for column_id in &self.block_reader.project_column_nodes[*index].leaf_column_ids {
+                metrics_inc_native_column_pages_read(*column_id, *pages);
+            }
+        }
+        for (index, pages) in self.pages_skipped.iter() {
+            for column_id in &self.block_reader.project_column_nodes[*index].leaf_column_ids {
+                metrics_inc_native_column_pages_skipped(*column_id, *pages);
+            }
+        }
+    }
+
+    /// If prewhere filter is None, we need to build a dummy filter executor
+    /// to select rows by the bitmaps.
+    fn get_or_build_filter_executor(&mut self) -> Result<&mut FilterExecutor> {
+        if self.filter_executor.is_none() {
+            let dummy_expr = Expr::Constant {
+                span: None,
+                scalar: Scalar::Boolean(true),
+                data_type: DataType::Boolean,
+            };
+            let (select_expr, has_or) = build_select_expr(&dummy_expr);
+            self.filter_executor = Some(FilterExecutor::new(
+                select_expr,
+                self.ctx.get_function_context()?,
+                has_or,
+                self.filter_row_per_page,
+                None,
+                &BUILTIN_FUNCTIONS,
+                false,
+            ));
+        }
+        Ok(self.filter_executor.as_mut().unwrap())
+    }
+
+    /// The pruners probing the bloom filter index of the parts, one for each inlist runtime
+    /// filter, a part is skipped if any of them rejects it.
+    fn bloom_index_pruners(&mut self) -> Result<&[Arc<dyn BloomPruner + Send + Sync>]> {
+        if self.cached_bloom_index_pruners.is_none() {
+            let filters = self.ctx.get_inlist_runtime_filter_with_id(self.table_index);
+            // The filters are not built yet, check again for the next part.
+            if filters.is_empty() {
+                return Ok(&[]);
+            }
+            let mut pruners = Vec::with_capacity(filters.len());
+            for filter in filters.iter() {
+                let Some(expr) = inlist_filter_to_point_queries(filter, &self.func_ctx)? else {
+                    continue;
+                };
+                if let Some(pruner) = BloomPrunerCreator::create(
+                    self.func_ctx.clone(),
+                    &self.table_schema,
+                    self.block_reader.operator.clone(),
+                    Some(&expr),
+                    BloomIndexColumns::All,
+                )? {
+                    pruners.push(pruner);
+                }
+            }
+            // Cache the pruners even if none of the filters applies, so they are not built again.
+            self.cached_bloom_index_pruners = Some(pruners);
+        }
+        Ok(self.cached_bloom_index_pruners.as_deref().unwrap())
+    }
+
+    /// Fetch the bloom runtime filters of the columns read by the scan and cache them,
+    /// return false if there is no filter to apply.
+    fn fetch_bloom_runtime_filters(&mut self) -> bool {
+        if self.cached_bloom_runtime_filter.is_none() {
+            let bloom_filters = self.ctx.get_bloom_runtime_filter_with_id(self.table_index);
+            let disjunctive_bloom_filters = self
+                .ctx
+                .get_disjunctive_bloom_runtime_filter_with_id(self.table_index);
+            // The filters are not built yet, check again for the next page.
+            if bloom_filters.is_empty() && disjunctive_bloom_filters.is_empty() {
+                return false;
+            }
+            let (groups, skipped) = resolve_bloom_runtime_filters(
+                bloom_filters,
+                disjunctive_bloom_filters,
+                &self.src_schema,
+            );
+            if skipped > 0 {
+                metrics_inc_runtime_filter_expression_keys_skipped(skipped as u64);
+            }
+            // Cache the filters even if none of them applies, so they are not fetched again.
+            self.cached_bloom_runtime_filter = Some(groups);
+        }
+        !self.cached_bloom_runtime_filter.as_ref().unwrap().is_empty()
+    }
+
+    /// Probe the bloom runtime filters with the columns of a whole block, used by the parts
+    /// in parquet format. Return None if there is no filter to apply or all the rows pass.
+    fn bloom_runtime_filter_bitmap(&mut self, block: &DataBlock) -> Result<Option<MutableBitmap>> {
+        if !self.fetch_bloom_runtime_filters() {
+            return Ok(None);
+        }
+        let num_rows = block.num_rows();
+        let mut group_bitmaps = vec![];
+        for (combinator, filters) in self.cached_bloom_runtime_filter.as_ref().unwrap().iter() {
+            let mut bitmaps = Vec::with_capacity(filters.len());
+            for (idx, filter) in filters.iter() {
+                let entry = block.get_by_offset(*idx);
+                let probe_column = entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, num_rows);
+                let mut bitmap = MutableBitmap::from_len_zeroed(num_rows);
+                update_bitmap_with_bloom_filter(probe_column, filter, &mut bitmap)?;
+                bitmaps.push(bitmap);
+            }
+            group_bitmaps.extend(combine_bloom_bitmaps(*combinator, bitmaps));
+        }
+        let rf_bitmap = combine_bloom_bitmaps(RuntimeFilterCombinator::And, group_bitmaps);
+        Ok(rf_bitmap.filter(|bitmap| bitmap.unset_bits() > 0))
+    }
+
+    /// AND the bitmap into the rows selected by the filter executor, or initialize the
+    /// selection with it if no rows are selected yet. Return the number of rows selected.
+    fn select_rows(&mut self, count: Option<usize>, bitmap: MutableBitmap) -> Result<usize> {
+        let filter_executor = self.get_or_build_filter_executor()?;
+        Ok(match count {
+            Some(count) => filter_executor.select_bitmap(count, bitmap),
+            None => filter_executor.from_bitmap(bitmap),
+        })
+    }
+
+    /// Apply the bloom runtime filters to the current page, in the order of the estimated
+    /// cost, see `order_bloom_runtime_filters`. The filters of a group are combined by its
+    /// combinator, and the groups are ANDed.
     fn bloom_runtime_filter(
         &mut self,
         arrays: &mut Vec<(usize, Box<dyn Array>)>,
         count: Option<usize>,
     ) -> Result<(bool, Option<usize>)> {
         let mut local_arrays = vec![];
-        // Check if already cached runtime filters
-        if self.cached_bloom_runtime_filter.is_none() {
-            let bloom_filters = self.ctx.get_bloom_runtime_filter_with_id(self.table_index);
-            let bloom_filters = bloom_filters
-                .into_iter()
-                .filter_map(|filter| {
-                    let name = filter.0.as_str();
-                    // Some probe keys are not in the schema, they are derived from expressions.
-                    self.src_schema
-                        .index_of(name)
-                        .ok()
-                        .map(|idx| (idx, filter.1.clone()))
-                })
-                .collect::<Vec<(FieldIndex, BinaryFuse8)>>();
-            if bloom_filters.is_empty() {
-                return Ok((false, count));
-            }
-            self.cached_bloom_runtime_filter = Some(bloom_filters);
-        }
-        let mut bitmaps =
-            Vec::with_capacity(self.cached_bloom_runtime_filter.as_ref().unwrap().len());
-        for (idx, filter) in self.cached_bloom_runtime_filter.as_ref().unwrap().iter() {
-            let mut find_array = false;
-            // It's possible that the column has multiple filters, so we need to avoid duplicate reads.
-            // Or the column in prewhere columns has been read.
-            for (i, array) in arrays.iter() {
-                if i == idx {
-                    local_arrays.push((*idx, array.clone()));
-                    find_array = true;
-                    break;
+        if !self.fetch_bloom_runtime_filters() {
+            return Ok((false, count));
+        }
+        // The read columns differ between pages, e.g. the top-k column may skip the page
+        // before the prewhere columns are read, so the filters are ordered for each page.
+        let read_columns = arrays.iter().map(|(index, _)| *index).collect::<Vec<_>>();
+        for (_, filters) in self.cached_bloom_runtime_filter.as_mut().unwrap().iter_mut() {
+            order_bloom_runtime_filters(filters, &read_columns);
+        }
+        let mut group_bitmaps = vec![];
+        let mut decode_error = None;
+        'groups: for (combinator, filters) in
+            self.cached_bloom_runtime_filter.as_ref().unwrap().iter()
+        {
+            let mut bitmaps = Vec::with_capacity(filters.len());
+            for (idx, filter) in filters.iter() {
+                let mut find_array = false;
+                // It's possible that the column has multiple filters, so we need to avoid
+                // duplicate reads. Or the column in prewhere columns has been read.
+                for (i, array) in arrays.iter() {
+                    if i == idx {
+                        local_arrays.push((*idx, array.clone()));
+                        find_array = true;
+                        break;
+                    }
                 }
-            }
-            if !find_array {
-                if let Some(array_iter) = self.array_iters.get_mut(idx) {
-                    let skip_pages = self.array_skip_pages.get(idx).unwrap();
-                    match array_iter.nth(*skip_pages) {
-                        Some(array) => {
-                            let array = array.as_ref().unwrap();
-                            if let Some(pos) = self.remain_columns.iter().position(|i| i == idx) {
-                                self.remain_columns.remove(pos);
+                if !find_array {
+                    if let Some(array_iter) = self.array_iters.get_mut(idx) {
+                        let skip_pages = self.array_skip_pages.get(idx).unwrap();
+                        match array_iter.nth(*skip_pages) {
+                            Some(Err(err)) => {
+                                decode_error = Some((*idx, err.into()));
+                                break 'groups;
+                            }
+                            Some(Ok(array)) => {
+                                if let Some(pos) =
+                                    self.remain_columns.iter().position(|i| i == idx)
+                                {
+                                    self.remain_columns.remove(pos);
+                                }
+                                self.read_columns.push(*idx);
+                                *self.pages_read.entry(*idx).or_default() += 1;
+                                // The column is not read again as a remain column, mask it
+                                // here so both the probe and the output see the masked values.
+                                let array = mask_array(&self.column_masks, *idx, array)?;
+                                arrays.push((*idx, array.clone()));
+                                local_arrays.push((*idx, array));
+                                self.array_skip_pages.insert(*idx, 0);
+                            }
+                            None => {
+                                return Ok((false, count));
                             }
-                            self.read_columns.push(*idx);
-                            arrays.push((*idx, array.clone()));
-                            local_arrays.push((*idx, array.clone()));
-                            self.array_skip_pages.insert(*idx, 0);
-                        }
-                        None => {
-                            return Ok((false, count));
                         }
                     }
                 }
+                let probe_block = self.block_reader.build_block(local_arrays.clone(), None)?;
+                let mut bitmap = MutableBitmap::from_len_zeroed(probe_block.num_rows());
+                local_arrays.clear();
+                let probe_column = probe_block.get_last_column().clone();
+                update_bitmap_with_bloom_filter(probe_column, filter, &mut bitmap)?;
+                let unset_bits = bitmap.unset_bits();
+                match combinator {
+                    // A filter rejecting all the rows rejects the page, skip it before
+                    // reading the columns of the other filters.
+                    RuntimeFilterCombinator::And if unset_bits == bitmap.len() => {
+                        self.offset_in_part += probe_block.num_rows();
+                        self.finish_process_skip_page()?;
+                        return Ok((true, None));
+                    }
+                    // A filter accepting all the rows accepts the page for the group, the
+                    // other filters of the group are not needed.
+                    RuntimeFilterCombinator::Or if unset_bits == 0 => continue 'groups,
+                    RuntimeFilterCombinator::And if unset_bits == 0 => {}
+                    _ => bitmaps.push(bitmap),
+                }
             }
-            let probe_block = self.block_reader.build_block(local_arrays.clone(), None)?;
-            let mut bitmap = MutableBitmap::from_len_zeroed(probe_block.num_rows());
-            local_arrays.clear();
-            let probe_column = probe_block.get_last_column().clone();
-            update_bitmap_with_bloom_filter(probe_column, filter, &mut bitmap)?;
-            let unset_bits = bitmap.unset_bits();
-            if unset_bits == bitmap.len() {
-                self.offset_in_part += probe_block.num_rows();
+            group_bitmaps.extend(combine_bloom_bitmaps(*combinator, bitmaps));
+        }
+        // The page of a filter column fails to decode, the part is finished or the error is
+        // returned, the same as the prewhere and remain columns.
+        if let Some((index, err)) = decode_error {
+            self.handle_decode_error(index, err)?;
+            return Ok((true, None));
+        }
+        let rf_bitmap = combine_bloom_bitmaps(RuntimeFilterCombinator::And, group_bitmaps);
+        if let Some(rf_bitmap) = rf_bitmap {
+            // The page is only skipped if the combined bitmap rejects all the rows.
+            if rf_bitmap.unset_bits() == rf_bitmap.len() {
+                self.offset_in_part += rf_bitmap.len();
                 self.finish_process_skip_page()?;
                 return Ok((true, None));
-            } else if unset_bits != 0 {
-                bitmaps.push(bitmap);
-            }
-        }
-        if !bitmaps.is_empty() {
-            let rf_bitmap = bitmaps
-                .into_iter()
-                .reduce(|acc, rf_filter| acc.bitand(&rf_filter.into()))
-                .unwrap();
-            if self.filter_executor.is_none() {
-                // If prewhere filter is None, we need to build a dummy filter executor.
-                let dummy_expr = Expr::Constant {
-                    span: None,
-                    scalar: Scalar::Boolean(true),
-                    data_type: DataType::Boolean,
-                };
-                let (select_expr, has_or) = build_select_expr(&dummy_expr);
-                self.filter_executor = Some(FilterExecutor::new(
-                    select_expr,
-                    self.ctx.get_function_context()?,
-                    has_or,
-                    DEFAULT_ROW_PER_PAGE,
-                    None,
-                    &BUILTIN_FUNCTIONS,
-                    false,
-                ));
             }
-            let filter_executor = self.filter_executor.as_mut().unwrap();
+            let num_rows = rf_bitmap.len();
+            let filter_executor = self.get_or_build_filter_executor()?;
             let filter_count = if let Some(count) = count {
                 filter_executor.select_bitmap(count, rf_bitmap)
             } else {
                 filter_executor.from_bitmap(rf_bitmap)
             };
+            // None of the rows kept by the prewhere filter pass the runtime filters,
+            // skip the page without reading the remain columns.
+            if filter_count == 0 {
+                self.offset_in_part += num_rows;
+                self.finish_process_skip_page()?;
+                return Ok((true, None));
+            }
             Ok((false, Some(filter_count)))
         } else {
             Ok((false, count))
@@ -647,6 +1726,7 @@ impl NativeDeserializeDataTransform {
     }
 }
 
+#[async_trait::async_trait]
 impl Processor for NativeDeserializeDataTransform {
     fn name(&self) -> String {
         String::from("NativeDeserializeDataTransform")
@@ -667,11 +1747,37 @@ impl Processor for NativeDeserializeDataTransform {
             return Ok(Event::NeedConsume);
         }
 
-        if let Some(data_block) = self.output_data.take() {
+        if let Some(data_block) = self
+            .output_data
+            .take()
+            .or_else(|| self.output_queue.pop_front())
+        {
             self.output.push_data(Ok(data_block));
+            self.finished_parts_in_tick = 0;
             return Ok(Event::NeedConsume);
         }
 
+        if self.need_flush_pending_blocks() {
+            return Ok(Event::Sync);
+        }
+
+        // Many tiny parts may be finished in a row without any output, yield to
+        // the scheduler in between so the other processors are not starved.
+        if !self.chunks.is_empty()
+            && self.max_parts_per_tick > 0
+            && self.finished_parts_in_tick >= self.max_parts_per_tick
+        {
+            return Ok(Event::Async);
+        }
+
+        if self.bloom_index_probe_pending {
+            return Ok(Event::Async);
+        }
+
+        if self.pending_page.is_some() && self.decoded_pages.is_none() {
+            return Ok(Event::Async);
+        }
+
         if !self.chunks.is_empty() {
             if !self.input.has_data() {
                 self.input.set_need_data();
@@ -693,7 +1799,11 @@ impl Processor for NativeDeserializeDataTransform {
         }
 
         if self.input.is_finished() {
+            if !self.pending_blocks.is_empty() {
+                return Ok(Event::Sync);
+            }
             metrics_inc_pruning_prewhere_nums(self.skipped_page as u64);
+            self.report_page_metrics();
             self.output.finish();
             return Ok(Event::Finished);
         }
@@ -703,15 +1813,56 @@ impl Processor for NativeDeserializeDataTransform {
     }
 
     fn process(&mut self) -> Result<()> {
+        if self.need_flush_pending_blocks()
+            || (self.chunks.is_empty()
+                && self.input.is_finished()
+                && !self.pending_blocks.is_empty())
+        {
+            return self.flush_pending_blocks();
+        }
+
+        if let Some(decoded_pages) = self.decoded_pages.take() {
+            let page = self.pending_page.take().unwrap();
+            return self.read_remain_columns(page, decoded_pages);
+        }
+
+        if !self.chunks.is_empty() {
+            self.check_deadline()?;
+            if self.skip_on_empty_build && self.ctx.is_runtime_filter_build_empty(self.table_index)
+            {
+                return self.finish_remaining_parts();
+            }
+            if self
+                .limit
+                .is_some_and(|limit| self.ctx.get_limited_scan_rows(self.table_index) >= limit)
+            {
+                return self.finish_remaining_parts();
+            }
+        }
+
+        let probe_bloom_index = self.bloom_index_probe
+            && !self.inited
+            && self.bloom_index_keep.is_none()
+            && !self.chunks.is_empty()
+            && !self.bloom_index_pruners()?.is_empty();
+
         if let Some(chunks) = self.chunks.front_mut() {
             let chunks = match chunks {
                 NativeDataSource::AggIndex(data) => {
                     let agg_index_reader = self.index_reader.as_ref().as_ref().unwrap();
                     let block = agg_index_reader.deserialize_native_data(data)?;
+                    let block = if self.force_full_columns {
+                        block.convert_to_full()
+                    } else {
+                        block
+                    };
                     self.output_data = Some(block);
                     return self.finish_process();
                 }
                 NativeDataSource::Normal(data) => data,
+                NativeDataSource::Parquet(_) => {
+                    return self.finish_process_with_parquet_data();
+                }
             };
 
             // this means it's empty projection
@@ -722,8 +1873,12 @@ impl Processor for NativeDeserializeDataTransform {
             // Init array_iters and array_skip_pages to read pages in subsequent processes.
             if !self.inited {
                 let fuse_part = FusePartInfo::from_part(&self.parts[0])?;
+                self.page_rows = self
+                    .page_rows_column
+                    .and_then(|column_id| fuse_part.page_rows(column_id));
                 if let Some(range) = fuse_part.range() {
-                    self.offset_in_part = fuse_part.page_size() * range.start;
+                    self.offset_in_part =
+                        fuse_part.page_row_offset(self.page_rows.as_deref(), range.start);
                 }
 
                 if let Some(((_top_k, sorter, _index), min_max)) =
@@ -734,16 +1889,64 @@ impl Processor for NativeDeserializeDataTransform {
                     }
                 }
 
+                match self.bloom_index_keep {
+                    // Probe the bloom filter index in `async_process` before building
+                    // the ArrayIters, the part is initialised again after it's probed.
+                    None if probe_bloom_index && fuse_part.bloom_index_location.is_some() => {
+                        self.bloom_index_probe_pending = true;
+                        return Ok(());
+                    }
+                    Some(false) => {
+                        metrics_inc_runtime_filter_bloom_index_pruned_parts(1);
+                        return self.finish_process();
+                    }
+                    _ => {}
+                }
+
                 let mut has_default_value = false;
                 self.inited = true;
+                if self.part_profile {
+                    self.part_profile_start = Some((
+                        Instant::now(),
+                        self.pages_read.values().sum(),
+                        self.pages_skipped.values().sum(),
+                    ));
+                }
                 for (index, column_node) in
                     self.block_reader.project_column_nodes.iter().enumerate()
                 {
-                    let readers = chunks.remove(&index).unwrap_or_default();
+                    let mut readers = chunks.remove(&index).unwrap_or_default();
                     if !readers.is_empty() {
-                        let leaves = self.column_leaves.get(index).unwrap().clone();
-                        let array_iter =
-                            BlockReader::build_array_iter(column_node, leaves, readers)?;
+                        // The iterators of nested columns keep the nested state between pages,
+                        // they are always rebuilt.
+                        let array_iter = if self.reuse_array_iters
+                            && !column_node.is_nested
+                            && readers.len() == 1
+                        {
+                            let reader = readers.pop().unwrap();
+                            let (array_iter, shared_reader) =
+                                match self.array_iter_pool.remove(&index) {
+                                    Some((array_iter, shared_reader)) => {
+                                        shared_reader.reset(reader);
+                                        (array_iter, shared_reader)
+                                    }
+                                    None => {
+                                        let shared_reader = SharedNativeReader::new(reader);
+                                        let leaves = self.column_leaves[index].clone();
+                                        let array_iter = BlockReader::build_array_iter(
+                                            column_node,
+                                            leaves,
+                                            vec![shared_reader.clone()],
+                                        )?;
+                                        (array_iter, shared_reader)
+                                    }
+                                };
+                            self.shared_readers.insert(index, shared_reader);
+                            array_iter
+                        } else {
+                            let leaves = self.column_leaves.get(index).unwrap().clone();
+                            BlockReader::build_array_iter(column_node, leaves, readers)?
+                        };
                         self.array_iters.insert(index, array_iter);
                         self.array_skip_pages.insert(index, 0);
 
@@ -794,9 +1997,14 @@ impl Processor for NativeDeserializeDataTransform {
                 if let Some((top_k, sorter, index)) = self.top_k.as_mut() {
                     if let Some(array_iter) = self.array_iters.get_mut(index) {
                         match array_iter.next() {
-                            Some(array) => {
-                                let array = array?;
+                            Some(Err(err)) => {
+                                let index = *index;
+                                return self.handle_decode_error(index, err.into());
+                            }
+                            Some(Ok(array)) => {
+                                let array = mask_array(&self.column_masks, *index, array)?;
                                 self.read_columns.push(*index);
+                                *self.pages_read.entry(*index).or_default() += 1;
                                 let data_type = top_k.field.data_type().into();
                                 let col = Column::from_arrow(array.as_ref(), &data_type);
 
@@ -807,7 +2015,7 @@ impl Processor for NativeDeserializeDataTransform {
                                 }
                             }
                             None => {
-                                return self.finish_process();
+                                return self.finish_process_with_all_pages();
                             }
                         }
                     }
@@ -816,27 +2024,85 @@ impl Processor for NativeDeserializeDataTransform {
 
             // Step 2: Read Prewhere columns and get the filter
             let mut prewhere_default_val_indices = HashSet::new();
-            for index in self.prewhere_columns.iter() {
-                if self.read_columns.contains(index) {
-                    continue;
-                }
-                if let Some(array_iter) = self.array_iters.get_mut(index) {
-                    let skip_pages = self.array_skip_pages.get(index).unwrap();
-
-                    match array_iter.nth(*skip_pages) {
-                        Some(array) => {
-                            self.read_columns.push(*index);
-                            arrays.push((*index, array?));
-                            self.array_skip_pages.insert(*index, 0);
-                        }
-                        None => {
-                            return self.finish_process();
+            let mut decode_error = None;
+            // The rows kept by the evaluated prewhere stages, `split_count` is `None` if
+            // no stage is evaluated.
+            let mut selection: Vec<u32> = vec![];
+            let mut split_count = None;
+            let mut next_stage = 0;
+            for (position, index) in self.prewhere_columns.iter().enumerate() {
+                if !self.read_columns.contains(index) {
+                    if let Some(array_iter) = self.array_iters.get_mut(index) {
+                        let skip_pages = self.array_skip_pages.get(index).unwrap();
+
+                        match array_iter.nth(*skip_pages) {
+                            Some(Err(err)) => {
+                                decode_error = Some((*index, err.into()));
+                                break;
+                            }
+                            Some(Ok(array)) => {
+                                self.read_columns.push(*index);
+                                *self.pages_read.entry(*index).or_default() += 1;
+                                let array = mask_array(&self.column_masks, *index, array)?;
+                                arrays.push((*index, array));
+                                self.array_skip_pages.insert(*index, 0);
+                            }
+                            None => {
+                                return self.finish_process_with_all_pages();
+                            }
                         }
+                    } else {
+                        prewhere_default_val_indices.insert(*index);
+                        need_to_fill_data = true;
                     }
-                } else {
-                    prewhere_default_val_indices.insert(*index);
-                    need_to_fill_data = true;
                 }
+
+                // Evaluate the conjuncts referencing only the prewhere columns read so far on
+                // the rows kept by the previous ones, the remaining prewhere columns are not
+                // read if no rows are kept.
+                while next_stage < self.prewhere_stages.len()
+                    && self.prewhere_stages[next_stage].0 <= position
+                {
+                    let read_prefix = &self.prewhere_columns[..=position];
+                    let prefix_arrays = arrays
+                        .iter()
+                        .filter(|(i, _)| read_prefix.contains(i))
+                        .cloned()
+                        .collect::<Vec<_>>();
+                    // All the columns read so far are default values, evaluate the
+                    // stage together with the next columns.
+                    if prefix_arrays.is_empty() {
+                        break;
+                    }
+                    let block = self
+                        .block_reader
+                        .build_block(prefix_arrays, Some(prewhere_default_val_indices.clone()))?;
+                    let num_rows = block.num_rows();
+                    let block = match split_count {
+                        Some(count) => block.take(&selection[0..count], &mut None)?,
+                        None => block,
+                    };
+                    let stage = &mut self.prewhere_stages[next_stage].1;
+                    let count = stage.select_in_batches(&block, self.filter_batch_rows)?;
+                    // The indices are relative to the kept rows, map them back to the page.
+                    selection = match split_count {
+                        Some(_) => stage.true_selection()[0..count]
+                            .iter()
+                            .map(|idx| selection[*idx as usize])
+                            .collect(),
+                        None => stage.true_selection()[0..count].to_vec(),
+                    };
+                    split_count = Some(count);
+                    next_stage += 1;
+
+                    if count == 0 {
+                        self.offset_in_part += num_rows;
+                        return self.finish_process_skip_page();
+                    }
+                }
+            }
+            if let Some((index, err)) = decode_error {
+                return self.handle_decode_error(index, err);
             }
 
             let filtered_count = match self.prewhere_filter.as_ref() {
@@ -855,13 +2121,23 @@ impl Processor for NativeDeserializeDataTransform {
                         // Add optional virtual columns for prewhere
                         self.add_virtual_columns(
                             arrays.clone(),
+                            None,
                             &self.prewhere_schema,
                             &self.prewhere_virtual_columns,
                             &mut prewhere_block,
                         )?;
 
                         let filter_executor = self.filter_executor.as_mut().unwrap();
-                        let mut count = filter_executor.select(&prewhere_block)?;
+                        let mut count = match split_count {
+                            Some(count) => {
+                                filter_executor.reserve_selection(prewhere_block.num_rows());
+                                filter_executor.mut_true_selection()[0..count]
+                                    .copy_from_slice(&selection);
+                                count
+                            }
+                            None => filter_executor
+                                .select_in_batches(&prewhere_block, self.filter_batch_rows)?,
+                        };
 
                         // Step 3: Apply the filter, if it's all filtered, we can skip the remain columns.
                         if count == 0 {
@@ -905,81 +2181,206 @@ impl Processor for NativeDeserializeDataTransform {
                 return Ok(());
             }
 
-            // Step 5: read remain columns and filter block if needed.
-            for index in self.remain_columns.iter() {
-                if let Some(array_iter) = self.array_iters.get_mut(index) {
-                    let skip_pages = self.array_skip_pages.get(index).unwrap();
+            // Step 5: read remain columns and filter block if needed. The pages are decoded
+            // concurrently on the shared IO runtime if enabled, the processor continues
+            // once they are decoded instead of blocking the pipeline worker.
+            let page = PendingPage {
+                arrays,
+                need_to_fill_data,
+                filtered_count,
+            };
+            if !self.parallel_remain_columns().is_empty() {
+                self.pending_page = Some(page);
+                return Ok(());
+            }
+            self.read_remain_columns(page, HashMap::new())?;
+        }
+
+        Ok(())
+    }
 
-                    match array_iter.nth(*skip_pages) {
-                        Some(array) => {
-                            self.read_columns.push(*index);
-                            arrays.push((*index, array?));
-                            self.array_skip_pages.insert(*index, 0);
-                        }
-                        None => {
-                            return self.finish_process();
-                        }
-                    }
-                } else {
-                    need_to_fill_data = true;
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        if std::mem::take(&mut self.bloom_index_probe_pending) {
+            let fuse_part = FusePartInfo::from_part(&self.parts[0])?;
+            // Only the filters of the columns read by the scan are probed.
+            let column_ids = fuse_part.columns_meta.keys().copied().collect::<Vec<_>>();
+            let mut keep = true;
+            for pruner in self.cached_bloom_index_pruners.iter().flatten() {
+                if !pruner
+                    .should_keep(
+                        &fuse_part.bloom_index_location,
+                        fuse_part.bloom_index_size,
+                        column_ids.clone(),
+                    )
+                    .await
+                {
+                    keep = false;
+                    break;
                 }
             }
+            self.bloom_index_keep = Some(keep);
+            return Ok(());
+        }
 
-            let block = self.block_reader.build_block(arrays.clone(), None)?;
-            // Step 6: fill missing field default value if need
-            let mut block = if need_to_fill_data {
-                self.block_reader
-                    .fill_missing_native_column_values(block, &self.read_column_ids)?
-            } else {
-                block
-            };
+        if self.pending_page.is_some() && self.decoded_pages.is_none() {
+            let array_iters = self
+                .parallel_remain_columns()
+                .into_iter()
+                .map(|index| {
+                    let array_iter = self.array_iters.remove(&index).unwrap();
+                    let skip_pages = *self.array_skip_pages.get(&index).unwrap();
+                    (index, array_iter, skip_pages)
+                })
+                .collect();
+            let pages = BlockReader::decode_pages_in_parallel(
+                &GlobalIORuntime::instance(),
+                array_iters,
+                self.decode_parallelism,
+            )
+            .await?;
+            // The ArrayIters are put back once the pages are decoded.
+            let mut decoded_pages = HashMap::with_capacity(pages.len());
+            for (index, array_iter, page) in pages {
+                self.array_iters.insert(index, array_iter);
+                decoded_pages.insert(index, page);
+            }
+            self.decoded_pages = Some(decoded_pages);
+            return Ok(());
+        }
 
-            // Step 7: Add optional virtual columns
-            self.add_virtual_columns(arrays, &self.src_schema, &self.virtual_columns, &mut block)?;
+        // Nothing to do, being scheduled again is the yield.
+        self.finished_parts_in_tick = 0;
+        Ok(())
+    }
+}
 
-            let origin_num_rows = block.num_rows();
-            let block = if let Some(count) = &filtered_count {
-                let filter_executor = self.filter_executor.as_mut().unwrap();
-                filter_executor.take(block, origin_num_rows, *count)?
-            } else {
-                block
-            };
+/// Split the block into slices of at most `max_rows` rows, the row offsets carried by the
+/// meta are sliced along with the rows. The blocks carrying the stream meta are not split,
+/// as the origin row numbers of the stream columns are counted from the start of the block.
+fn split_output_block(mut block: DataBlock, max_rows: usize) -> Result<Vec<DataBlock>> {
+    let num_rows = block.num_rows();
+    if max_rows == 0 || num_rows <= max_rows {
+        return Ok(vec![block]);
+    }
+    let meta = block.take_meta();
+    if meta.as_ref().is_some_and(has_stream_meta) {
+        return Ok(vec![block.add_meta(meta)?]);
+    }
+    (0..num_rows)
+        .step_by(max_rows)
+        .map(|start| {
+            let range = start..(start + max_rows).min(num_rows);
+            let meta = meta
+                .as_ref()
+                .map(|meta| slice_block_meta(meta, range.clone()));
+            block.slice(range).add_meta(meta)
+        })
+        .collect()
+}
 
-            // Step 8: Fill `InternalColumnMeta` as `DataBlock.meta` if query internal columns,
-            // `TransformAddInternalColumns` will generate internal columns using `InternalColumnMeta` in next pipeline.
-            let mut block = block.resort(&self.src_schema, &self.output_schema)?;
-            if self.block_reader.query_internal_columns() {
-                let offsets = if let Some(count) = filtered_count {
-                    let filter_executor = self.filter_executor.as_mut().unwrap();
-                    filter_executor.mut_true_selection()[0..count]
-                        .iter()
-                        .map(|idx| *idx as usize + self.offset_in_part)
-                        .collect::<Vec<_>>()
-                } else {
-                    (self.offset_in_part..self.offset_in_part + origin_num_rows).collect()
-                };
+fn has_stream_meta(meta: &BlockMetaInfoPtr) -> bool {
+    match LineageMeta::downcast_ref_from(meta) {
+        Some(lineage) => lineage.inner.as_ref().is_some_and(has_stream_meta),
+        None => StreamColumnMeta::downcast_ref_from(meta).is_some(),
+    }
+}
 
-                let fuse_part = FusePartInfo::from_part(&self.parts[0])?;
-                block = fill_internal_column_meta(
-                    block,
-                    fuse_part,
-                    Some(offsets),
-                    self.base_block_ids.clone(),
-                )?;
-            }
+/// Slice the meta of the block to the rows in `range`.
+fn slice_block_meta(meta: &BlockMetaInfoPtr, range: Range<usize>) -> BlockMetaInfoPtr {
+    if let Some(lineage) = LineageMeta::downcast_ref_from(meta) {
+        let inner = lineage
+            .inner
+            .as_ref()
+            .map(|inner| slice_block_meta(inner, range));
+        return Box::new(LineageMeta {
+            part_location: lineage.part_location.clone(),
+            segment_idx: lineage.segment_idx,
+            block_idx: lineage.block_idx,
+            inner,
+        });
+    }
+    if let Some(internal) = InternalColumnMeta::downcast_ref_from(meta) {
+        // No offsets means the rows of the block are the rows of the whole part.
+        let offsets = match &internal.offsets {
+            Some(offsets) => offsets[range].to_vec(),
+            None => range.collect(),
+        };
+        return Box::new(InternalColumnMeta {
+            offsets: Some(offsets),
+            ..internal.clone()
+        });
+    }
+    meta.clone()
+}
 
-            if self.block_reader.update_stream_columns() {
-                let inner_meta = block.take_meta();
-                let fuse_part = FusePartInfo::from_part(&self.parts[0])?;
-                let meta = gen_mutation_stream_meta(inner_meta, &fuse_part.location)?;
-                block = block.add_meta(Some(Box::new(meta)))?;
-            }
+/// Wrap the meta of the block with the lineage of the part it derived from,
+/// so that it coexists with the internal column meta and the stream meta.
+fn add_lineage_meta(mut block: DataBlock, fuse_part: &FusePartInfo) -> Result<DataBlock> {
+    let inner_meta = block.take_meta();
+    let block_meta_index = fuse_part.block_meta_index.as_ref();
+    let meta = gen_lineage_meta(
+        inner_meta,
+        &fuse_part.location,
+        block_meta_index.map(|index| index.segment_idx),
+        block_meta_index.map(|index| index.block_idx),
+    );
+    block.add_meta(Some(Box::new(meta)))
+}
 
-            // Step 9: Add the block to output data
-            self.offset_in_part += origin_num_rows;
-            self.add_block(block)?;
-        }
+/// Rewrite the decoded array with the mask of the column if any.
+fn mask_array(
+    column_masks: &HashMap<usize, (DataType, ColumnMask)>,
+    index: usize,
+    array: Box<dyn Array>,
+) -> Result<Box<dyn Array>> {
+    let Some((data_type, mask)) = column_masks.get(&index) else {
+        return Ok(array);
+    };
+    let column = Column::from_arrow(array.as_ref(), data_type);
+    Ok(apply_mask(data_type, mask, &column)?.as_arrow())
+}
 
-        Ok(())
+/// Rewrite the columns of the block with their masks, used by the parts in parquet format
+/// which are deserialized as a whole block. The masks are keyed by the index in `src_schema`,
+/// they are mapped to the columns of the block by name, the masks of the virtual columns
+/// don't apply as the block only has the columns read from storage.
+fn mask_block(
+    column_masks: &HashMap<usize, (DataType, ColumnMask)>,
+    src_schema: &DataSchema,
+    block_schema: &DataSchema,
+    block: DataBlock,
+) -> Result<DataBlock> {
+    if column_masks.is_empty() {
+        return Ok(block);
+    }
+    let num_rows = block.num_rows();
+    let mut columns = block.columns().to_vec();
+    for (index, (data_type, mask)) in column_masks {
+        let name = src_schema.field(*index).name();
+        let Ok(offset) = block_schema.index_of(name) else {
+            continue;
+        };
+        let column = columns[offset]
+            .value
+            .convert_to_full_column(data_type, num_rows);
+        let masked = apply_mask(data_type, mask, &column)?;
+        columns[offset] = BlockEntry::new(data_type.clone(), Value::Column(masked));
+    }
+    Ok(DataBlock::new(columns, num_rows))
+}
+
+/// The mask must preserve the data type and the number of rows of the column.
+fn apply_mask(data_type: &DataType, mask: &ColumnMask, column: &Column) -> Result<Column> {
+    let masked = mask(column)?;
+    if masked.len() != column.len() || &masked.data_type() != data_type {
+        return Err(ErrorCode::Internal(format!(
+            "The column mask must preserve the data type {} and the number of rows {}, but got {} and {}",
+            data_type,
+            column.len(),
+            masked.data_type(),
+            masked.len()
+        )));
     }
+    Ok(masked)
 }
@@ -35,6 +35,7 @@ use databend_common_sql::IndexType;
 use super::native_data_source::NativeDataSource;
 use crate::io::AggIndexReader;
 use crate::io::BlockReader;
+use crate::io::ReadSettings;
 use crate::io::TableMetaLocationGenerator;
 use crate::io::VirtualColumnReader;
 use crate::operations::read::data_source_with_meta::DataSourceWithMeta;
@@ -57,6 +58,17 @@ pub struct ReadNativeDataSource<const BLOCKING_IO: bool> {
 
     table_schema: Arc<TableSchema>,
     table_index: IndexType,
+    // The pushed down limit, no more parts are read once the deserializers of the table
+    // have emitted `limit` rows in total.
+    limit: Option<usize>,
+}
+
+impl<const BLOCKING_IO: bool> ReadNativeDataSource<BLOCKING_IO> {
+    fn limit_reached(&self) -> bool {
+        self.limit.is_some_and(|limit| {
+            self.partitions.ctx.get_limited_scan_rows(self.table_index) >= limit
+        })
+    }
 }
 
 impl ReadNativeDataSource<true> {
@@ -70,6 +82,7 @@ impl ReadNativeDataSource<true> {
         partitions: StealablePartitions,
         index_reader: Arc<Option<AggIndexReader>>,
         virtual_reader: Arc<Option<VirtualColumnReader>>,
+        limit: Option<usize>,
     ) -> Result<ProcessorPtr> {
         let batch_size = ctx.get_settings().get_storage_fetch_part_num()? as usize;
         let func_ctx = ctx.get_function_context()?;
@@ -86,6 +99,7 @@ impl ReadNativeDataSource<true> {
             virtual_reader,
             table_schema,
             table_index,
+            limit,
         })
     }
 }
@@ -101,6 +115,7 @@ impl ReadNativeDataSource<false> {
         partitions: StealablePartitions,
         index_reader: Arc<Option<AggIndexReader>>,
         virtual_reader: Arc<Option<VirtualColumnReader>>,
+        limit: Option<usize>,
     ) -> Result<ProcessorPtr> {
         let batch_size = ctx.get_settings().get_storage_fetch_part_num()? as usize;
         let func_ctx = ctx.get_function_context()?;
@@ -119,6 +134,7 @@ impl ReadNativeDataSource<false> {
             virtual_reader,
             table_schema,
             table_index,
+            limit,
         })))
     }
 }
@@ -127,6 +143,9 @@ impl SyncSource for ReadNativeDataSource<true> {
     const NAME: &'static str = "SyncReadNativeDataSource";
 
     fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.limit_reached() {
+            return Ok(None);
+        }
         match self.partitions.steal_one(self.id) {
             None => Ok(None),
             Some(part) => {
@@ -164,6 +183,19 @@ impl SyncSource for ReadNativeDataSource<true> {
                     }
                 }
 
+                if FusePartInfo::from_part(&part)?.is_parquet_format() {
+                    let source = self.block_reader.sync_read_columns_data_by_merge_io(
+                        &ReadSettings::from_ctx(&self.partitions.ctx)?,
+                        &part,
+                        &None,
+                    )?;
+                    return Ok(Some(DataBlock::empty_with_meta(
+                        DataSourceWithMeta::create(vec![part.clone()], vec![
+                            NativeDataSource::Parquet(source),
+                        ]),
+                    )));
+                }
+
                 if let Some(virtual_reader) = self.virtual_reader.as_ref() {
                     let fuse_part = FusePartInfo::from_part(&part)?;
                     let loc =
@@ -231,6 +263,10 @@ impl Processor for ReadNativeDataSource<false> {
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
+        if self.limit_reached() {
+            self.finished = true;
+            return Ok(());
+        }
         let parts = self.partitions.steal(self.id, self.batch_size);
 
         if !parts.is_empty() {
@@ -277,6 +313,19 @@ impl Processor for ReadNativeDataSource<false> {
                                 }
                             }
 
+                            if fuse_part.is_parquet_format() {
+                                let settings = ReadSettings::from_ctx(&ctx)?;
+                                let source = block_reader
+                                    .read_columns_data_by_merge_io(
+                                        &settings,
+                                        &fuse_part.location,
+                                        &fuse_part.columns_meta,
+                                        &None,
+                                    )
+                                    .await?;
+                                return Ok(NativeDataSource::Parquet(source));
+                            }
+
                             if let Some(virtual_reader) = virtual_reader.as_ref() {
                                 let loc = TableMetaLocationGenerator::gen_virtual_block_location(
                                     &fuse_part.location,
@@ -13,7 +13,6 @@
 // limitations under the License.
 
 use std::any::Any;
-use std::ops::BitAnd;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -24,6 +23,7 @@ use databend_common_base::base::ProgressValues;
 use databend_common_catalog::plan::gen_mutation_stream_meta;
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::PartInfoPtr;
+use databend_common_catalog::runtime_filter_info::RuntimeFilterCombinator;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
@@ -31,7 +31,6 @@ use databend_common_expression::BlockMetaInfoDowncast;
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchema;
-use databend_common_expression::FieldIndex;
 use databend_common_expression::Scalar;
 use databend_common_metrics::storage::*;
 use databend_common_pipeline_core::processors::Event;
@@ -40,7 +39,6 @@ use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_sql::IndexType;
-use xorf::BinaryFuse8;
 
 use super::fuse_source::fill_internal_column_meta;
 use super::parquet_data_source::ParquetDataSource;
@@ -50,7 +48,10 @@ use crate::io::BlockReader;
 use crate::io::UncompressedBuffer;
 use crate::io::VirtualColumnReader;
 use crate::operations::read::data_source_with_meta::DataSourceWithMeta;
+use crate::operations::read::runtime_filter_prunner::combine_bloom_bitmaps;
+use crate::operations::read::runtime_filter_prunner::resolve_bloom_runtime_filters;
 use crate::operations::read::runtime_filter_prunner::update_bitmap_with_bloom_filter;
+use crate::operations::read::runtime_filter_prunner::BloomRuntimeFilterGroup;
 
 pub struct DeserializeDataTransform {
     ctx: Arc<dyn TableContext>,
@@ -71,7 +72,7 @@ pub struct DeserializeDataTransform {
     virtual_reader: Arc<Option<VirtualColumnReader>>,
 
     base_block_ids: Option<Scalar>,
-    cached_runtime_filter: Option<Vec<(FieldIndex, BinaryFuse8)>>,
+    cached_runtime_filter: Option<Vec<BloomRuntimeFilterGroup>>,
 }
 
 unsafe impl Send for DeserializeDataTransform {}
@@ -129,44 +130,34 @@ impl DeserializeDataTransform {
     fn runtime_filter(&mut self, data_block: DataBlock) -> Result<Option<Bitmap>> {
         // Check if already cached runtime filters
         if self.cached_runtime_filter.is_none() {
-            let bloom_filters = self.ctx.get_bloom_runtime_filter_with_id(self.table_index);
-            let bloom_filters = bloom_filters
-                .into_iter()
-                .filter_map(|filter| {
-                    let name = filter.0.as_str();
-                    // Some probe keys are not in the schema, they are derived from expressions.
-                    self.src_schema
-                        .index_of(name)
-                        .ok()
-                        .map(|idx| (idx, filter.1.clone()))
-                })
-                .collect::<Vec<(FieldIndex, BinaryFuse8)>>();
+            let (bloom_filters, _) = resolve_bloom_runtime_filters(
+                self.ctx.get_bloom_runtime_filter_with_id(self.table_index),
+                self.ctx.get_disjunctive_bloom_runtime_filter_with_id(self.table_index),
+                &self.src_schema,
+            );
             if bloom_filters.is_empty() {
                 return Ok(None);
             }
             self.cached_runtime_filter = Some(bloom_filters);
         }
 
-        let mut bitmaps = vec![];
-        for (idx, filter) in self.cached_runtime_filter.as_ref().unwrap().iter() {
-            let mut bitmap = MutableBitmap::from_len_zeroed(data_block.num_rows());
-            let probe_block_entry = data_block.get_by_offset(*idx);
-            let probe_column = probe_block_entry
-                .value
-                .convert_to_full_column(&probe_block_entry.data_type, data_block.num_rows());
-            update_bitmap_with_bloom_filter(probe_column, filter, &mut bitmap)?;
-            bitmaps.push(bitmap);
-        }
-        if !bitmaps.is_empty() {
-            let rf_bitmap = bitmaps
-                .into_iter()
-                .reduce(|acc, rf_filter| acc.bitand(&rf_filter.into()))
-                .unwrap();
-
-            Ok(rf_bitmap.into())
-        } else {
-            Ok(None)
+        let mut group_bitmaps = vec![];
+        for (combinator, filters) in self.cached_runtime_filter.as_ref().unwrap().iter() {
+            let mut bitmaps = vec![];
+            for (idx, filter) in filters.iter() {
+                let mut bitmap = MutableBitmap::from_len_zeroed(data_block.num_rows());
+                let probe_block_entry = data_block.get_by_offset(*idx);
+                let probe_column = probe_block_entry
+                    .value
+                    .convert_to_full_column(&probe_block_entry.data_type, data_block.num_rows());
+                update_bitmap_with_bloom_filter(probe_column, filter, &mut bitmap)?;
+                bitmaps.push(bitmap);
+            }
+            group_bitmaps.extend(combine_bloom_bitmaps(*combinator, bitmaps));
         }
+        // All the rows pass if the combined bitmap has no unset bits.
+        Ok(combine_bloom_bitmaps(RuntimeFilterCombinator::And, group_bitmaps)
+            .and_then(|bitmap| bitmap.into()))
     }
 }
 
@@ -13,16 +13,23 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::ops::BitAnd;
+use std::ops::BitOr;
 use std::sync::Arc;
 
 use databend_common_arrow::arrow::bitmap::MutableBitmap;
 use databend_common_catalog::plan::PartInfoPtr;
+use databend_common_catalog::runtime_filter_info::RuntimeFilterCombinator;
 use databend_common_exception::Result;
+use databend_common_expression::type_check::check_function;
+use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberColumn;
 use databend_common_expression::Column;
 use databend_common_expression::ConstantFolder;
 use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
 use databend_common_expression::Expr;
+use databend_common_expression::FieldIndex;
 use databend_common_expression::FunctionContext;
 use databend_common_expression::HashMethod;
 use databend_common_expression::HashMethodKind;
@@ -95,6 +102,139 @@ pub fn runtime_filter_pruner(
     Ok(false)
 }
 
+/// A group of the bloom runtime filters resolved to the fields of a scan, the filters of the
+/// group are combined by its combinator, and the groups are ANDed.
+pub(crate) type BloomRuntimeFilterGroup = (RuntimeFilterCombinator, Vec<(FieldIndex, BinaryFuse8)>);
+
+/// Resolve the bloom runtime filters of a table to the fields of `schema`, the conjunctive
+/// filters come first as a group, followed by the disjunctive groups. Return the groups and
+/// the number of the filters skipped.
+///
+/// Some probe keys are not in the schema, they are derived from expressions. A conjunctive
+/// filter on them is skipped alone, while a disjunctive group is skipped entirely, as the rows
+/// only passing the skipped filter must not be rejected.
+pub(crate) fn resolve_bloom_runtime_filters(
+    conjunctive: Vec<(String, BinaryFuse8)>,
+    disjunctive: Vec<Vec<(String, BinaryFuse8)>>,
+    schema: &DataSchema,
+) -> (Vec<BloomRuntimeFilterGroup>, usize) {
+    let resolve = |filters: Vec<(String, BinaryFuse8)>| {
+        filters
+            .into_iter()
+            .filter_map(|(name, filter)| schema.index_of(&name).ok().map(|idx| (idx, filter)))
+            .collect::<Vec<_>>()
+    };
+    let mut groups = Vec::with_capacity(disjunctive.len() + 1);
+    let mut skipped = 0;
+    let num_filters = conjunctive.len();
+    let filters = resolve(conjunctive);
+    skipped += num_filters - filters.len();
+    if !filters.is_empty() {
+        groups.push((RuntimeFilterCombinator::And, filters));
+    }
+    for group in disjunctive {
+        let num_filters = group.len();
+        let filters = resolve(group);
+        if filters.len() < num_filters {
+            skipped += num_filters;
+        } else if !filters.is_empty() {
+            groups.push((RuntimeFilterCombinator::Or, filters));
+        }
+    }
+    (groups, skipped)
+}
+
+/// Combine the bitmaps of the bloom runtime filters of a group, return None if there is none.
+pub(crate) fn combine_bloom_bitmaps(
+    combinator: RuntimeFilterCombinator,
+    bitmaps: impl IntoIterator<Item = MutableBitmap>,
+) -> Option<MutableBitmap> {
+    bitmaps.into_iter().reduce(|acc, bitmap| match combinator {
+        RuntimeFilterCombinator::And => acc.bitand(&bitmap.into()),
+        RuntimeFilterCombinator::Or => acc.bitor(&bitmap.into()),
+    })
+}
+
+/// Order the bloom runtime filters by the estimated cost to apply them to a page.
+///
+/// The filters on the columns in `read_columns` come first, they are applied without
+/// reading more pages. Then the filters built from fewer keys come first, the number of
+/// fingerprints grows with the keys, and a filter of fewer keys is likely more selective,
+/// so it's more likely to skip the page before the other columns are read.
+pub fn order_bloom_runtime_filters(
+    filters: &mut [(FieldIndex, BinaryFuse8)],
+    read_columns: &[FieldIndex],
+) {
+    filters.sort_by_key(|(index, filter)| {
+        (!read_columns.contains(index), filter.fingerprints.len())
+    });
+}
+
+/// Rewrite the inlist runtime filter `contains(<array>, col)` into the point queries
+/// `col = v1 OR col = v2 OR ...`, which the bloom filter index of the blocks can be probed with.
+///
+/// Returns `None` if the probe key is not a column, or all the values are NULL.
+pub(crate) fn inlist_filter_to_point_queries(
+    filter: &Expr<String>,
+    func_ctx: &FunctionContext,
+) -> Result<Option<Expr<String>>> {
+    // Fold the casts of the array auto-casted to the type of the probe key.
+    let (filter, _) = ConstantFolder::fold(filter, func_ctx, &BUILTIN_FUNCTIONS);
+    let Expr::FunctionCall { function, args, .. } = &filter else {
+        return Ok(None);
+    };
+    if function.signature.name != "contains" {
+        return Ok(None);
+    }
+    let [
+        Expr::Constant {
+            scalar: Scalar::Array(values),
+            data_type: DataType::Array(value_type),
+            ..
+        },
+        probe_key @ Expr::ColumnRef { .. },
+    ] = args.as_slice()
+    else {
+        return Ok(None);
+    };
+
+    let point_queries = values
+        .iter()
+        .map(|value| value.to_owned())
+        .filter(|value| !value.is_null())
+        .map(|value| {
+            let value = Expr::Constant {
+                span: None,
+                scalar: value,
+                data_type: value_type.remove_nullable(),
+            };
+            check_function(None, "eq", &[], &[probe_key.clone(), value], &BUILTIN_FUNCTIONS)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if point_queries.is_empty() {
+        return Ok(None);
+    }
+
+    // The disjunction is built as a balanced tree, the values can be up to thousands.
+    fn disjunction(exprs: &[Expr<String>]) -> Result<Expr<String>> {
+        if exprs.len() == 1 {
+            return Ok(exprs[0].clone());
+        }
+        let (left, right) = exprs.split_at(exprs.len() / 2);
+        check_function(
+            None,
+            "or",
+            &[],
+            &[disjunction(left)?, disjunction(right)?],
+            &BUILTIN_FUNCTIONS,
+        )
+    }
+    let expr = disjunction(&point_queries)?;
+    // Fold the casts of the values, the bloom filter index only recognizes `col = <constant>`.
+    let (expr, _) = ConstantFolder::fold(&expr, func_ctx, &BUILTIN_FUNCTIONS);
+    Ok(Some(expr))
+}
+
 pub(crate) fn update_bitmap_with_bloom_filter(
     column: Column,
     filter: &BinaryFuse8,
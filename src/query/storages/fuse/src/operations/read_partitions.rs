@@ -473,6 +473,8 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            meta.bloom_filter_index_location.clone(),
+            meta.bloom_filter_index_size,
         )
     }
 
@@ -521,6 +523,8 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            meta.bloom_filter_index_location.clone(),
+            meta.bloom_filter_index_size,
         )
     }
 }